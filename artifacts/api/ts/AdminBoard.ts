@@ -0,0 +1,343 @@
+// Generated by `cargo run -p ticket-auction-api` from the contract schemas. Do not edit.
+
+import { Coin, CosmWasmQuerier, CosmWasmSigner, Fee } from "./base";
+
+export const CONTRACT_NAME = "admin-board";
+export const CONTRACT_VERSION = "0.1.0";
+
+export type InstantiateMsg = Record<string, never>;
+
+export type ExecuteMsg =
+  | { add_ticket: AddTicketMsg }
+  | { update_ticket: UpdateTicketMsg }
+  | { remove_ticket: { tid: number } }
+  | { decide_winning_bet: { tid: number } }
+  | { report_no_show: { tid: number } }
+  | { add_tickets: AddTicketMsg[] }
+  | { update_tickets: UpdateTicketMsg[] }
+  | { cancel_tickets: { tids: number[] } }
+  | { decide_winning_bets: { tids: number[] } }
+  | { set_keeper_reward: { amount: Uint128; bet_fee?: Uint128 } }
+  | { set_ticket_limits: TicketLimits }
+  | { add_grader: { address: string } }
+  | { remove_grader: { address: string } }
+  | { add_arbiter: { address: string } }
+  | { remove_arbiter: { address: string } }
+  | { set_dispute_params: DisputeParams }
+  | { grade_submission: GradeSubmissionMsg }
+  | { resolve_dispute: { tid: number; uphold: boolean; worker: string } }
+  | { release_stake_with_slash: SlashMsg }
+  | { create_ticket_manager: { code_id: number } }
+  | { create_usr_board_manager: { code_id: number } }
+  | { create_auction_manager: { code_id: number } }
+  | { create_collateral_manager: { code_id: number } }
+  | { post_config: PostConfigMsg }
+  | { migrate_child: { contract: ChildKind; msg: Binary; new_code_id: number } }
+  | { migrate_all: MigrateAllMsg };
+
+export type QueryMsg =
+  | { query_ticket_info: { tid: number } }
+  | { query_ticket_worker: { tid: number } }
+  | { query_children: Record<string, never> }
+  | { query_graders: Record<string, never> }
+  | { query_arbiters: Record<string, never> };
+
+export type MigrateMsg = Record<string, never>;
+
+export interface AddTicketMsg {
+  assessment?: AssessmentMode;
+  auction?: AuctionParams;
+  bet_finish_timestamp: number;
+  close_timestamp: number;
+  collateral: number;
+  creator?: string | null;
+  id: number;
+  lateness?: LatenessPenalty;
+  matcher?: ResultMatcher;
+  metadata?: TicketMetadata;
+  resubmission?: boolean;
+  result: string;
+  slots?: number;
+}
+
+export type Addr = string;
+
+export type AssessmentMode =
+  | "expected_result" | "consensus" | "partial_credit"
+  | { manual: { review_period: number } };
+
+export interface AuctionParams {
+  min_bid?: number | null;
+  min_decrement?: number | null;
+  min_reputation?: number | null;
+  reserve_price?: number | null;
+  scoring?: BidScoring | null;
+  soft_close?: SoftClose | null;
+}
+
+export interface BidScoring {
+  completion_weight: number;
+  price_weight: number;
+  punctuality_weight: number;
+}
+
+export type Binary = string;
+
+export interface ChildInfo {
+  address: string;
+  code_id: number;
+  contract?: string | null;
+  kind: ChildKind;
+  version?: string | null;
+}
+
+export type ChildKind = "ticket_manager" | "user_board" | "auction_manager" | "collateral_manager";
+
+export interface ChildrenResponse {
+  children: ChildInfo[];
+}
+
+export type Decimal = string;
+
+export interface DisputeParams {
+  bond: Uint128;
+  window: number;
+}
+
+export interface GradeSubmissionMsg {
+  grader?: string | null;
+  notes?: string;
+  score: number;
+  tid: number;
+  worker?: string | null;
+}
+
+export type LatenessPenalty =
+  | { flat: { cutoff?: number | null } }
+  | { linear: { cutoff?: number | null; max: number; per_hour: number } }
+  | { stepped: { cutoff?: number | null; tiers: LatenessTier[] } };
+
+export interface LatenessTier {
+  after: number;
+  slash_perc: number;
+}
+
+export interface MigrateAllMsg {
+  auction_manager?: number | null;
+  collateral_manager?: number | null;
+  ticket_manager?: number | null;
+  user_board?: number | null;
+}
+
+export interface PostConfigMsg {
+  auction_manager?: string | null;
+  collateral_manager?: string | null;
+  ticket_manager?: string | null;
+  user_board?: string | null;
+}
+
+export type ResultMatcher =
+  | "exact" | "case_insensitive" | "trimmed" | "pattern" | "normalized_hash"
+  | { numeric: { tolerance: Decimal } }
+  | { one_of: { alternatives: string[] } };
+
+export interface SlashMsg {
+  slash_perc: Uint128;
+  tid: number;
+  worker: Addr;
+}
+
+export interface SoftClose {
+  extension: number;
+  max_extension: number;
+  window: number;
+}
+
+export interface TicketInfoResponse {
+  assessment?: AssessmentMode;
+  auction?: AuctionParams;
+  bet_finish_timestamp: number;
+  close_timestamp: number;
+  collateral: number;
+  creator?: string;
+  id: number;
+  lateness?: LatenessPenalty;
+  matcher?: ResultMatcher;
+  metadata?: TicketMetadata;
+  resubmission?: boolean;
+  result: string;
+  slots?: number;
+}
+
+export interface TicketLimits {
+  max_bet_duration?: number | null;
+  max_collateral?: number | null;
+  max_work_duration?: number | null;
+  min_bet_duration: number;
+  min_collateral: number;
+  min_work_duration: number;
+}
+
+export interface TicketMetadata {
+  category?: string | null;
+  content_hash?: string | null;
+  content_uri?: string | null;
+  description?: string;
+  tags?: string[];
+  title?: string;
+}
+
+export type Uint128 = string;
+
+export interface UpdateTicketMsg {
+  assessment?: AssessmentMode | null;
+  auction?: AuctionParams | null;
+  bet_finish_timestamp?: number | null;
+  close_timestamp?: number | null;
+  collateral?: number | null;
+  id: number;
+  lateness?: LatenessPenalty | null;
+  matcher?: ResultMatcher | null;
+  metadata?: TicketMetadata | null;
+  resubmission?: boolean | null;
+  result?: string | null;
+  slots?: number | null;
+}
+
+export class QueryClient {
+  constructor(readonly client: CosmWasmQuerier, readonly contractAddress: string) {}
+
+  queryTicketInfo(args: { tid: number }): Promise<TicketInfoResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_ticket_info: args });
+  }
+
+  queryTicketWorker(args: { tid: number }): Promise<string> {
+    return this.client.queryContractSmart(this.contractAddress, { query_ticket_worker: args });
+  }
+
+  queryChildren(): Promise<ChildrenResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_children: {} });
+  }
+
+  queryGraders(): Promise<Addr[]> {
+    return this.client.queryContractSmart(this.contractAddress, { query_graders: {} });
+  }
+
+  queryArbiters(): Promise<Addr[]> {
+    return this.client.queryContractSmart(this.contractAddress, { query_arbiters: {} });
+  }
+}
+
+export class Client extends QueryClient {
+  constructor(
+    readonly signer: CosmWasmSigner,
+    readonly sender: string,
+    contractAddress: string,
+  ) {
+    super(signer, contractAddress);
+  }
+
+  addTicket(args: AddTicketMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { add_ticket: args }, fee, memo, funds);
+  }
+
+  updateTicket(args: UpdateTicketMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { update_ticket: args }, fee, memo, funds);
+  }
+
+  removeTicket(args: { tid: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { remove_ticket: args }, fee, memo, funds);
+  }
+
+  decideWinningBet(args: { tid: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { decide_winning_bet: args }, fee, memo, funds);
+  }
+
+  reportNoShow(args: { tid: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { report_no_show: args }, fee, memo, funds);
+  }
+
+  addTickets(args: AddTicketMsg[], fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { add_tickets: args }, fee, memo, funds);
+  }
+
+  updateTickets(args: UpdateTicketMsg[], fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { update_tickets: args }, fee, memo, funds);
+  }
+
+  cancelTickets(args: { tids: number[] }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { cancel_tickets: args }, fee, memo, funds);
+  }
+
+  decideWinningBets(args: { tids: number[] }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { decide_winning_bets: args }, fee, memo, funds);
+  }
+
+  setKeeperReward(args: { amount: Uint128; bet_fee?: Uint128 }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { set_keeper_reward: args }, fee, memo, funds);
+  }
+
+  setTicketLimits(args: TicketLimits, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { set_ticket_limits: args }, fee, memo, funds);
+  }
+
+  addGrader(args: { address: string }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { add_grader: args }, fee, memo, funds);
+  }
+
+  removeGrader(args: { address: string }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { remove_grader: args }, fee, memo, funds);
+  }
+
+  addArbiter(args: { address: string }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { add_arbiter: args }, fee, memo, funds);
+  }
+
+  removeArbiter(args: { address: string }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { remove_arbiter: args }, fee, memo, funds);
+  }
+
+  setDisputeParams(args: DisputeParams, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { set_dispute_params: args }, fee, memo, funds);
+  }
+
+  gradeSubmission(args: GradeSubmissionMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { grade_submission: args }, fee, memo, funds);
+  }
+
+  resolveDispute(args: { tid: number; uphold: boolean; worker: string }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { resolve_dispute: args }, fee, memo, funds);
+  }
+
+  releaseStakeWithSlash(args: SlashMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { release_stake_with_slash: args }, fee, memo, funds);
+  }
+
+  createTicketManager(args: { code_id: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { create_ticket_manager: args }, fee, memo, funds);
+  }
+
+  createUsrBoardManager(args: { code_id: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { create_usr_board_manager: args }, fee, memo, funds);
+  }
+
+  createAuctionManager(args: { code_id: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { create_auction_manager: args }, fee, memo, funds);
+  }
+
+  createCollateralManager(args: { code_id: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { create_collateral_manager: args }, fee, memo, funds);
+  }
+
+  postConfig(args: PostConfigMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { post_config: args }, fee, memo, funds);
+  }
+
+  migrateChild(args: { contract: ChildKind; msg: Binary; new_code_id: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { migrate_child: args }, fee, memo, funds);
+  }
+
+  migrateAll(args: MigrateAllMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { migrate_all: args }, fee, memo, funds);
+  }
+}
@@ -0,0 +1,125 @@
+// Generated by `cargo run -p ticket-auction-api` from the contract schemas. Do not edit.
+
+import { Coin, CosmWasmQuerier, CosmWasmSigner, Fee } from "./base";
+
+export const CONTRACT_NAME = "auction-manager";
+export const CONTRACT_VERSION = "0.1.0";
+
+export interface InstantiateMsg {
+  ticket_manager: string;
+  user_board: string;
+}
+
+export type ExecuteMsg =
+  | { place_bet: PlaceBetMsg }
+  | { decide_winning_bet: { tid: number } }
+  | { settle_expired: { limit?: number | null } }
+  | { update_config: { bet_fee?: Uint128 | null; keeper_reward?: Uint128 | null; user_board?: string | null } };
+
+export type QueryMsg =
+  | "bet_available_tickets" | "fee_pool"
+  | { curr_active_bets: { tid: number } }
+  | { worker_active_bets: { worker: string } }
+  | { bid_scores: { tid: number } };
+
+export type MigrateMsg = Record<string, never>;
+
+export type Addr = string;
+
+export interface BetDetail {
+  bet_amt: number;
+  placed_at?: number;
+  worker: Addr;
+}
+
+export interface BidScore {
+  bet_amt: number;
+  completion_score: number;
+  placed_at: number;
+  price_score: number;
+  punctuality_score: number;
+  total_score: number;
+  worker: string;
+}
+
+export interface BidScoresResponse {
+  decided: boolean;
+  scores: BidScore[];
+  scoring?: BidScoring | null;
+  tid: number;
+}
+
+export interface BidScoring {
+  completion_weight: number;
+  price_weight: number;
+  punctuality_weight: number;
+}
+
+export interface FeePoolResponse {
+  bet_fee: Uint128;
+  fee_pool: Uint128;
+  keeper_reward: Uint128;
+}
+
+export interface PlaceBetMsg {
+  bet_amount: number;
+  ticket_id: number;
+  workder: string;
+}
+
+export type Uint128 = string;
+
+export interface WorkerBet {
+  bet_amt: number;
+  tid: number;
+}
+
+export class QueryClient {
+  constructor(readonly client: CosmWasmQuerier, readonly contractAddress: string) {}
+
+  betAvailableTickets(): Promise<number[]> {
+    return this.client.queryContractSmart(this.contractAddress, "bet_available_tickets");
+  }
+
+  feePool(): Promise<FeePoolResponse> {
+    return this.client.queryContractSmart(this.contractAddress, "fee_pool");
+  }
+
+  currActiveBets(args: { tid: number }): Promise<BetDetail[]> {
+    return this.client.queryContractSmart(this.contractAddress, { curr_active_bets: args });
+  }
+
+  workerActiveBets(args: { worker: string }): Promise<WorkerBet[]> {
+    return this.client.queryContractSmart(this.contractAddress, { worker_active_bets: args });
+  }
+
+  bidScores(args: { tid: number }): Promise<BidScoresResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { bid_scores: args });
+  }
+}
+
+export class Client extends QueryClient {
+  constructor(
+    readonly signer: CosmWasmSigner,
+    readonly sender: string,
+    contractAddress: string,
+  ) {
+    super(signer, contractAddress);
+  }
+
+  placeBet(args: PlaceBetMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { place_bet: args }, fee, memo, funds);
+  }
+
+  decideWinningBet(args: { tid: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { decide_winning_bet: args }, fee, memo, funds);
+  }
+
+  settleExpired(args: { limit?: number | null }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { settle_expired: args }, fee, memo, funds);
+  }
+
+  updateConfig(args: { bet_fee?: Uint128 | null; keeper_reward?: Uint128 | null; user_board?: string | null }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { update_config: args }, fee, memo, funds);
+  }
+}
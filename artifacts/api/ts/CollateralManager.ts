@@ -0,0 +1,208 @@
+// Generated by `cargo run -p ticket-auction-api` from the contract schemas. Do not edit.
+
+import { Coin, CosmWasmQuerier, CosmWasmSigner, Fee } from "./base";
+
+export const CONTRACT_NAME = "collateral-manager";
+export const CONTRACT_VERSION = "0.1.0";
+
+export interface InstantiateMsg {
+  ticket_manager: string;
+  user_board: string;
+}
+
+export type ExecuteMsg =
+  | { lock_stake: { tid: number; worker: string } }
+  | { open_dispute: { evidence_uri: string; tid: number; worker: string } }
+  | { release_stake: ReleaseStakeMsg }
+  | { set_dispute_params: DisputeParams }
+  | { resolve_dispute: { tid: number; uphold: boolean; worker: string } }
+  | { finalize_slash: { tid: number; worker: string } };
+
+export type QueryMsg =
+  | { query_stake_status: QueryStakeStatusMsg }
+  | { query_ticket: { tid: number } }
+  | { query_worker_stakes: { worker: Addr } }
+  | { query_ticket_stakers: { tid: number } }
+  | { query_treasury: Record<string, never> }
+  | { query_dispute_params: Record<string, never> }
+  | { query_held_slash: { tid: number; worker: Addr } }
+  | { query_disputes: Record<string, never> };
+
+export type MigrateMsg = Record<string, never>;
+
+export type Addr = string;
+
+export type AssessmentMode =
+  | "expected_result" | "consensus" | "partial_credit"
+  | { manual: { review_period: number } };
+
+export interface AuctionParams {
+  min_bid?: number | null;
+  min_decrement?: number | null;
+  min_reputation?: number | null;
+  reserve_price?: number | null;
+  scoring?: BidScoring | null;
+  soft_close?: SoftClose | null;
+}
+
+export interface BidScoring {
+  completion_weight: number;
+  price_weight: number;
+  punctuality_weight: number;
+}
+
+export type Decimal = string;
+
+export interface Dispute {
+  bond: Uint128;
+  evidence_uri: string;
+  opened_at: number;
+}
+
+export interface DisputeParams {
+  bond: Uint128;
+  window: number;
+}
+
+export interface HeldSlash {
+  amount: Uint128;
+  dispute?: Dispute | null;
+  release_at: number;
+  tid: number;
+  worker: Addr;
+}
+
+export type LatenessPenalty =
+  | { flat: { cutoff?: number | null } }
+  | { linear: { cutoff?: number | null; max: number; per_hour: number } }
+  | { stepped: { cutoff?: number | null; tiers: LatenessTier[] } };
+
+export interface LatenessTier {
+  after: number;
+  slash_perc: number;
+}
+
+export interface QueryStakeStatusMsg {
+  tid: number;
+  worker: Addr;
+}
+
+export interface ReleaseStakeMsg {
+  amt: Uint128;
+  tid: number;
+  worker: Addr;
+}
+
+export type ResultMatcher =
+  | "exact" | "case_insensitive" | "trimmed" | "pattern" | "normalized_hash"
+  | { numeric: { tolerance: Decimal } }
+  | { one_of: { alternatives: string[] } };
+
+export interface SoftClose {
+  extension: number;
+  max_extension: number;
+  window: number;
+}
+
+export interface TicketInfoResponse {
+  assessment?: AssessmentMode;
+  auction?: AuctionParams;
+  bet_finish_timestamp: number;
+  close_timestamp: number;
+  collateral: number;
+  creator?: string;
+  id: number;
+  lateness?: LatenessPenalty;
+  matcher?: ResultMatcher;
+  metadata?: TicketMetadata;
+  resubmission?: boolean;
+  result: string;
+  slots?: number;
+}
+
+export interface TicketMetadata {
+  category?: string | null;
+  content_hash?: string | null;
+  content_uri?: string | null;
+  description?: string;
+  tags?: string[];
+  title?: string;
+}
+
+export interface TreasuryResponse {
+  held?: Uint128;
+  locked: Uint128;
+  slashed: Uint128;
+}
+
+export type Uint128 = string;
+
+export class QueryClient {
+  constructor(readonly client: CosmWasmQuerier, readonly contractAddress: string) {}
+
+  queryStakeStatus(args: QueryStakeStatusMsg): Promise<boolean> {
+    return this.client.queryContractSmart(this.contractAddress, { query_stake_status: args });
+  }
+
+  queryTicket(args: { tid: number }): Promise<TicketInfoResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_ticket: args });
+  }
+
+  queryWorkerStakes(args: { worker: Addr }): Promise<number[]> {
+    return this.client.queryContractSmart(this.contractAddress, { query_worker_stakes: args });
+  }
+
+  queryTicketStakers(args: { tid: number }): Promise<Addr[]> {
+    return this.client.queryContractSmart(this.contractAddress, { query_ticket_stakers: args });
+  }
+
+  queryTreasury(): Promise<TreasuryResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_treasury: {} });
+  }
+
+  queryDisputeParams(): Promise<DisputeParams> {
+    return this.client.queryContractSmart(this.contractAddress, { query_dispute_params: {} });
+  }
+
+  queryHeldSlash(args: { tid: number; worker: Addr }): Promise<HeldSlash | null> {
+    return this.client.queryContractSmart(this.contractAddress, { query_held_slash: args });
+  }
+
+  queryDisputes(): Promise<HeldSlash[]> {
+    return this.client.queryContractSmart(this.contractAddress, { query_disputes: {} });
+  }
+}
+
+export class Client extends QueryClient {
+  constructor(
+    readonly signer: CosmWasmSigner,
+    readonly sender: string,
+    contractAddress: string,
+  ) {
+    super(signer, contractAddress);
+  }
+
+  lockStake(args: { tid: number; worker: string }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { lock_stake: args }, fee, memo, funds);
+  }
+
+  openDispute(args: { evidence_uri: string; tid: number; worker: string }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { open_dispute: args }, fee, memo, funds);
+  }
+
+  releaseStake(args: ReleaseStakeMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { release_stake: args }, fee, memo, funds);
+  }
+
+  setDisputeParams(args: DisputeParams, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { set_dispute_params: args }, fee, memo, funds);
+  }
+
+  resolveDispute(args: { tid: number; uphold: boolean; worker: string }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { resolve_dispute: args }, fee, memo, funds);
+  }
+
+  finalizeSlash(args: { tid: number; worker: string }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { finalize_slash: args }, fee, memo, funds);
+  }
+}
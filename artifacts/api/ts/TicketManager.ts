@@ -0,0 +1,427 @@
+// Generated by `cargo run -p ticket-auction-api` from the contract schemas. Do not edit.
+
+import { Coin, CosmWasmQuerier, CosmWasmSigner, Fee } from "./base";
+
+export const CONTRACT_NAME = "ticket-manager";
+export const CONTRACT_VERSION = "0.2.0";
+
+export interface InstantiateMsg {
+  auction_manager?: string | null;
+  user_board?: string | null;
+}
+
+export type ExecuteMsg =
+  | { add_ticket: AddTicketMsg }
+  | { update_ticket: UpdateTicketMsg }
+  | { remove_ticket: { tid: number } }
+  | { add_tickets: AddTicketMsg[] }
+  | { update_tickets: UpdateTicketMsg[] }
+  | { cancel_tickets: { tids: number[] } }
+  | { save_ticket_worker: TicketWorkerPair }
+  | { extend_bet_finish: { bet_finish_timestamp: number; tid: number } }
+  | { assess_submission: TicketResultMsg }
+  | { report_no_show: { tid: number } }
+  | { set_ticket_limits: TicketLimits }
+  | { grade_submission: GradeSubmissionMsg }
+  | { reverse_slash: { tid: number; worker: string } }
+  | { auto_approve: { tid: number } }
+  | { close_submissions: { tid: number } }
+  | { post_config: PostConfigMsg };
+
+export type QueryMsg =
+  | { query_tickets: { category?: string | null; tag?: string | null } }
+  | { query_ticket_info: { tid: number } }
+  | { query_ticket_worker_pairs: Record<string, never> }
+  | { query_ticket_worker: { tid: number } }
+  | { query_ticket_workers: { tid: number } }
+  | { query_worker_tickets: { worker: string } }
+  | { query_worker_reputation: { worker: string } }
+  | { query_ticket_limits: Record<string, never> }
+  | { query_pending_reviews: Record<string, never> }
+  | { query_submission: { tid: number; worker: string } }
+  | { query_submissions_by_worker: { worker: string } }
+  | { query_lateness_penalty: { at_time?: number | null; tid: number } }
+  | { simulate_assessment: { at_time?: number | null; result: string; tid: number; worker: string } };
+
+export type MigrateMsg = Record<string, never>;
+
+export interface AddTicketMsg {
+  assessment?: AssessmentMode;
+  auction?: AuctionParams;
+  bet_finish_timestamp: number;
+  close_timestamp: number;
+  collateral: number;
+  creator?: string | null;
+  id: number;
+  lateness?: LatenessPenalty;
+  matcher?: ResultMatcher;
+  metadata?: TicketMetadata;
+  resubmission?: boolean;
+  result: string;
+  slots?: number;
+}
+
+export type AssessmentMode =
+  | "expected_result" | "consensus" | "partial_credit"
+  | { manual: { review_period: number } };
+
+export interface AuctionParams {
+  min_bid?: number | null;
+  min_decrement?: number | null;
+  min_reputation?: number | null;
+  reserve_price?: number | null;
+  scoring?: BidScoring | null;
+  soft_close?: SoftClose | null;
+}
+
+export interface BidScoring {
+  completion_weight: number;
+  price_weight: number;
+  punctuality_weight: number;
+}
+
+export type Decimal = string;
+
+export interface Grade {
+  grader?: string | null;
+  notes: string;
+  score: number;
+}
+
+export interface GradeSubmissionMsg {
+  grader?: string | null;
+  notes?: string;
+  score: number;
+  tid: number;
+  worker?: string | null;
+}
+
+export type LatenessPenalty =
+  | { flat: { cutoff?: number | null } }
+  | { linear: { cutoff?: number | null; max: number; per_hour: number } }
+  | { stepped: { cutoff?: number | null; tiers: LatenessTier[] } };
+
+export interface LatenessResponse {
+  at_time: number;
+  late_by: number;
+  rejected: boolean;
+  slash_perc: Uint128;
+  tid: number;
+}
+
+export interface LatenessTier {
+  after: number;
+  slash_perc: number;
+}
+
+export interface PendingReview {
+  result: string;
+  review_deadline: number;
+  submitted_at: number;
+  tid: number;
+  worker: string;
+}
+
+export interface PostConfigMsg {
+  auction_manager?: string | null;
+  collateral_manager?: string | null;
+  user_board?: string | null;
+}
+
+export type ResultMatcher =
+  | "exact" | "case_insensitive" | "trimmed" | "pattern" | "normalized_hash"
+  | { numeric: { tolerance: Decimal } }
+  | { one_of: { alternatives: string[] } };
+
+export interface SimulateAssessmentResponse {
+  at_time: number;
+  collateral: Uint128;
+  credit?: number | null;
+  held: boolean;
+  lateness_penalty: Uint128;
+  matched?: boolean | null;
+  on_time: boolean;
+  rejected: boolean;
+  released: Uint128;
+  slash_perc: Uint128;
+  tid: number;
+  worker: string;
+}
+
+export interface SoftClose {
+  extension: number;
+  max_extension: number;
+  window: number;
+}
+
+export interface Submission {
+  assessment?: SubmissionAssessment | null;
+  result: string;
+  submitted_at: number;
+  tid: number;
+  worker: string;
+}
+
+export interface SubmissionAssessment {
+  assessed_at: number;
+  correct: boolean;
+  on_time: boolean;
+  released: Uint128;
+  slash_perc: Uint128;
+}
+
+export interface TWPairsReponse {
+  pairs: TicketWorkerPair[];
+}
+
+export interface Ticket {
+  assessment?: AssessmentMode;
+  auction?: AuctionParams;
+  bet_finish_timestamp: number;
+  close_timestamp: number;
+  collateral: number;
+  creator?: string;
+  id: number;
+  lateness?: LatenessPenalty;
+  matcher?: ResultMatcher;
+  metadata?: TicketMetadata;
+  resubmission?: boolean;
+  result: string;
+  slots?: number;
+}
+
+export interface TicketInfoResponse {
+  assessment?: AssessmentMode;
+  auction?: AuctionParams;
+  bet_finish_timestamp: number;
+  close_timestamp: number;
+  collateral: number;
+  creator?: string;
+  id: number;
+  lateness?: LatenessPenalty;
+  matcher?: ResultMatcher;
+  metadata?: TicketMetadata;
+  resubmission?: boolean;
+  result: string;
+  slots?: number;
+}
+
+export interface TicketLimits {
+  max_bet_duration?: number | null;
+  max_collateral?: number | null;
+  max_work_duration?: number | null;
+  min_bet_duration: number;
+  min_collateral: number;
+  min_work_duration: number;
+}
+
+export interface TicketMetadata {
+  category?: string | null;
+  content_hash?: string | null;
+  content_uri?: string | null;
+  description?: string;
+  tags?: string[];
+  title?: string;
+}
+
+export interface TicketOutcome {
+  assessed_at: number;
+  correct: boolean;
+  credit?: number | null;
+  grade?: Grade | null;
+  no_show?: boolean;
+  on_time: boolean;
+  reversed?: boolean;
+  slash_perc: Uint128;
+  tid: number;
+  worker: string;
+}
+
+export interface TicketResultMsg {
+  result: string;
+  tid: number;
+  worker: string;
+}
+
+export interface TicketWorkerPair {
+  tid: number;
+  worker: string;
+}
+
+export interface TicketsResponse {
+  tickets: Ticket[];
+}
+
+export type Uint128 = string;
+
+export interface UpdateTicketMsg {
+  assessment?: AssessmentMode | null;
+  auction?: AuctionParams | null;
+  bet_finish_timestamp?: number | null;
+  close_timestamp?: number | null;
+  collateral?: number | null;
+  id: number;
+  lateness?: LatenessPenalty | null;
+  matcher?: ResultMatcher | null;
+  metadata?: TicketMetadata | null;
+  resubmission?: boolean | null;
+  result?: string | null;
+  slots?: number | null;
+}
+
+export interface WorkerAssignment {
+  close_timestamp: number;
+  outcome?: TicketOutcome | null;
+  tid: number;
+}
+
+export interface WorkerReputationResponse {
+  score: number;
+  stats: WorkerStats;
+  worker: string;
+}
+
+export interface WorkerStats {
+  completed: number;
+  late: number;
+  no_shows: number;
+  slash_perc_sum: Uint128;
+  total_slashed: Uint128;
+  won: number;
+  wrong: number;
+}
+
+export interface WorkerTicketsResponse {
+  assignments: WorkerAssignment[];
+  worker: string;
+}
+
+export class QueryClient {
+  constructor(readonly client: CosmWasmQuerier, readonly contractAddress: string) {}
+
+  queryTickets(args: { category?: string | null; tag?: string | null }): Promise<TicketsResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_tickets: args });
+  }
+
+  queryTicketInfo(args: { tid: number }): Promise<TicketInfoResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_ticket_info: args });
+  }
+
+  queryTicketWorkerPairs(): Promise<TWPairsReponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_ticket_worker_pairs: {} });
+  }
+
+  queryTicketWorker(args: { tid: number }): Promise<string> {
+    return this.client.queryContractSmart(this.contractAddress, { query_ticket_worker: args });
+  }
+
+  queryTicketWorkers(args: { tid: number }): Promise<string[]> {
+    return this.client.queryContractSmart(this.contractAddress, { query_ticket_workers: args });
+  }
+
+  queryWorkerTickets(args: { worker: string }): Promise<WorkerTicketsResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_worker_tickets: args });
+  }
+
+  queryWorkerReputation(args: { worker: string }): Promise<WorkerReputationResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_worker_reputation: args });
+  }
+
+  queryTicketLimits(): Promise<TicketLimits> {
+    return this.client.queryContractSmart(this.contractAddress, { query_ticket_limits: {} });
+  }
+
+  queryPendingReviews(): Promise<PendingReview[]> {
+    return this.client.queryContractSmart(this.contractAddress, { query_pending_reviews: {} });
+  }
+
+  querySubmission(args: { tid: number; worker: string }): Promise<Submission | null> {
+    return this.client.queryContractSmart(this.contractAddress, { query_submission: args });
+  }
+
+  querySubmissionsByWorker(args: { worker: string }): Promise<Submission[]> {
+    return this.client.queryContractSmart(this.contractAddress, { query_submissions_by_worker: args });
+  }
+
+  queryLatenessPenalty(args: { at_time?: number | null; tid: number }): Promise<LatenessResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_lateness_penalty: args });
+  }
+
+  simulateAssessment(args: { at_time?: number | null; result: string; tid: number; worker: string }): Promise<SimulateAssessmentResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { simulate_assessment: args });
+  }
+}
+
+export class Client extends QueryClient {
+  constructor(
+    readonly signer: CosmWasmSigner,
+    readonly sender: string,
+    contractAddress: string,
+  ) {
+    super(signer, contractAddress);
+  }
+
+  addTicket(args: AddTicketMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { add_ticket: args }, fee, memo, funds);
+  }
+
+  updateTicket(args: UpdateTicketMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { update_ticket: args }, fee, memo, funds);
+  }
+
+  removeTicket(args: { tid: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { remove_ticket: args }, fee, memo, funds);
+  }
+
+  addTickets(args: AddTicketMsg[], fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { add_tickets: args }, fee, memo, funds);
+  }
+
+  updateTickets(args: UpdateTicketMsg[], fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { update_tickets: args }, fee, memo, funds);
+  }
+
+  cancelTickets(args: { tids: number[] }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { cancel_tickets: args }, fee, memo, funds);
+  }
+
+  saveTicketWorker(args: TicketWorkerPair, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { save_ticket_worker: args }, fee, memo, funds);
+  }
+
+  extendBetFinish(args: { bet_finish_timestamp: number; tid: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { extend_bet_finish: args }, fee, memo, funds);
+  }
+
+  assessSubmission(args: TicketResultMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { assess_submission: args }, fee, memo, funds);
+  }
+
+  reportNoShow(args: { tid: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { report_no_show: args }, fee, memo, funds);
+  }
+
+  setTicketLimits(args: TicketLimits, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { set_ticket_limits: args }, fee, memo, funds);
+  }
+
+  gradeSubmission(args: GradeSubmissionMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { grade_submission: args }, fee, memo, funds);
+  }
+
+  reverseSlash(args: { tid: number; worker: string }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { reverse_slash: args }, fee, memo, funds);
+  }
+
+  autoApprove(args: { tid: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { auto_approve: args }, fee, memo, funds);
+  }
+
+  closeSubmissions(args: { tid: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { close_submissions: args }, fee, memo, funds);
+  }
+
+  postConfig(args: PostConfigMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { post_config: args }, fee, memo, funds);
+  }
+}
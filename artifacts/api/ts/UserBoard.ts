@@ -0,0 +1,213 @@
+// Generated by `cargo run -p ticket-auction-api` from the contract schemas. Do not edit.
+
+import { Coin, CosmWasmQuerier, CosmWasmSigner, Fee } from "./base";
+
+export const CONTRACT_NAME = "user-board";
+export const CONTRACT_VERSION = "0.1.0";
+
+export interface InstantiateMsg {
+  auction_manager?: string | null;
+  collateral_manater?: string | null;
+  ticket_manager?: string | null;
+}
+
+export type ExecuteMsg =
+  | { place_bet: PlaceBetMsg }
+  | { lock_stake: { tid: number } }
+  | { submit_result: TicketResultMsg }
+  | { dispute_assessment: { evidence_uri: string; tid: number } }
+  | { post_config: PostConfigMsg };
+
+export type QueryMsg =
+  | { query_bet_avail_tickets: Record<string, never> }
+  | { query_tickets: { category?: string | null; tag?: string | null } }
+  | { query_stake_status: QueryStakeStatusMsg }
+  | { query_worker_dashboard: { worker: string } };
+
+export type MigrateMsg = Record<string, never>;
+
+export type Addr = string;
+
+export type AssessmentMode =
+  | "expected_result" | "consensus" | "partial_credit"
+  | { manual: { review_period: number } };
+
+export interface AuctionParams {
+  min_bid?: number | null;
+  min_decrement?: number | null;
+  min_reputation?: number | null;
+  reserve_price?: number | null;
+  scoring?: BidScoring | null;
+  soft_close?: SoftClose | null;
+}
+
+export interface BidScoring {
+  completion_weight: number;
+  price_weight: number;
+  punctuality_weight: number;
+}
+
+export type Decimal = string;
+
+export interface Grade {
+  grader?: string | null;
+  notes: string;
+  score: number;
+}
+
+export type LatenessPenalty =
+  | { flat: { cutoff?: number | null } }
+  | { linear: { cutoff?: number | null; max: number; per_hour: number } }
+  | { stepped: { cutoff?: number | null; tiers: LatenessTier[] } };
+
+export interface LatenessTier {
+  after: number;
+  slash_perc: number;
+}
+
+export interface PlaceBetMsg {
+  bet_amount: number;
+  ticket_id: number;
+}
+
+export interface PostConfigMsg {
+  auction_manager?: string | null;
+  collateral_manager?: string | null;
+  ticket_manager?: string | null;
+}
+
+export interface QueryStakeStatusMsg {
+  tid: number;
+  worker: Addr;
+}
+
+export type ResultMatcher =
+  | "exact" | "case_insensitive" | "trimmed" | "pattern" | "normalized_hash"
+  | { numeric: { tolerance: Decimal } }
+  | { one_of: { alternatives: string[] } };
+
+export interface SoftClose {
+  extension: number;
+  max_extension: number;
+  window: number;
+}
+
+export interface Ticket {
+  assessment?: AssessmentMode;
+  auction?: AuctionParams;
+  bet_finish_timestamp: number;
+  close_timestamp: number;
+  collateral: number;
+  creator?: string;
+  id: number;
+  lateness?: LatenessPenalty;
+  matcher?: ResultMatcher;
+  metadata?: TicketMetadata;
+  resubmission?: boolean;
+  result: string;
+  slots?: number;
+}
+
+export interface TicketMetadata {
+  category?: string | null;
+  content_hash?: string | null;
+  content_uri?: string | null;
+  description?: string;
+  tags?: string[];
+  title?: string;
+}
+
+export interface TicketOutcome {
+  assessed_at: number;
+  correct: boolean;
+  credit?: number | null;
+  grade?: Grade | null;
+  no_show?: boolean;
+  on_time: boolean;
+  reversed?: boolean;
+  slash_perc: Uint128;
+  tid: number;
+  worker: string;
+}
+
+export interface TicketResultMsg {
+  result: string;
+  tid: number;
+  worker: string;
+}
+
+export interface TicketsResponse {
+  tickets: Ticket[];
+}
+
+export type Uint128 = string;
+
+export interface WorkerAssignment {
+  close_timestamp: number;
+  outcome?: TicketOutcome | null;
+  tid: number;
+}
+
+export interface WorkerBet {
+  bet_amt: number;
+  tid: number;
+}
+
+export interface WorkerDashboardResponse {
+  active_bets: WorkerBet[];
+  assignments: WorkerAssignment[];
+  outcomes: TicketOutcome[];
+  pending_submissions: number[];
+  staked_tickets: number[];
+  worker: string;
+}
+
+export class QueryClient {
+  constructor(readonly client: CosmWasmQuerier, readonly contractAddress: string) {}
+
+  queryBetAvailTickets(): Promise<number[]> {
+    return this.client.queryContractSmart(this.contractAddress, { query_bet_avail_tickets: {} });
+  }
+
+  queryTickets(args: { category?: string | null; tag?: string | null }): Promise<TicketsResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_tickets: args });
+  }
+
+  queryStakeStatus(args: QueryStakeStatusMsg): Promise<boolean> {
+    return this.client.queryContractSmart(this.contractAddress, { query_stake_status: args });
+  }
+
+  queryWorkerDashboard(args: { worker: string }): Promise<WorkerDashboardResponse> {
+    return this.client.queryContractSmart(this.contractAddress, { query_worker_dashboard: args });
+  }
+}
+
+export class Client extends QueryClient {
+  constructor(
+    readonly signer: CosmWasmSigner,
+    readonly sender: string,
+    contractAddress: string,
+  ) {
+    super(signer, contractAddress);
+  }
+
+  placeBet(args: PlaceBetMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { place_bet: args }, fee, memo, funds);
+  }
+
+  lockStake(args: { tid: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { lock_stake: args }, fee, memo, funds);
+  }
+
+  submitResult(args: TicketResultMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { submit_result: args }, fee, memo, funds);
+  }
+
+  disputeAssessment(args: { evidence_uri: string; tid: number }, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { dispute_assessment: args }, fee, memo, funds);
+  }
+
+  postConfig(args: PostConfigMsg, fee: Fee = "auto", memo?: string, funds?: readonly Coin[]): Promise<unknown> {
+    return this.signer.execute(this.sender, this.contractAddress, { post_config: args }, fee, memo, funds);
+  }
+}
@@ -0,0 +1,25 @@
+// Generated by `cargo run -p ticket-auction-api` from the contract schemas. Do not edit.
+
+export interface Coin {
+  denom: string;
+  amount: string;
+}
+
+// "CosmWasmClient" of cosmjs satisfies this interface.
+export interface CosmWasmQuerier {
+  queryContractSmart(address: string, queryMsg: unknown): Promise<any>;
+}
+
+// "SigningCosmWasmClient" of cosmjs satisfies this interface.
+export interface CosmWasmSigner extends CosmWasmQuerier {
+  execute(
+    senderAddress: string,
+    contractAddress: string,
+    msg: unknown,
+    fee: unknown,
+    memo?: string,
+    funds?: readonly Coin[],
+  ): Promise<unknown>;
+}
+
+export type Fee = number | "auto" | object;
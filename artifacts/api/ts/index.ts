@@ -0,0 +1,7 @@
+// Generated by `cargo run -p ticket-auction-api` from the contract schemas. Do not edit.
+export * from "./base";
+export * as AdminBoard from "./AdminBoard";
+export * as AuctionManager from "./AuctionManager";
+export * as CollateralManager from "./CollateralManager";
+export * as TicketManager from "./TicketManager";
+export * as UserBoard from "./UserBoard";
@@ -3,26 +3,44 @@ use std::ops::Sub;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    attr, to_json_binary, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, Fraction, MessageInfo,
-    Response, StdResult, Uint128, WasmMsg,
+    attr, to_json_binary, Binary, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, Fraction,
+    MessageInfo, Response, StdResult, Uint128, WasmMsg,
 };
+use cw2::set_contract_version;
 
-use crate::state::{read_config, store_config, Config};
+use crate::state::{
+    is_arbiter, is_grader, read_arbiters, read_config, read_graders, remove_arbiter, remove_grader,
+    store_arbiter, store_config, store_grader, Config,
+};
 
 use ticket_auction::{
-    admin_board::{ExecuteMsg, InstantiateMsg, MigrateMsg, PostConfigMsg, QueryMsg, SlashMsg},
+    admin_board::{
+        ChildInfo, ChildKind, ChildrenResponse, ExecuteMsg, InstantiateMsg, MigrateAllMsg,
+        MigrateMsg, PostConfigMsg, QueryMsg, SlashMsg,
+    },
     auction_manager::InstantiateMsg as AuctionInstantiateMsg,
-    collateral_manager::{InstantiateMsg as CollateralInstantiateMsg, ReleaseStakeMsg},
+    batch::validate_batch,
+    collateral_manager::{
+        DisputeParams, InstantiateMsg as CollateralInstantiateMsg, ReleaseStakeMsg,
+    },
     error::TAError,
+    migration::migrate_contract,
     ticket_manager::{
-        AddTicketMsg, InstantiateMsg as TicketInstantiateMsg, TicketInfoResponse, UpdateTicketMsg,
+        AddTicketMsg, GradeSubmissionMsg, InstantiateMsg as TicketInstantiateMsg,
+        PostConfigMsg as TicketPostConfigMsg, TicketInfoResponse, TicketLimits, UpdateTicketMsg,
+    },
+    user_board::{
+        InstantiateMsg as UserBoardInstantiateMsg, PostConfigMsg as UserBoardPostConfigMsg,
     },
-    user_board::InstantiateMsg as UserBoardInstantiateMsg,
 };
 
-use ticket_auction::auction_manager::ExecuteMsg as AuctionExecuteMsg;
-use ticket_auction::collateral_manager::ExecuteMsg as CollateralExecuteMsg;
-use ticket_auction::ticket_manager::{ExecuteMsg as TicketExecuteMsg, QueryMsg as TicketQueryMsg};
+use ticket_auction::helpers::{
+    AuctionManagerContract, CollateralManagerContract, TicketManagerContract, UserBoardContract,
+};
+
+// Contract info recorded by cw2, checked on the migration.
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -42,6 +60,8 @@ pub fn instantiate(
 
     store_config(deps.storage, config)?;
 
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     Ok(Response::new()
         .add_attribute("method", "instantiate")
         .add_attribute("owner", info.sender))
@@ -55,6 +75,30 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
         ExecuteMsg::UpdateTicket(msg) => execute_update_ticket(deps, env, info, msg),
         ExecuteMsg::RemoveTicket { tid } => execute_remove_ticket(deps, env, info, tid),
         ExecuteMsg::DecideWinningBet { tid } => execute_decide_win_bet(deps, env, info, tid),
+        ExecuteMsg::ReportNoShow { tid } => execute_report_no_show(deps, env, info, tid),
+        ExecuteMsg::AddTickets(msgs) => execute_add_tickets(deps, env, info, msgs),
+        ExecuteMsg::UpdateTickets(msgs) => execute_update_tickets(deps, env, info, msgs),
+        ExecuteMsg::CancelTickets { tids } => execute_cancel_tickets(deps, env, info, tids),
+        ExecuteMsg::DecideWinningBets { tids } => execute_decide_win_bets(deps, env, info, tids),
+        ExecuteMsg::SetKeeperReward { amount, bet_fee } => {
+            execute_set_keeper_reward(deps, env, info, amount, bet_fee)
+        }
+        ExecuteMsg::SetTicketLimits(limits) => execute_set_ticket_limits(deps, env, info, limits),
+        ExecuteMsg::AddGrader { address } => execute_add_grader(deps, env, info, address),
+        ExecuteMsg::RemoveGrader { address } => execute_remove_grader(deps, env, info, address),
+        ExecuteMsg::AddArbiter { address } => execute_add_arbiter(deps, env, info, address),
+        ExecuteMsg::RemoveArbiter { address } => execute_remove_arbiter(deps, env, info, address),
+        ExecuteMsg::SetDisputeParams(params) => execute_set_dispute_params(deps, env, info, params),
+
+        // Transaction initiated by grader.
+        ExecuteMsg::GradeSubmission(msg) => execute_grade_submission(deps, env, info, msg),
+
+        // Transaction initiated by arbiter.
+        ExecuteMsg::ResolveDispute {
+            tid,
+            worker,
+            uphold,
+        } => execute_resolve_dispute(deps, env, info, tid, worker, uphold),
 
         // Transaction initiated by "ticket_manager" (user_board -> ticket_manager -> here)
         ExecuteMsg::ReleaseStakeWithSlash(msg) => {
@@ -76,6 +120,13 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
         }
 
         ExecuteMsg::PostConfig(msg) => execute_post_config(deps, env, info, msg),
+
+        ExecuteMsg::MigrateChild {
+            contract,
+            new_code_id,
+            msg,
+        } => execute_migrate_child(deps, env, info, contract, new_code_id, msg),
+        ExecuteMsg::MigrateAll(msg) => execute_migrate_all(deps, env, info, msg),
     }
 }
 
@@ -103,11 +154,12 @@ fn execute_add_ticket(
         None => return Err(TAError::NotInitialized.into()),
     };
 
-    let msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: ticket_manager,
-        msg: to_json_binary(&TicketExecuteMsg::AddTicket(msg))?,
-        funds: vec![],
-    })];
+    // The admin wallet is the creator of the ticket.
+    let msg = AddTicketMsg {
+        creator: Some(info.sender.to_string()),
+        ..msg
+    };
+    let msgs: Vec<CosmosMsg> = vec![TicketManagerContract::new(ticket_manager).add_ticket(msg)?];
 
     Ok(Response::new()
         .add_messages(msgs)
@@ -138,11 +190,7 @@ fn execute_update_ticket(
         None => return Err(TAError::NotInitialized.into()),
     };
 
-    let msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: ticket_manager,
-        msg: to_json_binary(&TicketExecuteMsg::UpdateTicket(msg))?,
-        funds: vec![],
-    })];
+    let msgs: Vec<CosmosMsg> = vec![TicketManagerContract::new(ticket_manager).update_ticket(msg)?];
 
     Ok(Response::new()
         .add_messages(msgs)
@@ -173,11 +221,7 @@ fn execute_remove_ticket(
         None => return Err(TAError::NotInitialized.into()),
     };
 
-    let msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: ticket_manager,
-        msg: to_json_binary(&TicketExecuteMsg::RemoveTicket { tid })?,
-        funds: vec![],
-    })];
+    let msgs: Vec<CosmosMsg> = vec![TicketManagerContract::new(ticket_manager).remove_ticket(tid)?];
 
     Ok(Response::new()
         .add_messages(msgs)
@@ -208,17 +252,502 @@ fn execute_decide_win_bet(
         None => return Err(TAError::NotInitialized.into()),
     };
 
-    let msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: auction_manager,
-        msg: to_json_binary(&AuctionExecuteMsg::DecideWinningBet { tid })?,
-        funds: vec![],
-    })];
+    let msgs: Vec<CosmosMsg> =
+        vec![AuctionManagerContract::new(auction_manager).decide_winning_bet(tid)?];
 
     Ok(Response::new()
         .add_messages(msgs)
         .add_attributes(vec![attr("method", "decide winning bet")]))
 }
 
+// Call the "AddTickets" of "ticket_manager"
+fn execute_add_tickets(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msgs: Vec<AddTicketMsg>,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Call the method of "AddTickets" in ticket_manager, the tickets are validated there.
+    let ticket_manager = match config.ticket_manager {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+
+    // The admin wallet is the creator of the tickets.
+    let msgs = msgs
+        .into_iter()
+        .map(|msg| AddTicketMsg {
+            creator: Some(info.sender.to_string()),
+            ..msg
+        })
+        .collect::<Vec<AddTicketMsg>>();
+    let count = msgs.len();
+    let msgs: Vec<CosmosMsg> = vec![TicketManagerContract::new(ticket_manager).add_tickets(msgs)?];
+
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "add tickets"),
+        attr("count", count.to_string()),
+    ]))
+}
+
+// Call the "UpdateTickets" of "ticket_manager"
+fn execute_update_tickets(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msgs: Vec<UpdateTicketMsg>,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Call the method of "UpdateTickets" in ticket_manager, the updates are validated there.
+    let ticket_manager = match config.ticket_manager {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+
+    let count = msgs.len();
+    let msgs: Vec<CosmosMsg> =
+        vec![TicketManagerContract::new(ticket_manager).update_tickets(msgs)?];
+
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "update tickets"),
+        attr("count", count.to_string()),
+    ]))
+}
+
+// Call the "CancelTickets" of "ticket_manager"
+fn execute_cancel_tickets(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    tids: Vec<u64>,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Call the method of "CancelTickets" in ticket_manager, the ticket ids are validated there.
+    let ticket_manager = match config.ticket_manager {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+
+    let count = tids.len();
+    let msgs: Vec<CosmosMsg> =
+        vec![TicketManagerContract::new(ticket_manager).cancel_tickets(tids)?];
+
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "cancel tickets"),
+        attr("count", count.to_string()),
+    ]))
+}
+
+// Call the "DecideWinningBet" of "auction_manager" for every ticket of the batch.
+// Nothing is decided if any of the tickets is not ready for the decision.
+fn execute_decide_win_bets(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tids: Vec<u64>,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    let (ticket_manager, auction_manager) = match (config.ticket_manager, config.auction_manager) {
+        (Some(t), Some(a)) => (
+            TicketManagerContract::new(t),
+            AuctionManagerContract::new(a),
+        ),
+        _ => return Err(TAError::NotInitialized.into()),
+    };
+
+    // Validation 3: Check if every ticket exists, its bet is finished & has any bet.
+    let timestamp = env.block.time.seconds();
+    validate_batch(
+        &tids,
+        |tid| *tid,
+        |tid| {
+            let ticket = ticket_manager
+                .query_ticket_info(&deps.querier, *tid)
+                .map_err(|_| TAError::NotFound)?;
+            if timestamp < ticket.bet_finish_timestamp {
+                return Err(TAError::BetNotFinished.into());
+            }
+            if auction_manager
+                .query_curr_active_bets(&deps.querier, *tid)
+                .map_err(|_| TAError::NotFound)?
+                .is_empty()
+            {
+                return Err(TAError::NotFound.into());
+            }
+            Ok(())
+        },
+    )?;
+
+    let msgs = tids
+        .iter()
+        .map(|tid| auction_manager.decide_winning_bet(*tid))
+        .collect::<StdResult<Vec<CosmosMsg>>>()?;
+
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "decide winning bets"),
+        attr("count", tids.len().to_string()),
+    ]))
+}
+
+// Call the "UpdateConfig" of "auction_manager"
+fn execute_set_keeper_reward(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    bet_fee: Uint128,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Call the method of "UpdateConfig" in auction_manager
+    let auction_manager = match config.auction_manager {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+
+    let msgs: Vec<CosmosMsg> = vec![AuctionManagerContract::new(auction_manager).update_config(
+        Some(amount),
+        Some(bet_fee),
+        None,
+    )?];
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attributes(vec![attr("method", "set keeper reward")]))
+}
+
+// Call the "SetTicketLimits" of "ticket_manager"
+fn execute_set_ticket_limits(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    limits: TicketLimits,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Call the method of "SetTicketLimits" in ticket_manager
+    let ticket_manager = match config.ticket_manager {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+
+    let msgs: Vec<CosmosMsg> =
+        vec![TicketManagerContract::new(ticket_manager).set_ticket_limits(limits)?];
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attributes(vec![attr("method", "set ticket limits")]))
+}
+
+// Allow the address to grade the submissions.
+fn execute_add_grader(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    let grader = deps.api.addr_validate(&address)?;
+    store_grader(deps.storage, &grader)?;
+
+    Ok(Response::new().add_attributes(vec![attr("method", "add grader"), attr("grader", grader)]))
+}
+
+// Revoke the grader role of the address.
+fn execute_remove_grader(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 3: Check if the address is the grader
+    let grader = deps.api.addr_validate(&address)?;
+    if !is_grader(deps.storage, &grader) {
+        return Err(TAError::NotFound.into());
+    }
+    remove_grader(deps.storage, &grader);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "remove grader"),
+        attr("grader", grader),
+    ]))
+}
+
+// Call the "GradeSubmission" of "ticket_manager"
+fn execute_grade_submission(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: GradeSubmissionMsg,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is the grader
+    let config = read_config(deps.storage)?;
+    if !is_grader(deps.storage, &info.sender) {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    let ticket_manager = match config.ticket_manager {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+
+    // The tx sender is recorded as the grader.
+    let msg = GradeSubmissionMsg {
+        grader: Some(info.sender.to_string()),
+        ..msg
+    };
+    let msgs: Vec<CosmosMsg> =
+        vec![TicketManagerContract::new(ticket_manager).grade_submission(msg)?];
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attributes(vec![attr("method", "grade submission")]))
+}
+
+// Allow the address to resolve the disputes.
+fn execute_add_arbiter(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    let arbiter = deps.api.addr_validate(&address)?;
+    store_arbiter(deps.storage, &arbiter)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "add arbiter"),
+        attr("arbiter", arbiter),
+    ]))
+}
+
+// Revoke the arbiter role of the address.
+fn execute_remove_arbiter(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 3: Check if the address is the arbiter
+    let arbiter = deps.api.addr_validate(&address)?;
+    if !is_arbiter(deps.storage, &arbiter) {
+        return Err(TAError::NotFound.into());
+    }
+    remove_arbiter(deps.storage, &arbiter);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "remove arbiter"),
+        attr("arbiter", arbiter),
+    ]))
+}
+
+// Call the "SetDisputeParams" of "collateral_manager"
+fn execute_set_dispute_params(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    params: DisputeParams,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    let collateral_manager = match config.collateral_manager {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+    let msgs: Vec<CosmosMsg> =
+        vec![CollateralManagerContract::new(collateral_manager).set_dispute_params(params)?];
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attributes(vec![attr("method", "set dispute params")]))
+}
+
+// Call the "ResolveDispute" of "collateral_manager",
+// the reversed slash is undone in the worker's history of "ticket_manager" as well.
+fn execute_resolve_dispute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    tid: u64,
+    worker: String,
+    uphold: bool,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is the arbiter
+    let config = read_config(deps.storage)?;
+    if !is_arbiter(deps.storage, &info.sender) {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    let (ticket_manager, collateral_manager) =
+        match (config.ticket_manager, config.collateral_manager) {
+            (Some(t), Some(c)) => (t, c),
+            _ => return Err(TAError::NotInitialized.into()),
+        };
+    let mut msgs: Vec<CosmosMsg> = vec![CollateralManagerContract::new(collateral_manager)
+        .resolve_dispute(tid, worker.clone(), uphold)?];
+    if !uphold {
+        msgs.push(TicketManagerContract::new(ticket_manager).reverse_slash(tid, worker)?);
+    }
+
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "resolve dispute"),
+        attr("arbiter", info.sender),
+        attr("uphold", uphold.to_string()),
+    ]))
+}
+
+// Call the "ReportNoShow" of "ticket_manager"
+fn execute_report_no_show(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    tid: u64,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Call the method of "ReportNoShow" in ticket_manager
+    let ticket_manager = match config.ticket_manager {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+
+    let msgs: Vec<CosmosMsg> =
+        vec![TicketManagerContract::new(ticket_manager).report_no_show(tid)?];
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attributes(vec![attr("method", "report no-show")]))
+}
+
 // Call the "ReleaseStake" of "collateral_manager"
 fn execute_release_stake_with_slash(
     deps: DepsMut,
@@ -237,10 +766,8 @@ fn execute_release_stake_with_slash(
     }
 
     // Query the stake amount for tid
-    let ticket_info: TicketInfoResponse = deps.querier.query_wasm_smart(
-        ticket_manager,
-        &to_json_binary(&TicketQueryMsg::QueryTicketInfo { tid: msg.tid })?,
-    )?;
+    let ticket_info =
+        TicketManagerContract::new(ticket_manager).query_ticket_info(&deps.querier, msg.tid)?;
     let stake_amount = ticket_info.collateral;
 
     // Query the release amount based on given slash percentage.
@@ -254,15 +781,12 @@ fn execute_release_stake_with_slash(
         Some(v) => v,
         None => return Err(TAError::NotInitialized.into()),
     };
-    let msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: collateral_manager,
-        msg: to_json_binary(&CollateralExecuteMsg::ReleaseStake(ReleaseStakeMsg {
+    let msgs: Vec<CosmosMsg> = vec![CollateralManagerContract::new(collateral_manager)
+        .release_stake(ReleaseStakeMsg {
             tid: msg.tid,
             worker: msg.worker,
             amt: release_amt,
-        }))?,
-        funds: vec![],
-    })];
+        })?];
 
     // TBD: Log every release result.(ticket, worker, stake, slash perc)
 
@@ -294,7 +818,7 @@ fn execute_create_ticket_manager(
             user_board: None,
         })?,
         funds: vec![],
-        label: "".to_string(),
+        label: "ticket_manager".to_string(),
     });
 
     Ok(Response::new()
@@ -326,7 +850,7 @@ fn execute_create_user_board_manager(
             collateral_manater: None,
         })?,
         funds: vec![],
-        label: "".to_string(),
+        label: "user_board".to_string(),
     });
 
     Ok(Response::new()
@@ -355,12 +879,20 @@ fn execute_create_auction_manager(
         None => return Err(TAError::NotInitialized.into()),
     };
 
+    let user_board = match config.user_board {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+
     let msg: CosmosMsg = CosmosMsg::Wasm(WasmMsg::Instantiate {
         admin: Some(env.contract.address.to_string()),
         code_id,
-        msg: to_json_binary(&AuctionInstantiateMsg { ticket_manager })?,
+        msg: to_json_binary(&AuctionInstantiateMsg {
+            ticket_manager,
+            user_board,
+        })?,
         funds: vec![],
-        label: "".to_string(),
+        label: "auction_manager".to_string(),
     });
 
     Ok(Response::new()
@@ -402,12 +934,12 @@ fn execute_create_collateral_manager(
             user_board,
         })?,
         funds: vec![],
-        label: "".to_string(),
+        label: "collateral_manager".to_string(),
     });
 
     Ok(Response::new()
         .add_message(msg)
-        .add_attribute("method", "instantiate auction manager"))
+        .add_attribute("method", "instantiate collateral manager"))
 }
 
 fn execute_post_config(
@@ -431,9 +963,127 @@ fn execute_post_config(
     config.ticket_manager = msg.ticket_manager;
     config.user_board = msg.user_board;
 
+    // Register the addresses in the children(they only accept the config from admin_board).
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if let Some(ticket_manager) = config.ticket_manager.clone() {
+        msgs.push(
+            TicketManagerContract::new(ticket_manager).post_config(TicketPostConfigMsg {
+                auction_manager: config.auction_manager.clone(),
+                user_board: config.user_board.clone(),
+                collateral_manager: config.collateral_manager.clone(),
+            })?,
+        );
+    }
+    if let Some(user_board) = config.user_board.clone() {
+        msgs.push(
+            UserBoardContract::new(user_board).post_config(UserBoardPostConfigMsg {
+                ticket_manager: config.ticket_manager.clone(),
+                collateral_manager: config.collateral_manager.clone(),
+                auction_manager: config.auction_manager.clone(),
+            })?,
+        );
+    }
+    if let (Some(auction_manager), Some(user_board)) =
+        (config.auction_manager.clone(), config.user_board.clone())
+    {
+        msgs.push(AuctionManagerContract::new(auction_manager).update_config(
+            None,
+            None,
+            Some(user_board),
+        )?);
+    }
+
     store_config(deps.storage, config)?;
 
-    Ok(Response::new().add_attribute("method", "PostConfig"))
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("method", "PostConfig"))
+}
+
+// Migrate the contract created by admin_board. (admin_board is the wasm admin)
+fn execute_migrate_child(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract: ChildKind,
+    new_code_id: u64,
+    msg: Binary,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    let contract_addr = match config.child(contract) {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+
+    let msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Migrate {
+        contract_addr,
+        new_code_id,
+        msg,
+    })];
+
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "migrate child"),
+        attr("new_code_id", new_code_id.to_string()),
+    ]))
+}
+
+// Migrate several contracts created by admin_board at once.
+fn execute_migrate_all(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: MigrateAllMsg,
+) -> StdResult<Response> {
+    // Validation 1: Check if the funds are provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2: Check if the tx sender is real admin wallet
+    let config = read_config(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    let code_ids = [
+        (ChildKind::TicketManager, msg.ticket_manager),
+        (ChildKind::UserBoard, msg.user_board),
+        (ChildKind::AuctionManager, msg.auction_manager),
+        (ChildKind::CollateralManager, msg.collateral_manager),
+    ];
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    for (kind, code_id) in code_ids {
+        let new_code_id = match code_id {
+            Some(v) => v,
+            None => continue,
+        };
+        let contract_addr = match config.child(kind) {
+            Some(v) => v,
+            None => return Err(TAError::NotInitialized.into()),
+        };
+        msgs.push(CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr,
+            new_code_id,
+            msg: to_json_binary(&Empty {})?,
+        }));
+    }
+
+    let migrated = msgs.len().to_string();
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "migrate all"),
+        attr("migrated", migrated),
+    ]))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -441,6 +1091,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::QueryTicketInfo { tid } => to_json_binary(&query_ticket_info(deps, tid)?),
         QueryMsg::QueryTicketWorker { tid } => to_json_binary(&query_ticket_worker(deps, tid)?),
+        QueryMsg::QueryChildren {} => to_json_binary(&query_children(deps)?),
+        QueryMsg::QueryGraders {} => to_json_binary(&read_graders(deps.storage)?),
+        QueryMsg::QueryArbiters {} => to_json_binary(&read_arbiters(deps.storage)?),
     }
 }
 
@@ -452,11 +1105,7 @@ fn query_ticket_info(deps: Deps, tid: u64) -> StdResult<TicketInfoResponse> {
         None => return Err(TAError::NotInitialized.into()),
     };
 
-    let ticket_info: TicketInfoResponse = deps.querier.query_wasm_smart(
-        ticket_manager,
-        &to_json_binary(&TicketQueryMsg::QueryTicketInfo { tid })?,
-    )?;
-    Ok(ticket_info)
+    TicketManagerContract::new(ticket_manager).query_ticket_info(&deps.querier, tid)
 }
 
 // Query the worker assigned on the task (Call the "QueryTicketWorker" of "ticket_manger")
@@ -467,14 +1116,32 @@ fn query_ticket_worker(deps: Deps, tid: u64) -> StdResult<String> {
         None => return Err(TAError::NotInitialized.into()),
     };
 
-    let worker: String = deps.querier.query_wasm_smart(
-        ticket_manager,
-        &to_json_binary(&TicketQueryMsg::QueryTicketWorker { tid })?,
-    )?;
-    Ok(worker)
+    TicketManagerContract::new(ticket_manager).query_ticket_worker(&deps.querier, tid)
+}
+
+// Query the code id & the cw2 version of the contracts created by admin_board.
+fn query_children(deps: Deps) -> StdResult<ChildrenResponse> {
+    let config = read_config(deps.storage)?;
+    let mut children = vec![];
+    for kind in ChildKind::ALL {
+        let address = match config.child(kind) {
+            Some(v) => v,
+            None => continue,
+        };
+        let code_id = deps.querier.query_wasm_contract_info(&address)?.code_id;
+        let info = cw2::query_contract_info(&deps.querier, &address).ok();
+        children.push(ChildInfo {
+            kind,
+            address,
+            code_id,
+            contract: info.as_ref().map(|i| i.contract.clone()),
+            version: info.map(|i| i.version),
+        });
+    }
+    Ok(ChildrenResponse { children })
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
-    Ok(Response::new())
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+    migrate_contract(deps, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
@@ -1,8 +1,10 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::{Addr, StdResult, Storage};
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Order, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+
+use ticket_auction::admin_board::ChildKind;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
@@ -13,6 +15,18 @@ pub struct Config {
     pub user_board: Option<String>,
 }
 
+impl Config {
+    // Address of the contract created by admin_board.
+    pub fn child(&self, kind: ChildKind) -> Option<String> {
+        match kind {
+            ChildKind::TicketManager => self.ticket_manager.clone(),
+            ChildKind::UserBoard => self.user_board.clone(),
+            ChildKind::AuctionManager => self.auction_manager.clone(),
+            ChildKind::CollateralManager => self.collateral_manager.clone(),
+        }
+    }
+}
+
 pub const CONFIG: Item<Config> = Item::new("config");
 
 // **=================================================
@@ -27,3 +41,59 @@ pub fn store_config(storage: &mut dyn Storage, config: Config) -> StdResult<()>
 pub fn read_config(storage: &dyn Storage) -> StdResult<Config> {
     CONFIG.load(storage)
 }
+
+// Addresses allowed to grade the submissions of the "Manual" tickets.
+pub const GRADERS: Map<&Addr, bool> = Map::new("graders");
+
+// **=================================================
+// ** GRADERS: Read and write operations     ========
+// **=================================================
+// Store the grader
+pub fn store_grader(storage: &mut dyn Storage, grader: &Addr) -> StdResult<()> {
+    GRADERS.save(storage, grader, &true)
+}
+
+// Remove the grader
+pub fn remove_grader(storage: &mut dyn Storage, grader: &Addr) {
+    GRADERS.remove(storage, grader)
+}
+
+// Check if the address is the grader
+pub fn is_grader(storage: &dyn Storage, addr: &Addr) -> bool {
+    GRADERS.has(storage, addr)
+}
+
+// Read all of the graders
+pub fn read_graders(storage: &dyn Storage) -> StdResult<Vec<Addr>> {
+    GRADERS
+        .keys(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+// Addresses allowed to resolve the disputes of the slashes.
+pub const ARBITERS: Map<&Addr, bool> = Map::new("arbiters");
+
+// **=================================================
+// ** ARBITERS: Read and write operations    ========
+// **=================================================
+// Store the arbiter
+pub fn store_arbiter(storage: &mut dyn Storage, arbiter: &Addr) -> StdResult<()> {
+    ARBITERS.save(storage, arbiter, &true)
+}
+
+// Remove the arbiter
+pub fn remove_arbiter(storage: &mut dyn Storage, arbiter: &Addr) {
+    ARBITERS.remove(storage, arbiter)
+}
+
+// Check if the address is the arbiter
+pub fn is_arbiter(storage: &dyn Storage, addr: &Addr) -> bool {
+    ARBITERS.has(storage, addr)
+}
+
+// Read all of the arbiters
+pub fn read_arbiters(storage: &dyn Storage) -> StdResult<Vec<Addr>> {
+    ARBITERS
+        .keys(storage, None, None, Order::Ascending)
+        .collect()
+}
@@ -1,23 +1,39 @@
+use std::cmp::Ordering;
+
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    attr, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
-    WasmMsg,
+    attr, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    Response, StdResult, Uint128,
 };
+use cw2::set_contract_version;
 
 use crate::state::{
-    read_bets_ticket, read_config, read_curr_avail_tickets, remove_bets_ticket, save_bets_ticket,
-    save_config, BetDetail, Config,
-};
-use ticket_auction::ticket_manager::{
-    ExecuteMsg as TicketExecuteMsg, QueryMsg as TicketQueryMsg, TicketInfoResponse,
-    TicketWorkerPair, TicketsResponse,
+    read_bets_ticket, read_config, read_curr_avail_tickets, read_decision, read_extension,
+    read_fee_pool, read_worker_bets, remove_bets_ticket, save_bets_ticket, save_config,
+    save_decision, save_extension, save_fee_pool, save_worker_bets, Config,
 };
+use ticket_auction::helpers::TicketManagerContract;
+use ticket_auction::ticket_manager::{BidScoring, TicketInfoResponse, TicketWorkerPair};
 use ticket_auction::{
-    auction_manager::{ExecuteMsg, InstantiateMsg, MigrateMsg, PlaceBetMsg, QueryMsg},
+    auction_manager::{
+        BetDetail, BidScore, BidScoresResponse, ExecuteMsg, FeePoolResponse, InstantiateMsg,
+        MigrateMsg, PlaceBetMsg, QueryMsg, WorkerBet,
+    },
     error::TAError,
+    migration::migrate_contract,
 };
 
+const BASE_DENOM: &str = "uluna";
+
+// Number of the tickets settled by "SettleExpired" in one transaction.
+const DEFAULT_SETTLE_LIMIT: u32 = 10;
+const MAX_SETTLE_LIMIT: u32 = 30;
+
+// Contract info recorded by cw2, checked on the migration.
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -29,6 +45,9 @@ pub fn instantiate(
     let config = Config {
         admin_board: info.sender.to_string(),
         ticket_manager: msg.ticket_manager,
+        user_board: Some(msg.user_board),
+        keeper_reward: Uint128::zero(),
+        bet_fee: Uint128::zero(),
     };
 
     save_config(deps.storage, config)?;
@@ -36,6 +55,8 @@ pub fn instantiate(
     // Initialize variables
     save_bets_ticket(deps.storage, 0, vec![])?;
 
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     Ok(Response::new()
         .add_attribute("method", "instantiate")
         .add_attribute("admin", info.sender))
@@ -45,98 +66,442 @@ pub fn instantiate(
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
     match msg {
         // Transaction initiated by user_board contract.
-        ExecuteMsg::PlaceBet(msg) => place_bet(deps, info, msg),
+        ExecuteMsg::PlaceBet(msg) => place_bet(deps, env, info, msg),
 
-        // Transaction intiated by admin_board contract.
+        // Transaction intiated by admin_board contract or keeper.
         ExecuteMsg::DecideWinningBet { tid } => decide_winning_bet(deps, env, info, tid),
+        ExecuteMsg::SettleExpired { limit } => settle_expired(deps, env, info, limit),
+
+        // Transaction intiated by admin_board contract.
+        ExecuteMsg::UpdateConfig {
+            keeper_reward,
+            bet_fee,
+            user_board,
+        } => update_config(deps, info, keeper_reward, bet_fee, user_board),
     }
 }
 
 // Place the bet for the given ticket id
-// Invoked by user_board contract
-fn place_bet(deps: DepsMut, info: MessageInfo, msg: PlaceBetMsg) -> StdResult<Response> {
-    // Validation 1: Check if funds are provided.
-    if !info.funds.is_empty() {
-        return Err(TAError::UnnecessaryFunds.into());
+// Invoked by user_board contract, with the bet fee sent as "funds".
+fn place_bet(deps: DepsMut, env: Env, info: MessageInfo, msg: PlaceBetMsg) -> StdResult<Response> {
+    // Validation 1: Check if the sent funds are the bet fee.
+    let config = read_config(deps.storage)?;
+    let base_coin = info
+        .funds
+        .into_iter()
+        .filter(|c| c.denom == *BASE_DENOM)
+        .collect::<Vec<Coin>>();
+    let sent = base_coin.first().map(|c| c.amount).unwrap_or_default();
+    if base_coin.len() > 1 || sent != config.bet_fee {
+        return Err(TAError::InsufficientFunds.into());
     }
 
-    // Validation 2: Check if the ticket id is valid.
-    let config = read_config(deps.storage)?;
-    let ticket_manager = config.ticket_manager;
-    let tickets: TicketsResponse = deps.querier.query_wasm_smart(
-        ticket_manager,
-        &to_json_binary(&TicketQueryMsg::QueryTickets {})?,
-    )?;
-    let is_exist = tickets.tickets.iter().any(|v| v.id == msg.ticket_id);
-    if !is_exist {
-        return Err(TAError::NotFound.into());
+    // Validation 2: Check if the tx sender is user_board.
+    let user_board = match &config.user_board {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+    if info.sender.as_str() != user_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 3: Check if the ticket id is valid.
+    let ticket_manager = TicketManagerContract::new(config.ticket_manager.clone());
+    let ticket_info = match ticket_manager.query_ticket_info(&deps.querier, msg.ticket_id) {
+        Ok(v) => v,
+        Err(_) => return Err(TAError::NotFound.into()),
+    };
+
+    // Validation 4: Check if the worker is valid wallet
+    let worker = match deps.api.addr_validate(&msg.workder) {
+        Ok(v) => v,
+        Err(_) => return Err(TAError::InvalidAddress.into()),
+    };
+
+    // Validation 5: Check if the worker meets the ticket's reputation requirement
+    if let Some(min_reputation) = ticket_info.auction.min_reputation {
+        let reputation = ticket_manager.query_worker_reputation(&deps.querier, &worker)?;
+        if reputation.score < min_reputation {
+            return Err(TAError::InsufficientReputation.into());
+        }
     }
 
-    // Validation 3: Check if tx sender is valid wallet
-    if deps.api.addr_canonicalize(info.sender.as_str()).is_err() {
-        return Err(TAError::InvalidAddress.into());
+    // Validation 6: Check if the bet is still open
+    let timestamp = env.block.time.seconds();
+    if timestamp >= ticket_info.bet_finish_timestamp {
+        return Err(TAError::BetFinished.into());
+    }
+
+    // Validation 7: Check the bet amount with the ticket's auction rules.
+    // The worker's previous bet is replaced by the new one.
+    let params = ticket_info.auction.clone();
+    let mut bets = read_bets_ticket(deps.storage, msg.ticket_id)?
+        .into_iter()
+        .filter(|b| b.worker != worker)
+        .collect::<Vec<BetDetail>>();
+    if msg.bet_amount < params.min_bid.unwrap_or(1).max(1) {
+        return Err(TAError::InvalidBetAmount.into());
+    }
+    if let Some(reserve_price) = params.reserve_price {
+        if msg.bet_amount > reserve_price {
+            return Err(TAError::InvalidBetAmount.into());
+        }
+    }
+    // The bet undercuts the worst bet still winning a slot(the "slots"-th lowest) by
+    // "min_decrement", no decrement is required while any slot has no bet.
+    let mut amounts = bets.iter().map(|b| b.bet_amt).collect::<Vec<u64>>();
+    amounts.sort_unstable();
+    let best_bet = amounts.first().copied();
+    let worst_winning_bet = amounts.get(ticket_info.slots.max(1) as usize - 1).copied();
+    if let (Some(min_decrement), Some(worst_winning_bet)) =
+        (params.min_decrement, worst_winning_bet)
+    {
+        if msg.bet_amount.saturating_add(min_decrement) > worst_winning_bet {
+            return Err(TAError::InvalidBetAmount.into());
+        }
     }
 
     // Save bet.
-    let mut bets = read_bets_ticket(deps.storage, msg.ticket_id)?;
     bets.push(BetDetail {
-        worker: info.sender,
+        worker: worker.clone(),
         bet_amt: msg.bet_amount,
+        placed_at: timestamp,
     });
     save_bets_ticket(deps.storage, msg.ticket_id, bets)?;
 
-    Ok(Response::new().add_attributes(vec![attr("method", "place_bet")]))
+    // Collect the bet fee.
+    let fee_pool = read_fee_pool(deps.storage)?;
+    save_fee_pool(deps.storage, fee_pool.checked_add(sent)?)?;
+
+    // Index the bet for the worker.
+    let mut worker_bets = read_worker_bets(deps.storage, &worker)?;
+    if !worker_bets.contains(&msg.ticket_id) {
+        worker_bets.push(msg.ticket_id);
+        save_worker_bets(deps.storage, &worker, worker_bets)?;
+    }
+
+    // Extend the bet finish timestamp when the new best bet comes in the soft close window.
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let is_best_bet = best_bet.is_none_or(|b| msg.bet_amount < b);
+    if let (Some(soft_close), true) = (params.soft_close, is_best_bet) {
+        let bet_finish = ticket_info.bet_finish_timestamp;
+        let extended = read_extension(deps.storage, msg.ticket_id)?;
+        // The winner keeps the minimum work duration of the ticket limits(at least 1 second).
+        let min_work = ticket_manager
+            .query_ticket_limits(&deps.querier)?
+            .min_work_duration
+            .max(1);
+        let latest_finish = ticket_info.close_timestamp.saturating_sub(min_work);
+        let extension = soft_close
+            .extension
+            .min(soft_close.max_extension.saturating_sub(extended))
+            .min(latest_finish.saturating_sub(bet_finish));
+        if timestamp.saturating_add(soft_close.window) >= bet_finish && extension > 0 {
+            save_extension(deps.storage, msg.ticket_id, extended + extension)?;
+            msgs.push(ticket_manager.extend_bet_finish(msg.ticket_id, bet_finish + extension)?);
+        }
+    }
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attributes(vec![attr("method", "place_bet")]))
 }
 
 // Decide the winning bet for given ticket id
-// Invoked by admin_board contract
-fn decide_winning_bet(deps: DepsMut, env: Env, info: MessageInfo, tid: u64) -> StdResult<Response> {
-    // Validation 1. Check if tx sender is admin.
+// Invoked by admin_board contract, or by anyone once "bet_finish_timestamp" has passed.
+// The keeper(not admin_board) receives the keeper reward.
+fn decide_winning_bet(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tid: u64,
+) -> StdResult<Response> {
+    // Validation 1. Check if any funds are provided.
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2. Given ticket id is valid for decision(If bet_finish_timestamp is passed)
     let config = read_config(deps.storage)?;
+    let ticket_info = TicketManagerContract::new(config.ticket_manager.clone())
+        .query_ticket_info(&deps.querier, tid)?;
+    if env.block.time.seconds() < ticket_info.bet_finish_timestamp {
+        return Err(TAError::BetNotFinished.into());
+    }
+
+    // Decide winning bets
+    let (mut msgs, winning_bets) = settle_ticket(deps.branch(), &config, &ticket_info)?;
+
+    // Reward the keeper.
     if info.sender.to_string() != config.admin_board {
-        return Err(TAError::NotAuthorized.into());
+        msgs.extend(keeper_reward_msgs(deps.branch(), &config, &info.sender, 1)?);
     }
 
-    // Validation 2. Check if any funds are provided.
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "win_bet"),
+        attr(
+            "worker",
+            winning_bets
+                .iter()
+                .map(|b| b.worker.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        ),
+        attr("score", winning_bets[0].total_score.to_string()),
+    ]))
+}
+
+// Decide the winning bets of the tickets whose "bet_finish_timestamp" has passed.
+// Invoked by anyone(keeper).
+fn settle_expired(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit: Option<u32>,
+) -> StdResult<Response> {
+    // Validation 1. Check if any funds are provided.
     if !info.funds.is_empty() {
         return Err(TAError::UnnecessaryFunds.into());
     }
 
-    // Validation 3. Given ticket id is valid for decision(If bet_finish_timestamp is passed)
-    let ticket_info: TicketInfoResponse = deps.querier.query_wasm_smart(
-        config.ticket_manager.clone(),
-        &to_json_binary(&TicketQueryMsg::QueryTicketInfo { tid })?,
-    )?;
-    if env.block.time.seconds() < ticket_info.bet_finish_timestamp {
-        return Err(TAError::BetNotFinished.into());
+    let config = read_config(deps.storage)?;
+    let limit = limit.unwrap_or(DEFAULT_SETTLE_LIMIT).min(MAX_SETTLE_LIMIT) as usize;
+    let timestamp = env.block.time.seconds();
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut settled: Vec<String> = vec![];
+    for tid in read_curr_avail_tickets(deps.storage)? {
+        if settled.len() >= limit {
+            break;
+        }
+        if read_bets_ticket(deps.storage, tid)?.is_empty() {
+            continue;
+        }
+        // Skip the tickets removed from ticket_manager & still in auction.
+        let ticket_info = match TicketManagerContract::new(config.ticket_manager.clone())
+            .query_ticket_info(&deps.querier, tid)
+        {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+        if timestamp < ticket_info.bet_finish_timestamp {
+            continue;
+        }
+
+        let (settle_msgs, _) = settle_ticket(deps.branch(), &config, &ticket_info)?;
+        msgs.extend(settle_msgs);
+        settled.push(tid.to_string());
     }
 
-    // Decide winning bet
+    // Reward the keeper for every settled ticket.
+    if info.sender.to_string() != config.admin_board && !settled.is_empty() {
+        msgs.extend(keeper_reward_msgs(
+            deps.branch(),
+            &config,
+            &info.sender,
+            settled.len() as u128,
+        )?);
+    }
+
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "settle_expired"),
+        attr("settled", settled.join(",")),
+    ]))
+}
+
+// Choose the winning bets of the ticket("slots" highest scores), clear the bets
+// & prepare the msgs to save the ticket-worker pairs.
+fn settle_ticket(
+    deps: DepsMut,
+    config: &Config,
+    ticket_info: &TicketInfoResponse,
+) -> StdResult<(Vec<CosmosMsg>, Vec<BidScore>)> {
+    let tid = ticket_info.id;
+
     // Get the bets for the ticket
     let curr_bets = read_bets_ticket(deps.storage, tid)?;
 
-    // Choose the winning bet(lowest bet amount)
-    let winning_bet = match curr_bets.iter().min_by(|x, y| x.bet_amt.cmp(&y.bet_amt)) {
-        Some(v) => v,
-        None => return Err(TAError::NotFound.into()),
-    };
+    // Choose the winning bets(highest scores, the lowest bet amounts without the scoring rule)
+    let scores = score_bets(
+        deps.as_ref(),
+        &config.ticket_manager,
+        &curr_bets,
+        &ticket_info.auction.scoring,
+    )?;
+    if scores.is_empty() {
+        return Err(TAError::NotFound.into());
+    }
+    let winning_bets = scores
+        .iter()
+        .take(ticket_info.slots as usize)
+        .cloned()
+        .collect::<Vec<BidScore>>();
 
     // Clear the bets data & prepare the msgs to return collaterals.
     remove_bets_ticket(deps.storage, tid)?;
-    let msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: config.ticket_manager,
-        msg: to_json_binary(&TicketExecuteMsg::SaveTicketWorker(TicketWorkerPair {
-            tid,
-            worker: winning_bet.worker.to_string(),
-        }))?,
-        funds: vec![],
-    })];
+    save_decision(deps.storage, tid, scores)?;
+    for bet in curr_bets.iter() {
+        let worker_bets = read_worker_bets(deps.storage, &bet.worker)?
+            .into_iter()
+            .filter(|t| *t != tid)
+            .collect::<Vec<u64>>();
+        save_worker_bets(deps.storage, &bet.worker, worker_bets)?;
+    }
+    let msgs = winning_bets
+        .iter()
+        .map(|bet| {
+            TicketManagerContract::new(config.ticket_manager.clone()).save_ticket_worker(
+                TicketWorkerPair {
+                    tid,
+                    worker: bet.worker.to_string(),
+                },
+            )
+        })
+        .collect::<StdResult<Vec<CosmosMsg>>>()?;
 
     // TODO: Prepare msgs to release the stakes of failed bet.
 
-    Ok(Response::new()
-        .add_messages(msgs)
-        .add_attributes(vec![attr("method", "win_bet")]))
+    Ok((msgs, winning_bets))
+}
+
+// Pay the keeper reward for the settled tickets out of the fee pool.
+// Nothing is paid when the reward is not set or the fee pool is insufficient.
+fn keeper_reward_msgs(
+    deps: DepsMut,
+    config: &Config,
+    keeper: &Addr,
+    settled: u128,
+) -> StdResult<Vec<CosmosMsg>> {
+    let reward = config.keeper_reward.checked_mul(Uint128::from(settled))?;
+    if reward.is_zero() {
+        return Ok(vec![]);
+    }
+    let fee_pool = read_fee_pool(deps.storage)?;
+    if fee_pool < reward {
+        return Ok(vec![]);
+    }
+    save_fee_pool(deps.storage, fee_pool - reward)?;
+    Ok(vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: keeper.to_string(),
+        amount: vec![Coin {
+            denom: BASE_DENOM.to_string(),
+            amount: reward,
+        }],
+    })])
+}
+
+// Update the keeper reward & the bet fee.
+// Invoked by admin_board contract
+fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    keeper_reward: Option<Uint128>,
+    bet_fee: Option<Uint128>,
+    user_board: Option<String>,
+) -> StdResult<Response> {
+    // Validation 1. Check if any funds are provided.
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2. Check if tx sender is admin.
+    let mut config = read_config(deps.storage)?;
+    if info.sender.to_string() != config.admin_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 3. Check if the user_board is valid address.
+    if let Some(user_board) = user_board {
+        deps.api.addr_validate(&user_board)?;
+        config.user_board = Some(user_board);
+    }
+    if let Some(keeper_reward) = keeper_reward {
+        config.keeper_reward = keeper_reward;
+    }
+    if let Some(bet_fee) = bet_fee {
+        config.bet_fee = bet_fee;
+    }
+    save_config(deps.storage, config)?;
+
+    Ok(Response::new().add_attribute("method", "update_config"))
+}
+
+// Score the bets with the ticket's scoring rule & rank them. (see "rank_bids")
+// Without the rule, only the price is scored (the lowest bet amount gets the highest score).
+fn score_bets(
+    deps: Deps,
+    ticket_manager: &str,
+    bets: &[BetDetail],
+    scoring: &Option<BidScoring>,
+) -> StdResult<Vec<BidScore>> {
+    let scoring = scoring.clone().unwrap_or(BidScoring {
+        price_weight: 1,
+        completion_weight: 0,
+        punctuality_weight: 0,
+    });
+    let weight_sum = scoring
+        .price_weight
+        .checked_add(scoring.completion_weight)
+        .and_then(|sum| sum.checked_add(scoring.punctuality_weight))
+        .ok_or(TAError::InvalidAuctionParams)?;
+    let lowest_bet = bets.iter().map(|b| b.bet_amt).min().unwrap_or_default();
+
+    let mut scores: Vec<BidScore> = vec![];
+    for bet in bets.iter() {
+        let price_score = if bet.bet_amt == 0 {
+            1000
+        } else {
+            (lowest_bet as u128 * 1000 / bet.bet_amt as u128) as u64
+        };
+
+        // Worker history is only needed when it is weighted.
+        let (completion_score, punctuality_score) =
+            if scoring.completion_weight > 0 || scoring.punctuality_weight > 0 {
+                let reputation = TicketManagerContract::new(ticket_manager)
+                    .query_worker_reputation(&deps.querier, &bet.worker)?;
+                (
+                    reputation.stats.completion_rate(),
+                    reputation.stats.punctuality_rate(),
+                )
+            } else {
+                (0, 0)
+            };
+
+        let total_score = if weight_sum == 0 {
+            price_score
+        } else {
+            ((scoring.price_weight as u128 * price_score as u128
+                + scoring.completion_weight as u128 * completion_score as u128
+                + scoring.punctuality_weight as u128 * punctuality_score as u128)
+                / weight_sum as u128) as u64
+        };
+
+        scores.push(BidScore {
+            worker: bet.worker.to_string(),
+            bet_amt: bet.bet_amt,
+            price_score,
+            completion_score,
+            punctuality_score,
+            total_score,
+            placed_at: bet.placed_at,
+        });
+    }
+    scores.sort_by(rank_bids);
+    Ok(scores)
+}
+
+// Order of the bets, the best bet first.
+// Ties are broken deterministically:
+//   1. higher total score
+//   2. lower bet amount
+//   3. earlier bet time
+//   4. lower worker address (lexicographic)
+fn rank_bids(x: &BidScore, y: &BidScore) -> Ordering {
+    y.total_score
+        .cmp(&x.total_score)
+        .then(x.bet_amt.cmp(&y.bet_amt))
+        .then(x.placed_at.cmp(&y.placed_at))
+        .then(x.worker.cmp(&y.worker))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -144,9 +509,24 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::BetAvailableTickets => to_json_binary(&query_bet_avail_tickets(deps)?),
         QueryMsg::CurrActiveBets { tid } => to_json_binary(&query_curr_active_bets(deps, tid)?),
+        QueryMsg::WorkerActiveBets { worker } => {
+            to_json_binary(&query_worker_active_bets(deps, worker)?)
+        }
+        QueryMsg::BidScores { tid } => to_json_binary(&query_bid_scores(deps, tid)?),
+        QueryMsg::FeePool => to_json_binary(&query_fee_pool(deps)?),
     }
 }
 
+// Query the bet fee, the keeper reward & the fee pool they are paid out of.
+fn query_fee_pool(deps: Deps) -> StdResult<FeePoolResponse> {
+    let config = read_config(deps.storage)?;
+    Ok(FeePoolResponse {
+        bet_fee: config.bet_fee,
+        keeper_reward: config.keeper_reward,
+        fee_pool: read_fee_pool(deps.storage)?,
+    })
+}
+
 // Query the bet available tickets.
 fn query_bet_avail_tickets(deps: Deps) -> StdResult<Vec<u64>> {
     // Query the tickets(ids) which currently have bets.
@@ -161,7 +541,51 @@ fn query_curr_active_bets(deps: Deps, tid: u64) -> StdResult<Vec<BetDetail>> {
     Ok(curr_bets)
 }
 
+// Query the active bets placed by the worker.
+fn query_worker_active_bets(deps: Deps, worker: String) -> StdResult<Vec<WorkerBet>> {
+    let worker = deps.api.addr_validate(&worker)?;
+    let tids = read_worker_bets(deps.storage, &worker)?;
+
+    let mut worker_bets: Vec<WorkerBet> = vec![];
+    for tid in tids {
+        let bets = read_bets_ticket(deps.storage, tid)?;
+        if let Some(bet) = bets.into_iter().find(|b| b.worker == worker) {
+            worker_bets.push(WorkerBet {
+                tid,
+                bet_amt: bet.bet_amt,
+            });
+        }
+    }
+    Ok(worker_bets)
+}
+
+// Query how the bets for ticket id are (or were) scored.
+fn query_bid_scores(deps: Deps, tid: u64) -> StdResult<BidScoresResponse> {
+    let config = read_config(deps.storage)?;
+    let ticket_info = TicketManagerContract::new(config.ticket_manager.clone())
+        .query_ticket_info(&deps.querier, tid)?;
+    let scoring = ticket_info.auction.scoring;
+
+    if let Some(scores) = read_decision(deps.storage, tid)? {
+        return Ok(BidScoresResponse {
+            tid,
+            scoring,
+            decided: true,
+            scores,
+        });
+    }
+
+    let curr_bets = read_bets_ticket(deps.storage, tid)?;
+    let scores = score_bets(deps, &config.ticket_manager, &curr_bets, &scoring)?;
+    Ok(BidScoresResponse {
+        tid,
+        scoring,
+        decided: false,
+        scores,
+    })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+    migrate_contract(deps, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
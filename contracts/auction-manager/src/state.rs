@@ -1,13 +1,24 @@
-use cosmwasm_std::{Addr, Order, StdResult, Storage};
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use cw_storage_plus::{Item, Map};
+use ticket_auction::auction_manager::{BetDetail, BidScore};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
     pub admin_board: String,
     pub ticket_manager: String,
+    // The only sender of "PlaceBet", which checks the worker's stake first.
+    // None for the contract instantiated without it, until set by "UpdateConfig".
+    #[serde(default)]
+    pub user_board: Option<String>,
+    // Paid(uluna) to the keeper for every settled ticket, out of the fee pool.
+    #[serde(default)]
+    pub keeper_reward: Uint128,
+    // Sent(uluna) with every bet & added to the fee pool.
+    #[serde(default)]
+    pub bet_fee: Uint128,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -27,12 +38,6 @@ pub fn read_config(storage: &dyn Storage) -> StdResult<Config> {
 }
 
 // Bets storage
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct BetDetail {
-    pub worker: Addr,
-    pub bet_amt: u64,
-}
-
 pub const BETS: Map<u64, Vec<BetDetail>> = Map::new("bets");
 
 // **=================================================
@@ -47,7 +52,7 @@ pub fn save_bets_ticket(
 }
 
 pub fn read_bets_ticket(storage: &dyn Storage, tid: u64) -> StdResult<Vec<BetDetail>> {
-    BETS.load(storage, tid)
+    Ok(BETS.may_load(storage, tid)?.unwrap_or_default())
 }
 
 pub fn remove_bets_ticket(storage: &mut dyn Storage, tid: u64) -> StdResult<()> {
@@ -60,3 +65,59 @@ pub fn read_curr_avail_tickets(storage: &dyn Storage) -> StdResult<Vec<u64>> {
     let keys = keys.into_iter().map(|v| v.unwrap()).collect::<Vec<u64>>();
     Ok(keys)
 }
+
+// Tickets on which the worker has active bets.
+pub const WORKER_BETS: Map<&Addr, Vec<u64>> = Map::new("worker_bets");
+
+// **=================================================
+// ** Worker bets: Read and write operations  ========
+// **=================================================
+pub fn save_worker_bets(storage: &mut dyn Storage, worker: &Addr, tids: Vec<u64>) -> StdResult<()> {
+    WORKER_BETS.save(storage, worker, &tids)
+}
+
+pub fn read_worker_bets(storage: &dyn Storage, worker: &Addr) -> StdResult<Vec<u64>> {
+    Ok(WORKER_BETS.may_load(storage, worker)?.unwrap_or_default())
+}
+
+// Bet scores recorded at the winning bet decision.
+pub const DECISIONS: Map<u64, Vec<BidScore>> = Map::new("decisions");
+
+// **=================================================
+// ** Decisions: Read and write operations    ========
+// **=================================================
+pub fn save_decision(storage: &mut dyn Storage, tid: u64, scores: Vec<BidScore>) -> StdResult<()> {
+    DECISIONS.save(storage, tid, &scores)
+}
+
+pub fn read_decision(storage: &dyn Storage, tid: u64) -> StdResult<Option<Vec<BidScore>>> {
+    DECISIONS.may_load(storage, tid)
+}
+
+// Total seconds by which the bet finish timestamp was extended. (soft close)
+pub const EXTENSIONS: Map<u64, u64> = Map::new("extensions");
+
+// **=================================================
+// ** Extensions: Read and write operations   ========
+// **=================================================
+pub fn save_extension(storage: &mut dyn Storage, tid: u64, extended: u64) -> StdResult<()> {
+    EXTENSIONS.save(storage, tid, &extended)
+}
+
+pub fn read_extension(storage: &dyn Storage, tid: u64) -> StdResult<u64> {
+    Ok(EXTENSIONS.may_load(storage, tid)?.unwrap_or_default())
+}
+
+// Bet fees collected & not yet paid as the keeper rewards.
+pub const FEE_POOL: Item<Uint128> = Item::new("fee_pool");
+
+// **=================================================
+// ** Fee pool: Read and write operations     ========
+// **=================================================
+pub fn save_fee_pool(storage: &mut dyn Storage, fee_pool: Uint128) -> StdResult<()> {
+    FEE_POOL.save(storage, &fee_pool)
+}
+
+pub fn read_fee_pool(storage: &dyn Storage) -> StdResult<Uint128> {
+    Ok(FEE_POOL.may_load(storage)?.unwrap_or_default())
+}
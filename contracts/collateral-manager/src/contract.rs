@@ -4,15 +4,30 @@ use cosmwasm_std::{
     attr, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
     Response, StdResult, Uint128,
 };
+use cw2::set_contract_version;
 
-use crate::state::{read_config, read_stakes, store_config, store_stakes, Config};
+use crate::migrations::MIGRATIONS;
+use crate::state::{
+    read_config, read_dispute_params, read_disputes, read_held_slash, read_stake_amount,
+    read_stakes, read_treasury, read_worker_stakes, remove_held_slash, remove_stake_amount,
+    store_config, store_dispute_params, store_held_slash, store_stake_amount, store_stakes,
+    store_treasury, store_worker_stakes, Config,
+};
 use ticket_auction::collateral_manager::{
-    ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, QueryStakeStatusMsg, ReleaseStakeMsg,
+    Dispute, DisputeParams, ExecuteMsg, HeldSlash, InstantiateMsg, MigrateMsg, QueryMsg,
+    QueryStakeStatusMsg, ReleaseStakeMsg, TreasuryResponse,
 };
 use ticket_auction::error::TAError;
-use ticket_auction::ticket_manager::{QueryMsg as TicketQueryMsg, TicketInfoResponse};
+use ticket_auction::helpers::TicketManagerContract;
+use ticket_auction::migration::migrate_contract;
+use ticket_auction::ticket_manager::TicketInfoResponse;
 
 const BASE_DENOM: &str = "uluna";
+const MAX_EVIDENCE_URI_LEN: usize = 256;
+
+// Contract info recorded by cw2, checked on the migration.
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -43,6 +58,8 @@ pub fn instantiate(
     // Initialize the variables
     store_stakes(deps.storage, 0, vec![])?;
 
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     Ok(Response::new()
         .add_attribute("method", "instantiate")
         .add_attribute("contract", env.contract.address)
@@ -53,10 +70,26 @@ pub fn instantiate(
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
     match msg {
         // Transactions initiated by user_board contract.
-        ExecuteMsg::LockStake { tid } => execute_lock_stake(deps, env, info, tid),
+        ExecuteMsg::LockStake { tid, worker } => execute_lock_stake(deps, env, info, tid, worker),
+        ExecuteMsg::OpenDispute {
+            tid,
+            worker,
+            evidence_uri,
+        } => execute_open_dispute(deps, env, info, tid, worker, evidence_uri),
 
         // Transactions initiated by admin_board contract. (user_board -> ticket_manager -> here).
         ExecuteMsg::ReleaseStake(msg) => execute_release_stake(deps, env, info, msg),
+        ExecuteMsg::SetDisputeParams(params) => execute_set_dispute_params(deps, info, params),
+        ExecuteMsg::ResolveDispute {
+            tid,
+            worker,
+            uphold,
+        } => execute_resolve_dispute(deps, info, tid, worker, uphold),
+
+        // Transactions initiated by anyone.
+        ExecuteMsg::FinalizeSlash { tid, worker } => {
+            execute_finalize_slash(deps, env, info, tid, worker)
+        }
     }
 }
 
@@ -65,6 +98,7 @@ fn execute_lock_stake(
     _env: Env,
     info: MessageInfo,
     tid: u64,
+    worker: String,
 ) -> StdResult<Response> {
     // validation 1: Check if the tx sender is user_board
     let config = read_config(deps.storage)?;
@@ -79,22 +113,40 @@ fn execute_lock_stake(
         .into_iter()
         .filter(|c| c.denom == *BASE_DENOM)
         .collect::<Vec<Coin>>();
-    if base_coin.len() != 1 && base_coin[0].amount != Uint128::from(ticket_info.collateral) {
-        return Err(TAError::UnnecessaryFunds.into());
+    let stake_amount = Uint128::from(ticket_info.collateral);
+    if base_coin.len() != 1 || base_coin[0].amount != stake_amount {
+        return Err(TAError::InsufficientFunds.into());
     }
 
-    // Store the stake data.
+    // validation 3: Check if the worker is valid wallet
+    let worker = deps.api.addr_validate(&worker)?;
+
+    // validation 4: Check if the worker has not staked on the ticket yet
     let mut workers = read_stakes(deps.storage, tid)?;
-    workers.push(info.sender);
+    if workers.contains(&worker) {
+        return Err(TAError::AlreadyStaked.into());
+    }
+
+    // Store the stake data.
+    workers.push(worker.clone());
 
     store_stakes(deps.storage, tid, workers)?;
 
+    let mut worker_stakes = read_worker_stakes(deps.storage, &worker)?;
+    worker_stakes.push(tid);
+    store_worker_stakes(deps.storage, &worker, worker_stakes)?;
+
+    store_stake_amount(deps.storage, tid, &worker, stake_amount)?;
+    let mut treasury = read_treasury(deps.storage)?;
+    treasury.locked += stake_amount;
+    store_treasury(deps.storage, treasury)?;
+
     Ok(Response::new().add_attribute("method", "lock_stake"))
 }
 
 fn execute_release_stake(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ReleaseStakeMsg,
 ) -> StdResult<Response> {
@@ -104,6 +156,42 @@ fn execute_release_stake(
         return Err(TAError::NotAuthorized.into());
     }
 
+    // Validate if the worker has the stake on the ticket.
+    let stake_amount = match read_stake_amount(deps.storage, msg.tid, &msg.worker) {
+        Ok(amount) => amount,
+        Err(_) => return Err(TAError::NotStaked.into()),
+    };
+
+    // The release amount is calculated from the current ticket collateral,
+    // never pay more than the locked stake.
+    let release_amt = msg.amt.min(stake_amount);
+    let slashed = stake_amount - release_amt;
+    let mut treasury = read_treasury(deps.storage)?;
+    treasury.locked = treasury
+        .locked
+        .checked_sub(stake_amount)
+        .map_err(|_| TAError::LockedUnderflow)?;
+
+    // Hold the slashed amount for the dispute window, if any.
+    let params = read_dispute_params(deps.storage)?;
+    if params.window > 0 && !slashed.is_zero() {
+        treasury.held += slashed;
+        store_held_slash(
+            deps.storage,
+            &HeldSlash {
+                tid: msg.tid,
+                worker: msg.worker.clone(),
+                amount: slashed,
+                release_at: env.block.time.seconds().saturating_add(params.window),
+                dispute: None,
+            },
+        )?;
+    } else {
+        treasury.slashed += slashed;
+    }
+    store_treasury(deps.storage, treasury)?;
+    remove_stake_amount(deps.storage, msg.tid, &msg.worker);
+
     // Remove stake record from STAKES
     let workers = read_stakes(deps.storage, msg.tid)?;
     let workers = workers
@@ -112,34 +200,219 @@ fn execute_release_stake(
         .collect::<Vec<Addr>>();
     store_stakes(deps.storage, msg.tid, workers)?;
 
-    // Build the message to release the stake.
-    let messages: Vec<CosmosMsg> = vec![CosmosMsg::Bank(BankMsg::Send {
-        to_address: msg.worker.to_string(),
-        amount: vec![Coin {
-            denom: BASE_DENOM.to_string(),
-            amount: msg.amt,
-        }],
-    })];
+    let worker_stakes = read_worker_stakes(deps.storage, &msg.worker)?
+        .into_iter()
+        .filter(|tid| *tid != msg.tid)
+        .collect::<Vec<u64>>();
+    store_worker_stakes(deps.storage, &msg.worker, worker_stakes)?;
+
+    // Build the message to release the stake.(nothing to send for the full slash)
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if !release_amt.is_zero() {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: msg.worker.to_string(),
+            amount: vec![Coin {
+                denom: BASE_DENOM.to_string(),
+                amount: release_amt,
+            }],
+        }));
+    }
     Ok(Response::new()
         .add_messages(messages)
         .add_attributes(vec![attr("method", "release stake")]))
 }
 
+fn execute_set_dispute_params(
+    deps: DepsMut,
+    info: MessageInfo,
+    params: DisputeParams,
+) -> StdResult<Response> {
+    // Validation 1. Check if the tx sender is admin.
+    let config = read_config(deps.storage)?;
+    if info.sender.to_string() != config.admin_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    store_dispute_params(deps.storage, &params)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "set dispute params"),
+        attr("window", params.window.to_string()),
+        attr("bond", params.bond),
+    ]))
+}
+
+fn execute_open_dispute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tid: u64,
+    worker: String,
+    evidence_uri: String,
+) -> StdResult<Response> {
+    // Validation 1. Check if the tx sender is user_board
+    let config = read_config(deps.storage)?;
+    if info.sender.to_string() != config.user_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 2. Check if the evidence is given.
+    if evidence_uri.trim().is_empty() || evidence_uri.len() > MAX_EVIDENCE_URI_LEN {
+        return Err(TAError::InvalidEvidence.into());
+    }
+
+    // Validation 3. Check if the slash is held & the dispute window is open.
+    let worker = deps.api.addr_validate(&worker)?;
+    let timestamp = env.block.time.seconds();
+    let mut held = match read_held_slash(deps.storage, tid, &worker)? {
+        Some(held) if timestamp <= held.release_at => held,
+        _ => return Err(TAError::NotDisputable.into()),
+    };
+    if held.dispute.is_some() {
+        return Err(TAError::AlreadyDisputed.into());
+    }
+
+    // Validation 4. Check if the sent funds are the dispute bond.
+    let bond = read_dispute_params(deps.storage)?.bond;
+    let base_coin = info
+        .funds
+        .into_iter()
+        .filter(|c| c.denom == *BASE_DENOM)
+        .collect::<Vec<Coin>>();
+    let sent = base_coin.first().map(|c| c.amount).unwrap_or_default();
+    if base_coin.len() > 1 || sent != bond {
+        return Err(TAError::InsufficientFunds.into());
+    }
+
+    held.dispute = Some(Dispute {
+        evidence_uri,
+        bond,
+        opened_at: timestamp,
+    });
+    store_held_slash(deps.storage, &held)?;
+    let mut treasury = read_treasury(deps.storage)?;
+    treasury.held += bond;
+    store_treasury(deps.storage, treasury)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "open dispute"),
+        attr("tid", tid.to_string()),
+        attr("worker", worker),
+    ]))
+}
+
+fn execute_resolve_dispute(
+    deps: DepsMut,
+    info: MessageInfo,
+    tid: u64,
+    worker: String,
+    uphold: bool,
+) -> StdResult<Response> {
+    // Validation 1. Check if the tx sender is admin.
+    let config = read_config(deps.storage)?;
+    if info.sender.to_string() != config.admin_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 2. Check if the held slash is disputed.
+    let worker = deps.api.addr_validate(&worker)?;
+    let held = match read_held_slash(deps.storage, tid, &worker)? {
+        Some(held) => held,
+        None => return Err(TAError::NotFound.into()),
+    };
+    let dispute = match held.dispute {
+        Some(dispute) => dispute,
+        None => return Err(TAError::NotDisputed.into()),
+    };
+
+    let total = held.amount + dispute.bond;
+    let mut treasury = read_treasury(deps.storage)?;
+    treasury.held -= total;
+    remove_held_slash(deps.storage, tid, &worker);
+
+    // Upheld: the slash & the bond are kept. Reversed: both are returned to the worker.
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if uphold {
+        treasury.slashed += total;
+    } else {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: worker.to_string(),
+            amount: vec![Coin {
+                denom: BASE_DENOM.to_string(),
+                amount: total,
+            }],
+        }));
+    }
+    store_treasury(deps.storage, treasury)?;
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        attr("method", "resolve dispute"),
+        attr("tid", tid.to_string()),
+        attr("worker", worker),
+        attr("uphold", uphold.to_string()),
+    ]))
+}
+
+// Keep the held slash for good once the dispute window has passed without the dispute.
+fn execute_finalize_slash(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tid: u64,
+    worker: String,
+) -> StdResult<Response> {
+    // Validation 1. Check if funds is provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2. Check if the held slash is undisputed & its window has passed.
+    let worker = deps.api.addr_validate(&worker)?;
+    let held = match read_held_slash(deps.storage, tid, &worker)? {
+        Some(held) => held,
+        None => return Err(TAError::NotFound.into()),
+    };
+    if held.dispute.is_some() {
+        return Err(TAError::AlreadyDisputed.into());
+    }
+    if env.block.time.seconds() <= held.release_at {
+        return Err(TAError::DisputeWindowOpen.into());
+    }
+
+    let mut treasury = read_treasury(deps.storage)?;
+    treasury.held -= held.amount;
+    treasury.slashed += held.amount;
+    store_treasury(deps.storage, treasury)?;
+    remove_held_slash(deps.storage, tid, &worker);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "finalize slash"),
+        attr("tid", tid.to_string()),
+        attr("worker", worker),
+    ]))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::QueryTicket { tid } => to_json_binary(&query_ticket(deps, tid)?),
         QueryMsg::QueryStakeStatus(msg) => to_json_binary(&query_stake_status(deps, msg)?),
+        QueryMsg::QueryWorkerStakes { worker } => {
+            to_json_binary(&query_worker_stakes(deps, worker)?)
+        }
+        QueryMsg::QueryTicketStakers { tid } => to_json_binary(&read_stakes(deps.storage, tid)?),
+        QueryMsg::QueryTreasury {} => to_json_binary(&query_treasury(deps)?),
+        QueryMsg::QueryDisputeParams {} => to_json_binary(&read_dispute_params(deps.storage)?),
+        QueryMsg::QueryHeldSlash { tid, worker } => {
+            to_json_binary(&read_held_slash(deps.storage, tid, &worker)?)
+        }
+        QueryMsg::QueryDisputes {} => to_json_binary(&read_disputes(deps.storage)?),
     }
 }
 
 fn query_ticket(deps: Deps, tid: u64) -> StdResult<TicketInfoResponse> {
     let config = read_config(deps.storage)?;
-    let ticket_info_response: TicketInfoResponse = deps.querier.query_wasm_smart(
-        config.ticket_manager,
-        &to_json_binary(&TicketQueryMsg::QueryTicketInfo { tid })?,
-    )?;
-    Ok(ticket_info_response)
+    TicketManagerContract::new(config.ticket_manager).query_ticket_info(&deps.querier, tid)
 }
 
 fn query_stake_status(deps: Deps, msg: QueryStakeStatusMsg) -> StdResult<bool> {
@@ -148,7 +421,22 @@ fn query_stake_status(deps: Deps, msg: QueryStakeStatusMsg) -> StdResult<bool> {
     Ok(is_staked)
 }
 
+// Query the tickets on which the worker currently has the stake.
+fn query_worker_stakes(deps: Deps, worker: Addr) -> StdResult<Vec<u64>> {
+    read_worker_stakes(deps.storage, &worker)
+}
+
+// Query the funds held by the contract. (active stakes, slashed amounts & dispute holdings)
+fn query_treasury(deps: Deps) -> StdResult<TreasuryResponse> {
+    let treasury = read_treasury(deps.storage)?;
+    Ok(TreasuryResponse {
+        locked: treasury.locked,
+        slashed: treasury.slashed,
+        held: treasury.held,
+    })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+    migrate_contract(deps, CONTRACT_NAME, CONTRACT_VERSION, MIGRATIONS)
 }
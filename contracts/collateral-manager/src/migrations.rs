@@ -0,0 +1,51 @@
+use cosmwasm_std::{DepsMut, Order, StdResult, Uint128};
+
+use crate::state::{
+    read_config, read_treasury, read_worker_stakes, store_stake_amount, store_treasury,
+    store_worker_stakes, STAKES, STAKE_AMOUNTS,
+};
+use ticket_auction::helpers::TicketManagerContract;
+use ticket_auction::migration::MigrationStep;
+
+// Storage migrations of collateral-manager, in the ascending order of the version.
+pub const MIGRATIONS: &[MigrationStep] = &[MigrationStep {
+    version: "0.1.0",
+    migrate: backfill_stake_amounts,
+}];
+
+// 0.1.0: Only the stakers of the ticket were stored before 0.1.0.
+// Rebuild the locked amounts & the treasury from the stake records, the amount locked
+// being the current collateral of the ticket. (ticket-manager is migrated first)
+fn backfill_stake_amounts(deps: DepsMut) -> StdResult<()> {
+    let config = read_config(deps.storage)?;
+    let ticket_manager = TicketManagerContract::new(config.ticket_manager);
+    let stakes = STAKES
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut treasury = read_treasury(deps.storage)?;
+    for (tid, workers) in stakes {
+        if workers.is_empty() {
+            continue;
+        }
+        let collateral = Uint128::from(
+            ticket_manager
+                .query_ticket_info(&deps.querier, tid)?
+                .collateral,
+        );
+        for worker in workers {
+            if STAKE_AMOUNTS.has(deps.storage, (tid, &worker)) {
+                continue;
+            }
+            store_stake_amount(deps.storage, tid, &worker, collateral)?;
+            treasury.locked += collateral;
+
+            let mut worker_stakes = read_worker_stakes(deps.storage, &worker)?;
+            if !worker_stakes.contains(&tid) {
+                worker_stakes.push(tid);
+                store_worker_stakes(deps.storage, &worker, worker_stakes)?;
+            }
+        }
+    }
+    store_treasury(deps.storage, treasury)
+}
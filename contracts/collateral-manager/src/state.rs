@@ -1,8 +1,9 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::{Addr, StdResult, Storage};
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128};
 use cw_storage_plus::{Item, Map};
+use ticket_auction::collateral_manager::{DisputeParams, HeldSlash};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
@@ -28,5 +29,103 @@ pub fn store_stakes(storage: &mut dyn Storage, tid: u64, stakes: Vec<Addr>) -> S
 }
 
 pub fn read_stakes(storage: &dyn Storage, tid: u64) -> StdResult<Vec<Addr>> {
-    STAKES.load(storage, tid)
+    Ok(STAKES.may_load(storage, tid)?.unwrap_or_default())
+}
+
+// Tickets on which the worker has locked the stake.
+pub const WORKER_STAKES: Map<&Addr, Vec<u64>> = Map::new("worker_stakes");
+
+pub fn store_worker_stakes(
+    storage: &mut dyn Storage,
+    worker: &Addr,
+    tids: Vec<u64>,
+) -> StdResult<()> {
+    WORKER_STAKES.save(storage, worker, &tids)
+}
+
+pub fn read_worker_stakes(storage: &dyn Storage, worker: &Addr) -> StdResult<Vec<u64>> {
+    Ok(WORKER_STAKES.may_load(storage, worker)?.unwrap_or_default())
+}
+
+// Amount locked by the worker on the ticket. (collateral of the ticket at the lock time)
+pub const STAKE_AMOUNTS: Map<(u64, &Addr), Uint128> = Map::new("stake_amounts");
+
+pub fn store_stake_amount(
+    storage: &mut dyn Storage,
+    tid: u64,
+    worker: &Addr,
+    amount: Uint128,
+) -> StdResult<()> {
+    STAKE_AMOUNTS.save(storage, (tid, worker), &amount)
+}
+
+pub fn read_stake_amount(storage: &dyn Storage, tid: u64, worker: &Addr) -> StdResult<Uint128> {
+    STAKE_AMOUNTS.load(storage, (tid, worker))
+}
+
+pub fn remove_stake_amount(storage: &mut dyn Storage, tid: u64, worker: &Addr) {
+    STAKE_AMOUNTS.remove(storage, (tid, worker))
+}
+
+// Bookkeeping of the funds held by the contract.
+//   locked : sum of the active stakes
+//   slashed: sum of the slashed amounts kept by the contract
+//   held   : sum of the slashed amounts in the dispute window & the dispute bonds
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct Treasury {
+    pub locked: Uint128,
+    pub slashed: Uint128,
+    #[serde(default)]
+    pub held: Uint128,
+}
+
+pub const TREASURY: Item<Treasury> = Item::new("treasury");
+
+pub fn store_treasury(storage: &mut dyn Storage, treasury: Treasury) -> StdResult<()> {
+    TREASURY.save(storage, &treasury)
+}
+
+pub fn read_treasury(storage: &dyn Storage) -> StdResult<Treasury> {
+    Ok(TREASURY.may_load(storage)?.unwrap_or_default())
+}
+
+pub const DISPUTE_PARAMS: Item<DisputeParams> = Item::new("dispute_params");
+
+pub fn store_dispute_params(storage: &mut dyn Storage, params: &DisputeParams) -> StdResult<()> {
+    DISPUTE_PARAMS.save(storage, params)
+}
+
+// No dispute window unless set by the admin.
+pub fn read_dispute_params(storage: &dyn Storage) -> StdResult<DisputeParams> {
+    Ok(DISPUTE_PARAMS.may_load(storage)?.unwrap_or_default())
+}
+
+// Slashed amounts held during the dispute window, removed once finalized or resolved.
+pub const HELD_SLASHES: Map<(u64, &Addr), HeldSlash> = Map::new("held_slashes");
+
+pub fn store_held_slash(storage: &mut dyn Storage, held: &HeldSlash) -> StdResult<()> {
+    HELD_SLASHES.save(storage, (held.tid, &held.worker), held)
+}
+
+pub fn read_held_slash(
+    storage: &dyn Storage,
+    tid: u64,
+    worker: &Addr,
+) -> StdResult<Option<HeldSlash>> {
+    HELD_SLASHES.may_load(storage, (tid, worker))
+}
+
+pub fn remove_held_slash(storage: &mut dyn Storage, tid: u64, worker: &Addr) {
+    HELD_SLASHES.remove(storage, (tid, worker))
+}
+
+pub fn read_disputes(storage: &dyn Storage) -> StdResult<Vec<HeldSlash>> {
+    HELD_SLASHES
+        .range(storage, None, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |(_, held)| held.dispute.is_some())
+        })
+        .map(|item| item.map(|(_, held)| held))
+        .collect()
 }
@@ -1,23 +0,0 @@
-use std::env::current_dir;
-use std::fs::create_dir_all;
-
-use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
-use ticket_auction::ticket_manager::{
-    AddTicketMsg, ExecuteMsg, InstantiateMsg, QueryMsg, Ticket, TicketInfoResponse,
-    TicketsResponse, UpdateTicketMsg,
-};
-fn main() {
-    let mut out_dir = current_dir().unwrap();
-    out_dir.push("schema");
-    create_dir_all(&out_dir).unwrap();
-    remove_schemas(&out_dir).unwrap();
-
-    export_schema(&schema_for!(InstantiateMsg), &out_dir);
-    export_schema(&schema_for!(ExecuteMsg), &out_dir);
-    export_schema(&schema_for!(QueryMsg), &out_dir);
-    export_schema(&schema_for!(TicketInfoResponse), &out_dir);
-    export_schema(&schema_for!(AddTicketMsg), &out_dir);
-    export_schema(&schema_for!(UpdateTicketMsg), &out_dir);
-    export_schema(&schema_for!(Ticket), &out_dir);
-    export_schema(&schema_for!(TicketsResponse), &out_dir);
-}
@@ -1,21 +1,46 @@
+use std::collections::BTreeMap;
+
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    attr, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
-    Uint128, WasmMsg,
+    attr, from_json, to_json_binary, Api, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    Response, StdResult, Storage, Uint128,
 };
+use cw2::set_contract_version;
 
+use crate::migrations::MIGRATIONS;
 use crate::state::{
-    read_all_assigned_tickets, read_config, read_ticket_for_id, read_tickets,
-    read_worker_for_ticket, store_config, store_tickets, store_tw_pair, Config,
+    has_ticket, read_all_assigned_tickets, read_all_pending_results, read_assignees, read_config,
+    read_limits, read_outcome, read_pending_result, read_pending_results, read_submission,
+    read_ticket_for_id, read_tickets, read_worker_for_ticket, read_worker_stats,
+    read_worker_tickets, remove_pending_result, remove_ticket_for_id, store_assignee, store_config,
+    store_limits, store_outcome, store_pending_result, store_submission, store_ticket,
+    store_tw_pair, store_worker_stats, store_worker_ticket, take_pending_results, Config,
+    PendingResult,
 };
-use ticket_auction::admin_board::{ExecuteMsg as AdminExecuteMsg, SlashMsg};
+use ticket_auction::admin_board::SlashMsg;
+use ticket_auction::batch::validate_batch;
 use ticket_auction::error::TAError;
+use ticket_auction::helpers::{
+    AdminBoardContract, AuctionManagerContract, CollateralManagerContract,
+};
+use ticket_auction::matcher::{sha256_hex, ResultMatcher};
+use ticket_auction::migration::migrate_contract;
 use ticket_auction::ticket_manager::{
-    AddTicketMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, PostConfigMsg, QueryMsg, TWPairsReponse,
-    Ticket, TicketInfoResponse, TicketResultMsg, TicketWorkerPair, UpdateTicketMsg,
+    AddTicketMsg, AssessmentMode, AuctionParams, ExecuteMsg, Grade, GradeSubmissionMsg,
+    InstantiateMsg, LatenessPenalty, LatenessResponse, MigrateMsg, PendingReview, PostConfigMsg,
+    QueryMsg, SimulateAssessmentResponse, Submission, SubmissionAssessment, TWPairsReponse, Ticket,
+    TicketInfoResponse, TicketLimits, TicketMetadata, TicketOutcome, TicketResultMsg,
+    TicketWorkerPair, TicketsResponse, UpdateTicketMsg, WorkerAssignment, WorkerReputationResponse,
+    WorkerTicketsResponse, MAX_CATEGORY_LEN, MAX_CONTENT_URI_LEN, MAX_DESCRIPTION_LEN,
+    MAX_NOTES_LEN, MAX_SCORE, MAX_SCORING_WEIGHT, MAX_SLOTS, MAX_STORED_RESULT_LEN, MAX_TAGS,
+    MAX_TAG_LEN, MAX_TITLE_LEN,
 };
 
+// Contract info recorded by cw2, checked on the migration.
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -36,11 +61,11 @@ pub fn instantiate(
             admin_board,
             auction_manager: msg.auction_manager,
             user_board: msg.user_board,
+            collateral_manager: None,
         },
     )?;
 
     // Initialize the variables
-    store_tickets(deps.storage, vec![])?;
     store_tw_pair(
         deps.storage,
         TicketWorkerPair {
@@ -49,6 +74,8 @@ pub fn instantiate(
         },
     )?;
 
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     Ok(Response::new()
         .add_attribute("method", "instantiate")
         .add_attribute("contract", env.contract.address)
@@ -62,12 +89,29 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
         ExecuteMsg::AddTicket(msg) => add_ticket(deps, env, info, msg),
         ExecuteMsg::UpdateTicket(msg) => update_ticket(deps, env, info, msg),
         ExecuteMsg::RemoveTicket { tid } => remove_ticket(deps, env, info, tid),
+        ExecuteMsg::AddTickets(msgs) => add_tickets(deps, env, info, msgs),
+        ExecuteMsg::UpdateTickets(msgs) => update_tickets(deps, env, info, msgs),
+        ExecuteMsg::CancelTickets { tids } => cancel_tickets(deps, env, info, tids),
 
         // Transaction initiated by user_board.
         ExecuteMsg::AssessSubmission(msg) => assess_submission(deps, env, info, msg),
 
+        // Transaction initiated by admin(contract).
+        ExecuteMsg::ReportNoShow { tid } => report_no_show(deps, env, info, tid),
+        ExecuteMsg::SetTicketLimits(limits) => set_ticket_limits(deps, info, limits),
+        ExecuteMsg::GradeSubmission(msg) => grade_submission(deps, env, info, msg),
+        ExecuteMsg::ReverseSlash { tid, worker } => reverse_slash(deps, info, tid, worker),
+
+        // Transaction initiated by anyone(keeper).
+        ExecuteMsg::AutoApprove { tid } => auto_approve(deps, env, info, tid),
+        ExecuteMsg::CloseSubmissions { tid } => close_submissions(deps, env, info, tid),
+
         // Transaction initiated by auction contract. (admin -> auction -> here)
         ExecuteMsg::SaveTicketWorker(msg) => save_ticket_worker(deps, info, msg),
+        ExecuteMsg::ExtendBetFinish {
+            tid,
+            bet_finish_timestamp,
+        } => extend_bet_finish(deps, info, tid, bet_finish_timestamp),
 
         // Utilities
         ExecuteMsg::PostConfig(msg) => execute_post_config(deps, env, info, msg),
@@ -77,7 +121,7 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
 // Add new ticket info to the storage
 fn add_ticket(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: AddTicketMsg,
 ) -> StdResult<Response> {
@@ -88,25 +132,206 @@ fn add_ticket(
     }
 
     // Store ticket
-    let mut tickets = read_tickets(deps.storage)?;
-    tickets.push(Ticket {
-        id: msg.id,
-        bet_finish_timestamp: msg.bet_finish_timestamp,
-        close_timestamp: msg.close_timestamp,
-        result: msg.result,
-        collateral: msg.collateral,
-    });
-    store_tickets(deps.storage, tickets)?;
+    let limits = read_limits(deps.storage)?;
+    let ticket = new_ticket(&msg, &config.admin_board, env.block.time.seconds(), &limits)?;
+    store_ticket(deps.storage, &ticket)?;
     Ok(Response::new().add_attributes(vec![
         attr("method", "store_ticket"),
         attr("result", "success"),
     ]))
 }
 
+// Build the ticket to store from "AddTicket" message.
+// The ticket is created by "admin_board" unless the creator is given.
+fn new_ticket(
+    msg: &AddTicketMsg,
+    admin_board: &str,
+    now: u64,
+    limits: &TicketLimits,
+) -> StdResult<Ticket> {
+    // Validate the timestamps, the collateral, the slots, the auction params & the metadata.
+    validate_schedule(
+        now,
+        msg.bet_finish_timestamp,
+        msg.close_timestamp,
+        msg.collateral,
+        limits,
+    )?;
+    validate_slots(msg.slots)?;
+    validate_assessment(&msg.assessment, &msg.result, msg.slots)?;
+    validate_matcher(&msg.matcher, &msg.result)?;
+    validate_lateness(&msg.lateness)?;
+    validate_auction_params(&msg.auction)?;
+    validate_metadata(&msg.metadata)?;
+
+    Ok(Ticket {
+        id: msg.id,
+        bet_finish_timestamp: msg.bet_finish_timestamp,
+        close_timestamp: msg.close_timestamp,
+        result: msg.result.clone(),
+        collateral: msg.collateral,
+        auction: msg.auction.clone(),
+        metadata: msg.metadata.clone(),
+        creator: msg.creator.clone().unwrap_or(admin_board.to_string()),
+        slots: msg.slots,
+        assessment: msg.assessment.clone(),
+        resubmission: msg.resubmission,
+        matcher: msg.matcher.clone(),
+        lateness: msg.lateness.clone(),
+    })
+}
+
+// Check if the number of the workers to assign is within 1 ~ "MAX_SLOTS".
+fn validate_slots(slots: u32) -> StdResult<()> {
+    if slots == 0 || slots > MAX_SLOTS {
+        return Err(TAError::InvalidSlots.into());
+    }
+    Ok(())
+}
+
+// Check if the timestamps are ordered after the block time("now")
+// & the durations and the collateral are within the limits.
+fn validate_schedule(
+    now: u64,
+    bet_finish_timestamp: u64,
+    close_timestamp: u64,
+    collateral: u64,
+    limits: &TicketLimits,
+) -> StdResult<()> {
+    if bet_finish_timestamp <= now || close_timestamp <= bet_finish_timestamp {
+        return Err(TAError::InvalidTimestamps.into());
+    }
+    let within =
+        |value: u64, min: u64, max: Option<u64>| value >= min && max.is_none_or(|max| value <= max);
+    if !within(
+        bet_finish_timestamp - now,
+        limits.min_bet_duration,
+        limits.max_bet_duration,
+    ) || !within(
+        close_timestamp - bet_finish_timestamp,
+        limits.min_work_duration,
+        limits.max_work_duration,
+    ) {
+        return Err(TAError::InvalidTimestamps.into());
+    }
+    if !within(collateral, limits.min_collateral, limits.max_collateral) {
+        return Err(TAError::InvalidCollateral.into());
+    }
+    Ok(())
+}
+
+// Check if the "Manual" ticket has the review period, the "Consensus" ticket has
+// the slots to vote & the expected result of the "PartialCredit" ticket has the fields.
+fn validate_assessment(assessment: &AssessmentMode, result: &str, slots: u32) -> StdResult<()> {
+    match assessment {
+        AssessmentMode::Manual { review_period: 0 } => Err(TAError::InvalidReviewPeriod.into()),
+        AssessmentMode::Consensus if slots < 2 => Err(TAError::InvalidSlots.into()),
+        AssessmentMode::PartialCredit => match parse_fields(result) {
+            Some(fields) if !fields.is_empty() => Ok(()),
+            _ => Err(TAError::InvalidStructuredResult.into()),
+        },
+        _ => Ok(()),
+    }
+}
+
+// Check if the expected result can be matched with the result matcher.
+fn validate_matcher(matcher: &ResultMatcher, result: &str) -> StdResult<()> {
+    matcher
+        .validate(result)
+        .map_err(|reason| TAError::InvalidMatcher(reason).into())
+}
+
+// Check if the slashes of the lateness penalty are within 100% & the tiers are ordered.
+fn validate_lateness(lateness: &LatenessPenalty) -> StdResult<()> {
+    lateness
+        .validate()
+        .map_err(|reason| TAError::InvalidLatenessPenalty(reason).into())
+}
+
+// Check if the bet amount range of the auction params is not empty.
+fn validate_auction_params(params: &AuctionParams) -> StdResult<()> {
+    if let (Some(min_bid), Some(reserve_price)) = (params.min_bid, params.reserve_price) {
+        if min_bid > reserve_price {
+            return Err(TAError::InvalidAuctionParams.into());
+        }
+    }
+    if params.reserve_price == Some(0) {
+        return Err(TAError::InvalidAuctionParams.into());
+    }
+    if let Some(scoring) = &params.scoring {
+        let weights = [
+            scoring.price_weight,
+            scoring.completion_weight,
+            scoring.punctuality_weight,
+        ];
+        if weights.iter().any(|w| *w > MAX_SCORING_WEIGHT) || weights.iter().all(|w| *w == 0) {
+            return Err(TAError::InvalidAuctionParams.into());
+        }
+    }
+    Ok(())
+}
+
+// Check the size limits of the metadata & the format of the filter words & content hash.
+fn validate_metadata(metadata: &TicketMetadata) -> StdResult<()> {
+    let invalid =
+        |reason: String| -> StdResult<()> { Err(TAError::InvalidMetadata(reason).into()) };
+
+    if metadata.title.len() > MAX_TITLE_LEN {
+        return invalid(format!("title exceeds {} bytes", MAX_TITLE_LEN));
+    }
+    if metadata.description.len() > MAX_DESCRIPTION_LEN {
+        return invalid(format!("description exceeds {} bytes", MAX_DESCRIPTION_LEN));
+    }
+    if let Some(category) = &metadata.category {
+        if !is_filter_word(category, MAX_CATEGORY_LEN) {
+            return invalid(format!("category \"{}\"", category));
+        }
+    }
+    if metadata.tags.len() > MAX_TAGS {
+        return invalid(format!("more than {} tags", MAX_TAGS));
+    }
+    for (i, tag) in metadata.tags.iter().enumerate() {
+        if !is_filter_word(tag, MAX_TAG_LEN) {
+            return invalid(format!("tag \"{}\"", tag));
+        }
+        if metadata.tags[..i].contains(tag) {
+            return invalid(format!("duplicate tag \"{}\"", tag));
+        }
+    }
+    match (&metadata.content_uri, &metadata.content_hash) {
+        (Some(uri), _) if uri.is_empty() || uri.len() > MAX_CONTENT_URI_LEN => {
+            return invalid(format!(
+                "content uri must be 1-{} bytes",
+                MAX_CONTENT_URI_LEN
+            ));
+        }
+        (None, Some(_)) => return invalid("content hash without content uri".to_string()),
+        (_, Some(hash))
+            if hash.len() != 64
+                || !hash
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)) =>
+        {
+            return invalid("content hash must be sha256 in lowercase hex".to_string());
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+// Non-empty lowercase word of "a-z", "0-9" & "-" within "max_len" bytes.
+fn is_filter_word(word: &str, max_len: usize) -> bool {
+    !word.is_empty()
+        && word.len() <= max_len
+        && word
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
 // Update the ticket with given info.
 fn update_ticket(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: UpdateTicketMsg,
 ) -> StdResult<Response> {
@@ -116,16 +341,39 @@ fn update_ticket(
         return Err(TAError::NotAuthorized.into());
     }
 
+    // Update & store the ticket
+    let ticket = updated_ticket(deps.as_ref(), &env, &config, &msg)?;
+    store_ticket(deps.storage, &ticket)?;
+    Ok(Response::new().add_attribute("method", "update_ticket"))
+}
+
+// Apply "UpdateTicket" message to the stored ticket.
+// The timestamps, the collateral, the slots & the assessment rules are frozen
+// once any worker has staked or bid on the ticket.
+fn updated_ticket(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    msg: &UpdateTicketMsg,
+) -> StdResult<Ticket> {
     let ticket_id = msg.id;
     // Get the ticket with "id"
-    let ticket_info = query_ticket(deps.as_ref(), ticket_id)?;
+    let ticket_info = query_ticket(deps, ticket_id)?;
 
     let mut ticket = Ticket {
         id: ticket_info.id,
         bet_finish_timestamp: ticket_info.bet_finish_timestamp,
         close_timestamp: ticket_info.close_timestamp,
-        result: ticket_info.result,
+        result: ticket_info.result.clone(),
         collateral: ticket_info.collateral,
+        auction: ticket_info.auction.clone(),
+        metadata: ticket_info.metadata.clone(),
+        creator: ticket_info.creator.clone(),
+        slots: ticket_info.slots,
+        assessment: ticket_info.assessment.clone(),
+        resubmission: ticket_info.resubmission,
+        matcher: ticket_info.matcher.clone(),
+        lateness: ticket_info.lateness.clone(),
     };
 
     // Update the ticket info.
@@ -138,20 +386,66 @@ fn update_ticket(
     if let Some(collateral) = msg.collateral {
         ticket.collateral = collateral;
     }
-    if let Some(result) = msg.result {
+    if let Some(result) = msg.result.clone() {
         ticket.result = result;
     }
+    if let Some(auction) = msg.auction.clone() {
+        validate_auction_params(&auction)?;
+        ticket.auction = auction;
+    }
+    if let Some(metadata) = msg.metadata.clone() {
+        validate_metadata(&metadata)?;
+        ticket.metadata = metadata;
+    }
+    if let Some(slots) = msg.slots {
+        validate_slots(slots)?;
+        ticket.slots = slots;
+    }
+    if let Some(assessment) = msg.assessment.clone() {
+        ticket.assessment = assessment;
+    }
+    if let Some(resubmission) = msg.resubmission {
+        ticket.resubmission = resubmission;
+    }
+    if let Some(matcher) = msg.matcher.clone() {
+        ticket.matcher = matcher;
+    }
+    if let Some(lateness) = msg.lateness.clone() {
+        validate_lateness(&lateness)?;
+        ticket.lateness = lateness;
+    }
+    if msg.assessment.is_some() || msg.result.is_some() || msg.slots.is_some() {
+        validate_assessment(&ticket.assessment, &ticket.result, ticket.slots)?;
+    }
+    if msg.matcher.is_some() || msg.result.is_some() {
+        validate_matcher(&ticket.matcher, &ticket.result)?;
+    }
 
-    // Update the tickets
-    let tickets = read_tickets(deps.storage)?;
-    let tickets = tickets
-        .into_iter()
-        .map(|t| if t.id == ticket_id { ticket.clone() } else { t })
-        .collect::<Vec<Ticket>>();
+    // Validate the changed timestamps, collateral, auction params, result, slots & assessment rules.
+    let rescheduled = ticket.bet_finish_timestamp != ticket_info.bet_finish_timestamp
+        || ticket.close_timestamp != ticket_info.close_timestamp
+        || ticket.collateral != ticket_info.collateral
+        || ticket.auction != ticket_info.auction
+        || ticket.result != ticket_info.result
+        || ticket.slots != ticket_info.slots
+        || ticket.assessment != ticket_info.assessment
+        || ticket.resubmission != ticket_info.resubmission
+        || ticket.matcher != ticket_info.matcher
+        || ticket.lateness != ticket_info.lateness;
+    if rescheduled {
+        if is_engaged(deps, config, ticket.id)? {
+            return Err(TAError::TicketFrozen.into());
+        }
+        validate_schedule(
+            env.block.time.seconds(),
+            ticket.bet_finish_timestamp,
+            ticket.close_timestamp,
+            ticket.collateral,
+            &read_limits(deps.storage)?,
+        )?;
+    }
 
-    // Store the tickets
-    store_tickets(deps.storage, tickets)?;
-    Ok(Response::new().add_attribute("method", "update_ticket"))
+    Ok(ticket)
 }
 
 // Remove the ticket from tickets with ticket id.
@@ -163,17 +457,158 @@ fn remove_ticket(deps: DepsMut, _env: Env, info: MessageInfo, id: u64) -> StdRes
     }
 
     // Remove the ticket with id
-    let tickets = read_tickets(deps.storage)?;
-    let tickets = tickets
-        .into_iter()
-        .filter(|t| t.id != id)
-        .collect::<Vec<Ticket>>();
-
-    // Store the tickets
-    store_tickets(deps.storage, tickets)?;
+    remove_ticket_for_id(deps.storage, id);
     Ok(Response::new().add_attribute("method", "remove_ticket"))
 }
 
+// Check if any worker has staked(collateral_manager), bid(auction_manager)
+// or is assigned on the ticket.
+fn is_engaged(deps: Deps, config: &Config, tid: u64) -> StdResult<bool> {
+    if !read_assignees(deps.storage, tid)?.is_empty() {
+        return Ok(true);
+    }
+    if let Some(auction_manager) = &config.auction_manager {
+        let bets = AuctionManagerContract::new(auction_manager)
+            .query_curr_active_bets(&deps.querier, tid)?;
+        if !bets.is_empty() {
+            return Ok(true);
+        }
+    }
+    if let Some(collateral_manager) = &config.collateral_manager {
+        let stakers = CollateralManagerContract::new(collateral_manager)
+            .query_ticket_stakers(&deps.querier, tid)?;
+        if !stakers.is_empty() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+// Add the tickets of the batch. Nothing is stored if any of them is invalid.
+fn add_tickets(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msgs: Vec<AddTicketMsg>,
+) -> StdResult<Response> {
+    // Validation 1: Check if the tx sender is admin.
+    let config = read_config(deps.storage)?;
+    if info.sender.to_string() != config.admin_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 2: Check every ticket of the batch.
+    let limits = read_limits(deps.storage)?;
+    let now = env.block.time.seconds();
+    let tickets = validate_batch(
+        &msgs,
+        |m| m.id,
+        |m| new_ticket(m, &config.admin_board, now, &limits),
+    )?;
+
+    for ticket in tickets.iter() {
+        store_ticket(deps.storage, ticket)?;
+    }
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "add_tickets"),
+        attr("tids", join_tids(tickets.iter().map(|t| t.id))),
+    ]))
+}
+
+// Update the tickets of the batch. Nothing is updated if any of them is invalid.
+fn update_tickets(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msgs: Vec<UpdateTicketMsg>,
+) -> StdResult<Response> {
+    // Validation 1: Check if the tx sender is admin.
+    let config = read_config(deps.storage)?;
+    if info.sender.to_string() != config.admin_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 2: Check every update of the batch.
+    let tickets = validate_batch(
+        &msgs,
+        |m| m.id,
+        |m| updated_ticket(deps.as_ref(), &env, &config, m),
+    )?;
+
+    for ticket in tickets.iter() {
+        store_ticket(deps.storage, ticket)?;
+    }
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "update_tickets"),
+        attr("tids", join_tids(tickets.iter().map(|t| t.id))),
+    ]))
+}
+
+// Remove the tickets of the batch. Nothing is removed if any of them is not found.
+fn cancel_tickets(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    tids: Vec<u64>,
+) -> StdResult<Response> {
+    // Validation 1: Check if the tx sender is admin.
+    let config = read_config(deps.storage)?;
+    if info.sender.to_string() != config.admin_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 2: Check if every ticket exists.
+    validate_batch(
+        &tids,
+        |tid| *tid,
+        |tid| match has_ticket(deps.storage, *tid) {
+            true => Ok(()),
+            false => Err(TAError::NotFound.into()),
+        },
+    )?;
+
+    for tid in tids.iter() {
+        remove_ticket_for_id(deps.storage, *tid);
+    }
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "cancel_tickets"),
+        attr("tids", join_tids(tids.iter().copied())),
+    ]))
+}
+
+// eg. "1,2,3"
+fn join_tids(tids: impl Iterator<Item = u64>) -> String {
+    tids.map(|tid| tid.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+// Set the bounds of the new & updated tickets.
+fn set_ticket_limits(
+    deps: DepsMut,
+    info: MessageInfo,
+    limits: TicketLimits,
+) -> StdResult<Response> {
+    // Validation 1: Check if the tx sender is admin.
+    let config = read_config(deps.storage)?;
+    if info.sender.to_string() != config.admin_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 2: Check if every range is not empty & the collateral is required.
+    let is_valid = |min: u64, max: Option<u64>| max.is_none_or(|max| min <= max);
+    if limits.min_collateral == 0
+        || !is_valid(limits.min_bet_duration, limits.max_bet_duration)
+        || !is_valid(limits.min_work_duration, limits.max_work_duration)
+        || !is_valid(limits.min_collateral, limits.max_collateral)
+    {
+        return Err(TAError::InvalidTicketLimits.into());
+    }
+
+    store_limits(deps.storage, &limits)?;
+    Ok(Response::new().add_attribute("method", "set_ticket_limits"))
+}
+
 fn save_ticket_worker(
     deps: DepsMut,
     info: MessageInfo,
@@ -190,18 +625,67 @@ fn save_ticket_worker(
     }
 
     // Validation 2: Check if ticket id is valid.
-    let tickets = read_tickets(deps.storage)?;
-    let is_ticket_exist = tickets.iter().any(|t| t.id == msg.tid);
-    if !is_ticket_exist {
-        return Err(TAError::NotFound.into());
+    let ticket = match read_ticket_for_id(deps.storage, msg.tid) {
+        Ok(v) => v,
+        Err(_) => return Err(TAError::NotFound.into()),
+    };
+
+    // Validation 3: Check if the ticket has a free slot for the worker.
+    let assignees = read_assignees(deps.storage, msg.tid)?;
+    if assignees.len() >= ticket.slots as usize {
+        return Err(TAError::SlotsFilled.into());
     }
 
-    // Save the ticket-worker pair(winning_bet)
-    store_tw_pair(deps.storage, msg)?;
+    // Save the ticket-worker pair(winning_bet). "TWPAIR" keeps the first assignee.
+    store_assignee(deps.storage, msg.tid, &msg.worker)?;
+    store_worker_ticket(deps.storage, &msg.worker, msg.tid)?;
+    let mut stats = read_worker_stats(deps.storage, &msg.worker)?;
+    stats.won += 1;
+    store_worker_stats(deps.storage, &msg.worker, &stats)?;
+    if assignees.is_empty() {
+        store_tw_pair(deps.storage, msg)?;
+    }
 
     Ok(Response::new().add_attributes(vec![attr("method", "save ticket-worker pair")]))
 }
 
+// Extend the bet finish timestamp of the ticket. (soft close of the auction)
+fn extend_bet_finish(
+    deps: DepsMut,
+    info: MessageInfo,
+    tid: u64,
+    bet_finish_timestamp: u64,
+) -> StdResult<Response> {
+    // Validation 1: Check if the tx sender is "auction" address.
+    let config = read_config(deps.storage)?;
+    let auction_manager = match config.auction_manager {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+    if info.sender.to_string() != auction_manager {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 2: Check if the timestamp is extended, keeping the minimum work duration
+    //               before the ticket's close timestamp.
+    let mut ticket = read_ticket_for_id(deps.storage, tid)?;
+    let min_work = read_limits(deps.storage)?.min_work_duration.max(1);
+    if bet_finish_timestamp < ticket.bet_finish_timestamp
+        || bet_finish_timestamp > ticket.close_timestamp.saturating_sub(min_work)
+    {
+        return Err(TAError::InvalidAuctionParams.into());
+    }
+
+    // Update the ticket
+    ticket.bet_finish_timestamp = bet_finish_timestamp;
+    store_ticket(deps.storage, &ticket)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "extend bet finish"),
+        attr("bet_finish_timestamp", bet_finish_timestamp.to_string()),
+    ]))
+}
+
 fn assess_submission(
     deps: DepsMut,
     env: Env,
@@ -213,45 +697,663 @@ fn assess_submission(
     if !info.funds.is_empty() {
         return Err(TAError::UnnecessaryFunds.into());
     }
-    // Validation 2.Check if the tx sender is front bot
+    // Validation 2.Check if the tx sender is front bot(user_board)
+    let user_board = match config.user_board.clone() {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+    if info.sender.to_string() != user_board {
+        return Err(TAError::NotAuthorized.into());
+    }
 
-    // Validation 3. Check if submitter is right worker.
-    let assignee = read_worker_for_ticket(deps.storage, msg.tid)?;
-    if msg.worker != assignee {
+    // Validation 3. Check if submitter is one of the assigned workers.
+    let assignees = read_assignees(deps.storage, msg.tid)?;
+    if !assignees.contains(&msg.worker) {
         return Err(TAError::NotAuthorized.into());
     }
 
-    // Check the result & submission timestamp
-    let slash_perc = Uint128::from(0u128);
-    let ticket = match read_ticket_for_id(deps.storage, msg.tid) {
-        Ok(v) => v,
-        Err(e) => return Err(e),
+    // Validation 4. Check if the submission is not assessed or held yet.
+    // (the held submission can be replaced until the close when the ticket allows it)
+    let ticket = read_ticket_for_id(deps.storage, msg.tid)?;
+    let timestamp = env.block.time.seconds();
+    let open = ticket.resubmission && timestamp <= ticket.close_timestamp;
+    if read_outcome(deps.storage, msg.tid, &msg.worker)?.is_some() {
+        return Err(TAError::AlreadyAssessed.into());
+    }
+    if !open && read_pending_result(deps.storage, msg.tid, &msg.worker)?.is_some() {
+        return Err(TAError::AlreadySubmitted.into());
+    }
+
+    // Validation 5. Check if the submission is not after the cutoff of the lateness penalty.
+    let late_by = timestamp.saturating_sub(ticket.close_timestamp);
+    if ticket.lateness.penalty(late_by).is_none() {
+        return Err(TAError::SubmissionClosed.into());
+    }
+
+    // Record the submission for the audit.
+    store_submission(
+        deps.storage,
+        &Submission {
+            tid: msg.tid,
+            worker: msg.worker.clone(),
+            result: stored_result(&msg.result),
+            submitted_at: timestamp,
+            assessment: None,
+        },
+    )?;
+
+    let submission = PendingResult {
+        result: msg.result,
+        submitted_at: timestamp,
     };
 
-    // Apply 50% slash when result no match.
-    if ticket.result != msg.result {
-        slash_perc.checked_add(Uint128::from(500u128))?;
+    let msgs = match ticket.assessment {
+        // Hold the last submission until the ticket is closed.
+        _ if open => {
+            store_pending_result(deps.storage, msg.tid, &msg.worker, &submission)?;
+            vec![]
+        }
+        // Assess the result against the ticket's expected result right away.
+        AssessmentMode::ExpectedResult | AssessmentMode::PartialCredit => vec![assess_result(
+            deps.storage,
+            deps.api,
+            &config.admin_board,
+            &ticket,
+            &msg.worker,
+            expected_verdict(&ticket, &submission.result),
+            submission.submitted_at,
+            timestamp,
+        )?],
+        // Hold the submission until the grader reviews it.
+        AssessmentMode::Manual { .. } => {
+            store_pending_result(deps.storage, msg.tid, &msg.worker, &submission)?;
+            vec![]
+        }
+        // Hold the result until every assignee has submitted or is assessed.
+        AssessmentMode::Consensus => {
+            store_pending_result(deps.storage, msg.tid, &msg.worker, &submission)?;
+            let mut complete = true;
+            for worker in assignees.iter() {
+                if read_pending_result(deps.storage, msg.tid, worker)?.is_none()
+                    && read_outcome(deps.storage, msg.tid, worker)?.is_none()
+                {
+                    complete = false;
+                }
+            }
+            match complete {
+                true => assess_consensus(deps, &config, &ticket, timestamp)?,
+                false => vec![],
+            }
+        }
+    };
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attributes(vec![attr("method", "assess submission")]))
+}
+
+// Assess the pending results of the consensus ticket.
+// The results are grouped with the ticket's matcher. The result submitted by at least 2 &
+// the strict majority of all the assignees(no-shows included) is taken as correct,
+// the results are matched with the ticket's expected result when there is no such majority.
+fn assess_consensus(
+    deps: DepsMut,
+    config: &Config,
+    ticket: &Ticket,
+    timestamp: u64,
+) -> StdResult<Vec<CosmosMsg>> {
+    let pending = take_pending_results(deps.storage, ticket.id)?;
+    let assignees = read_assignees(deps.storage, ticket.id)?;
+
+    let mut counts: Vec<(&str, usize)> = vec![];
+    for (_, submission) in pending.iter() {
+        match counts
+            .iter_mut()
+            .find(|(r, _)| ticket.matcher.equivalent(r, &submission.result))
+        {
+            Some((_, count)) => *count += 1,
+            None => counts.push((&submission.result, 1)),
+        }
+    }
+    let voters = assignees.len().max(pending.len());
+    let consensus = counts
+        .iter()
+        .find(|(_, count)| *count >= 2 && count * 2 > voters)
+        .map(|(result, _)| result.to_string());
+
+    pending
+        .iter()
+        .map(|(worker, submission)| {
+            assess_result(
+                deps.storage,
+                deps.api,
+                &config.admin_board,
+                ticket,
+                worker,
+                Verdict::from(match &consensus {
+                    Some(consensus) => ticket.matcher.equivalent(consensus, &submission.result),
+                    None => ticket.matcher.matches(&ticket.result, &submission.result),
+                }),
+                submission.submitted_at,
+                timestamp,
+            )
+        })
+        .collect()
+}
+
+// Assess the held submissions of the closed ticket, "Manual" ones stay for the grader.
+fn assess_held_results(
+    deps: DepsMut,
+    config: &Config,
+    ticket: &Ticket,
+    timestamp: u64,
+) -> StdResult<Vec<CosmosMsg>> {
+    match ticket.assessment {
+        AssessmentMode::Consensus => assess_consensus(deps, config, ticket, timestamp),
+        AssessmentMode::Manual { .. } => Ok(vec![]),
+        AssessmentMode::ExpectedResult | AssessmentMode::PartialCredit => {
+            take_pending_results(deps.storage, ticket.id)?
+                .iter()
+                .map(|(worker, submission)| {
+                    assess_result(
+                        deps.storage,
+                        deps.api,
+                        &config.admin_board,
+                        ticket,
+                        worker,
+                        expected_verdict(ticket, &submission.result),
+                        submission.submitted_at,
+                        timestamp,
+                    )
+                })
+                .collect()
+        }
+    }
+}
+
+// Assess the last submissions of the closed ticket.
+fn close_submissions(deps: DepsMut, env: Env, info: MessageInfo, tid: u64) -> StdResult<Response> {
+    // Validation 1. Check if funds is provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
     }
-    // Apply 30% slash when timestamp passed.
+
+    // Validation 2. Check if the ticket is closed with the held submissions.
+    let config = read_config(deps.storage)?;
+    let ticket = read_ticket_for_id(deps.storage, tid)?;
     let timestamp = env.block.time.seconds();
-    if timestamp > ticket.close_timestamp {
-        slash_perc.checked_add(Uint128::from(300u128))?;
+    if timestamp <= ticket.close_timestamp {
+        return Err(TAError::NotClosed.into());
+    }
+    if matches!(ticket.assessment, AssessmentMode::Manual { .. }) {
+        return Err(TAError::NotGradable.into());
+    }
+    if read_pending_results(deps.storage, tid)?.is_empty() {
+        return Err(TAError::NotFound.into());
     }
 
-    // Create msg to be sent to admin contract for applying slash perc.
-    let msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: config.admin_board,
-        msg: to_json_binary(&AdminExecuteMsg::ReleaseStakeWithSlash(SlashMsg {
-            tid: msg.tid,
-            worker: deps.api.addr_validate(msg.worker.as_str())?,
-            slash_perc,
-        }))?,
-        funds: vec![],
-    })];
+    let msgs = assess_held_results(deps, &config, &ticket, timestamp)?;
 
     Ok(Response::new()
         .add_messages(msgs)
-        .add_attributes(vec![attr("method", "assess submission")]))
+        .add_attributes(vec![attr("method", "close submissions")]))
+}
+
+// Grade the submission of the "Manual" ticket & apply the slash derived from the score.
+fn grade_submission(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: GradeSubmissionMsg,
+) -> StdResult<Response> {
+    // Validation 1. Check if funds is provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+    // Validation 2. Check if the tx sender is admin.
+    let config = read_config(deps.storage)?;
+    if info.sender.to_string() != config.admin_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 3. Check the score & the notes.
+    if msg.score > MAX_SCORE {
+        return Err(TAError::InvalidGrade(format!("score exceeds {}", MAX_SCORE)).into());
+    }
+    if msg.notes.len() > MAX_NOTES_LEN {
+        return Err(TAError::InvalidGrade(format!("notes exceed {} bytes", MAX_NOTES_LEN)).into());
+    }
+
+    // Validation 4. Check if the submission is waiting for the grade.
+    let ticket = read_ticket_for_id(deps.storage, msg.tid)?;
+    if !matches!(ticket.assessment, AssessmentMode::Manual { .. }) {
+        return Err(TAError::NotGradable.into());
+    }
+    // The submission can still be replaced.
+    if ticket.resubmission && env.block.time.seconds() <= ticket.close_timestamp {
+        return Err(TAError::NotClosed.into());
+    }
+    let pending = read_pending_results(deps.storage, msg.tid)?;
+    let (worker, submission) = match &msg.worker {
+        Some(worker) => match pending.into_iter().find(|(w, _)| w == worker) {
+            Some(v) => v,
+            None => return Err(TAError::NotGradable.into()),
+        },
+        None if pending.len() == 1 => pending.into_iter().next().unwrap(),
+        None => return Err(TAError::NotGradable.into()),
+    };
+
+    remove_pending_result(deps.storage, msg.tid, &worker);
+    let verdict = Verdict::Graded(Grade {
+        score: msg.score,
+        notes: msg.notes,
+        grader: msg.grader,
+    });
+    let msgs = vec![assess_result(
+        deps.storage,
+        deps.api,
+        &config.admin_board,
+        &ticket,
+        &worker,
+        verdict,
+        submission.submitted_at,
+        env.block.time.seconds(),
+    )?];
+
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "grade submission"),
+        attr("worker", worker),
+        attr("score", msg.score.to_string()),
+    ]))
+}
+
+// Approve the submissions of the "Manual" ticket not graded until the review deadline
+// with the full score.
+fn auto_approve(deps: DepsMut, env: Env, info: MessageInfo, tid: u64) -> StdResult<Response> {
+    // Validation 1. Check if funds is provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+
+    // Validation 2. Check if any submission has passed the review deadline.
+    let config = read_config(deps.storage)?;
+    let ticket = read_ticket_for_id(deps.storage, tid)?;
+    let review_period = match ticket.assessment {
+        AssessmentMode::Manual { review_period } => review_period,
+        _ => return Err(TAError::NotGradable.into()),
+    };
+    let pending = read_pending_results(deps.storage, tid)?;
+    if pending.is_empty() {
+        return Err(TAError::NotGradable.into());
+    }
+    let timestamp = env.block.time.seconds();
+    if ticket.resubmission && timestamp <= ticket.close_timestamp {
+        return Err(TAError::NotClosed.into());
+    }
+    let expired = pending
+        .into_iter()
+        .filter(|(_, p)| p.submitted_at.saturating_add(review_period) < timestamp)
+        .collect::<Vec<(String, PendingResult)>>();
+    if expired.is_empty() {
+        return Err(TAError::ReviewNotExpired.into());
+    }
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    for (worker, submission) in expired.iter() {
+        remove_pending_result(deps.storage, tid, worker);
+        let verdict = Verdict::Graded(Grade {
+            score: MAX_SCORE,
+            notes: String::new(),
+            grader: None,
+        });
+        msgs.push(assess_result(
+            deps.storage,
+            deps.api,
+            &config.admin_board,
+            &ticket,
+            worker,
+            verdict,
+            submission.submitted_at,
+            timestamp,
+        )?);
+    }
+
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "auto approve"),
+        attr(
+            "workers",
+            expired
+                .iter()
+                .map(|(w, _)| w.clone())
+                .collect::<Vec<String>>()
+                .join(","),
+        ),
+    ]))
+}
+
+// Assessment of the submitted result.
+enum Verdict {
+    Correct,
+    Wrong,
+    // Score of the grader(per mille). At least half of the score counts as correct.
+    Graded(Grade),
+    // Share of the matched fields(per mille). Only the full match counts as correct.
+    Partial(u64),
+}
+
+impl From<bool> for Verdict {
+    fn from(correct: bool) -> Verdict {
+        match correct {
+            true => Verdict::Correct,
+            false => Verdict::Wrong,
+        }
+    }
+}
+
+impl Verdict {
+    // Correctness, grade, credit & slash(per mille) of the verdict before the lateness penalty.
+    // 50% slash when result no match, the missing score when graded,
+    // the share of 50% for the unmatched fields.
+    fn assess(self) -> (bool, Option<Grade>, Option<u64>, u64) {
+        match self {
+            Verdict::Correct => (true, None, None, 0),
+            Verdict::Wrong => (false, None, None, 500),
+            Verdict::Graded(grade) => {
+                let slash = MAX_SCORE - grade.score;
+                (grade.score * 2 >= MAX_SCORE, Some(grade), None, slash)
+            }
+            Verdict::Partial(credit) => {
+                let slash = (MAX_SCORE - credit) * 500 / MAX_SCORE;
+                (credit == MAX_SCORE, None, Some(credit), slash)
+            }
+        }
+    }
+}
+
+// Lateness penalty(per mille) of the ticket for the submission at "submitted_at",
+// the full slash when it is after the cutoff.
+fn lateness_penalty(ticket: &Ticket, submitted_at: u64) -> u64 {
+    let late_by = submitted_at.saturating_sub(ticket.close_timestamp);
+    ticket.lateness.penalty(late_by).unwrap_or(MAX_SCORE)
+}
+
+// Assess the result against the ticket's expected result.
+fn expected_verdict(ticket: &Ticket, result: &str) -> Verdict {
+    match ticket.assessment {
+        AssessmentMode::PartialCredit => {
+            Verdict::Partial(field_credit(&ticket.matcher, &ticket.result, result))
+        }
+        _ => Verdict::from(ticket.matcher.matches(&ticket.result, result)),
+    }
+}
+
+// Flat JSON object of string fields.
+fn parse_fields(result: &str) -> Option<BTreeMap<String, String>> {
+    from_json(result.as_bytes()).ok()
+}
+
+// Share of the expected fields(per mille) whose value is matched in the submitted result.
+// The submission which is not the JSON object of string fields has no credit.
+fn field_credit(matcher: &ResultMatcher, expected: &str, submitted: &str) -> u64 {
+    let (expected, submitted) = match (parse_fields(expected), parse_fields(submitted)) {
+        (Some(expected), Some(submitted)) if !expected.is_empty() => (expected, submitted),
+        _ => return 0,
+    };
+    let matched = expected
+        .iter()
+        .filter(|(field, value)| {
+            submitted
+                .get(*field)
+                .is_some_and(|submitted| matcher.matches(value, submitted))
+        })
+        .count() as u64;
+    matched * MAX_SCORE / expected.len() as u64
+}
+
+// Record the outcome of the worker's result
+// & create msg to be sent to admin contract for applying slash perc.
+#[allow(clippy::too_many_arguments)]
+fn assess_result(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    admin_board: &str,
+    ticket: &Ticket,
+    worker: &str,
+    verdict: Verdict,
+    submitted_at: u64,
+    assessed_at: u64,
+) -> StdResult<CosmosMsg> {
+    // Apply the slash of the verdict & the lateness penalty of the ticket when timestamp passed.
+    let (correct, grade, credit, slash) = verdict.assess();
+    let on_time = submitted_at <= ticket.close_timestamp;
+    let slash_perc = Uint128::from(
+        slash
+            .saturating_add(lateness_penalty(ticket, submitted_at))
+            .min(MAX_SCORE),
+    );
+
+    // Record the outcome for the worker's history.
+    store_outcome(
+        storage,
+        TicketOutcome {
+            tid: ticket.id,
+            worker: worker.to_string(),
+            correct,
+            on_time,
+            no_show: false,
+            slash_perc,
+            assessed_at,
+            grade,
+            credit,
+            reversed: false,
+        },
+    )?;
+    record_assessment(
+        storage,
+        ticket,
+        worker,
+        SubmissionAssessment {
+            correct,
+            on_time,
+            slash_perc,
+            released: Uint128::from(ticket.collateral)
+                - slash_amount(ticket.collateral, slash_perc),
+            assessed_at,
+        },
+    )?;
+
+    // Update the worker's statistics.
+    let mut stats = read_worker_stats(storage, worker)?;
+    stats.completed += 1;
+    if !on_time {
+        stats.late += 1;
+    }
+    if !correct {
+        stats.wrong += 1;
+    }
+    stats.total_slashed += slash_amount(ticket.collateral, slash_perc);
+    stats.slash_perc_sum += slash_perc;
+    store_worker_stats(storage, worker, &stats)?;
+
+    AdminBoardContract::new(admin_board).release_stake_with_slash(SlashMsg {
+        tid: ticket.id,
+        worker: api.addr_validate(worker)?,
+        slash_perc,
+    })
+}
+
+// Slash the whole stake of the workers who didn't submit the result until the ticket is closed.
+// The pending results of the consensus ticket are assessed along.
+fn report_no_show(deps: DepsMut, env: Env, info: MessageInfo, tid: u64) -> StdResult<Response> {
+    // Validation 1. Check if funds is provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+    // Validation 2. Check if the tx sender is admin.
+    let config = read_config(deps.storage)?;
+    if info.sender.to_string() != config.admin_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 3. Check if the ticket is closed without the submissions.
+    // (the late submissions are accepted until the cutoff of the lateness penalty)
+    let ticket = read_ticket_for_id(deps.storage, tid)?;
+    let timestamp = env.block.time.seconds();
+    let deadline = ticket
+        .close_timestamp
+        .saturating_add(ticket.lateness.cutoff());
+    if timestamp <= deadline {
+        return Err(TAError::NotClosed.into());
+    }
+    let assignees = read_assignees(deps.storage, tid)?;
+    if assignees.is_empty() {
+        return Err(TAError::NotFound.into());
+    }
+    let mut no_shows: Vec<String> = vec![];
+    let mut has_pending = false;
+    for worker in assignees {
+        if read_outcome(deps.storage, tid, &worker)?.is_some() {
+            continue;
+        }
+        match read_pending_result(deps.storage, tid, &worker)? {
+            Some(_) => has_pending = true,
+            None => no_shows.push(worker),
+        }
+    }
+    if no_shows.is_empty() {
+        return Err(TAError::AlreadyAssessed.into());
+    }
+
+    // Record the no-shows & apply the full slash.
+    let slash_perc = Uint128::from(1000u128);
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    for worker in no_shows.iter() {
+        store_outcome(
+            deps.storage,
+            TicketOutcome {
+                tid,
+                worker: worker.clone(),
+                correct: false,
+                on_time: false,
+                no_show: true,
+                slash_perc,
+                assessed_at: timestamp,
+                grade: None,
+                credit: None,
+                reversed: false,
+            },
+        )?;
+
+        let mut stats = read_worker_stats(deps.storage, worker)?;
+        stats.no_shows += 1;
+        stats.total_slashed += slash_amount(ticket.collateral, slash_perc);
+        stats.slash_perc_sum += slash_perc;
+        store_worker_stats(deps.storage, worker, &stats)?;
+
+        msgs.push(
+            AdminBoardContract::new(config.admin_board.clone()).release_stake_with_slash(
+                SlashMsg {
+                    tid,
+                    worker: deps.api.addr_validate(worker.as_str())?,
+                    slash_perc,
+                },
+            )?,
+        );
+    }
+
+    // The held submissions wait no more for the absent workers.
+    // (the submissions of the "Manual" ticket stay for the review)
+    if has_pending {
+        msgs.extend(assess_held_results(deps, &config, &ticket, timestamp)?);
+    }
+
+    Ok(Response::new().add_messages(msgs).add_attributes(vec![
+        attr("method", "report no-show"),
+        attr("worker", no_shows.join(",")),
+    ]))
+}
+
+// Undo the slash of the worker's outcome reversed by the arbiter on the dispute.
+// The slashed amount is returned by collateral_manager.
+fn reverse_slash(
+    deps: DepsMut,
+    info: MessageInfo,
+    tid: u64,
+    worker: String,
+) -> StdResult<Response> {
+    // Validation 1. Check if funds is provided
+    if !info.funds.is_empty() {
+        return Err(TAError::UnnecessaryFunds.into());
+    }
+    // Validation 2. Check if the tx sender is admin.
+    let config = read_config(deps.storage)?;
+    if info.sender.to_string() != config.admin_board {
+        return Err(TAError::NotAuthorized.into());
+    }
+
+    // Validation 3. Check if the worker's outcome is slashed.
+    let mut outcome = match read_outcome(deps.storage, tid, &worker)? {
+        Some(outcome) if !outcome.reversed && !outcome.slash_perc.is_zero() => outcome,
+        _ => return Err(TAError::NotFound.into()),
+    };
+
+    let ticket = read_ticket_for_id(deps.storage, tid)?;
+    let mut stats = read_worker_stats(deps.storage, &worker)?;
+    stats.total_slashed = stats
+        .total_slashed
+        .saturating_sub(slash_amount(ticket.collateral, outcome.slash_perc));
+    stats.slash_perc_sum = stats.slash_perc_sum.saturating_sub(outcome.slash_perc);
+    store_worker_stats(deps.storage, &worker, &stats)?;
+
+    if let Some(mut submission) = read_submission(deps.storage, tid, &worker)? {
+        if let Some(assessment) = submission.assessment.as_mut() {
+            assessment.slash_perc = Uint128::zero();
+            assessment.released = Uint128::from(ticket.collateral);
+        }
+        store_submission(deps.storage, &submission)?;
+    }
+
+    outcome.slash_perc = Uint128::zero();
+    outcome.reversed = true;
+    store_outcome(deps.storage, outcome)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "reverse slash"),
+        attr("tid", tid.to_string()),
+        attr("worker", worker),
+    ]))
+}
+
+// Result recorded in the submission history, the long one is replaced by its hash.
+fn stored_result(result: &str) -> String {
+    if result.len() <= MAX_STORED_RESULT_LEN {
+        return result.to_string();
+    }
+    format!("sha256:{}", sha256_hex(result.as_bytes()))
+}
+
+// Record the assessment in the worker's submission history.
+fn record_assessment(
+    storage: &mut dyn Storage,
+    ticket: &Ticket,
+    worker: &str,
+    assessment: SubmissionAssessment,
+) -> StdResult<()> {
+    match read_submission(storage, ticket.id, worker)? {
+        Some(submission) => store_submission(
+            storage,
+            &Submission {
+                assessment: Some(assessment),
+                ..submission
+            },
+        ),
+        None => Ok(()),
+    }
+}
+
+// Slashed amount of the collateral for given slash percentage(per mille).
+fn slash_amount(collateral: u64, slash_perc: Uint128) -> Uint128 {
+    Uint128::from(collateral).multiply_ratio(slash_perc, 1000u128)
 }
 
 fn execute_post_config(
@@ -272,6 +1374,7 @@ fn execute_post_config(
 
     config.auction_manager = msg.auction_manager;
     config.user_board = msg.user_board;
+    config.collateral_manager = msg.collateral_manager;
 
     store_config(deps.storage, config)?;
 
@@ -279,43 +1382,90 @@ fn execute_post_config(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::QueryTicketInfo { tid } => to_json_binary(&query_ticket(deps, tid)?),
-        QueryMsg::QueryTickets {} => to_json_binary(&query_tickets(deps)?),
+        QueryMsg::QueryTickets { category, tag } => {
+            to_json_binary(&query_tickets(deps, category, tag)?)
+        }
         QueryMsg::QueryTicketWorkerPairs {} => to_json_binary(&query_ticket_worker_pairs(deps)?),
         QueryMsg::QueryTicketWorker { tid } => to_json_binary(&query_ticket_worker(deps, tid)?),
+        QueryMsg::QueryTicketWorkers { tid } => to_json_binary(&read_assignees(deps.storage, tid)?),
+        QueryMsg::QueryWorkerTickets { worker } => {
+            to_json_binary(&query_worker_tickets(deps, worker)?)
+        }
+        QueryMsg::QueryWorkerReputation { worker } => {
+            to_json_binary(&query_worker_reputation(deps, worker)?)
+        }
+        QueryMsg::QueryTicketLimits {} => to_json_binary(&read_limits(deps.storage)?),
+        QueryMsg::QueryPendingReviews {} => to_json_binary(&query_pending_reviews(deps)?),
+        QueryMsg::QuerySubmission { tid, worker } => {
+            to_json_binary(&read_submission(deps.storage, tid, &worker)?)
+        }
+        QueryMsg::QuerySubmissionsByWorker { worker } => {
+            to_json_binary(&query_submissions_by_worker(deps, worker)?)
+        }
+        QueryMsg::QueryLatenessPenalty { tid, at_time } => to_json_binary(&query_lateness(
+            deps,
+            tid,
+            at_time.unwrap_or(env.block.time.seconds()),
+        )?),
+        QueryMsg::SimulateAssessment {
+            tid,
+            worker,
+            result,
+            at_time,
+        } => to_json_binary(&query_simulate_assessment(
+            deps,
+            tid,
+            worker,
+            result,
+            at_time.unwrap_or(env.block.time.seconds()),
+        )?),
     }
 }
 
 fn query_ticket(deps: Deps, id: u64) -> StdResult<TicketInfoResponse> {
-    let tickets = read_tickets(deps.storage)?;
-    let ticket = match tickets.into_iter().find(|t| t.id == id) {
-        Some(t) => t,
-        None => return Err(TAError::NotFound.into()),
-    };
+    let ticket = read_ticket_for_id(deps.storage, id)?;
     Ok(TicketInfoResponse {
         id: ticket.id,
         bet_finish_timestamp: ticket.bet_finish_timestamp,
         close_timestamp: ticket.close_timestamp,
         result: ticket.result,
         collateral: ticket.collateral,
+        auction: ticket.auction,
+        metadata: ticket.metadata,
+        creator: ticket.creator,
+        slots: ticket.slots,
+        assessment: ticket.assessment,
+        resubmission: ticket.resubmission,
+        matcher: ticket.matcher,
+        lateness: ticket.lateness,
     })
 }
 
-fn query_tickets(deps: Deps) -> StdResult<Vec<Ticket>> {
-    read_tickets(deps.storage)
+// Query the tickets matching every given filter.
+fn query_tickets(
+    deps: Deps,
+    category: Option<String>,
+    tag: Option<String>,
+) -> StdResult<TicketsResponse> {
+    let tickets = read_tickets(deps.storage)?
+        .into_iter()
+        .filter(|t| category.is_none() || t.metadata.category == category)
+        .filter(|t| tag.as_ref().is_none_or(|tag| t.metadata.tags.contains(tag)))
+        .collect();
+    Ok(TicketsResponse { tickets })
 }
 
 fn query_ticket_worker_pairs(deps: Deps) -> StdResult<TWPairsReponse> {
     let tids = read_all_assigned_tickets(deps.storage)?;
-    let tw_pairs = tids
-        .into_iter()
-        .map(|tid| {
-            let worker = read_worker_for_ticket(deps.storage, tid).unwrap();
-            TicketWorkerPair { tid, worker }
-        })
-        .collect::<Vec<TicketWorkerPair>>();
+    let mut tw_pairs: Vec<TicketWorkerPair> = vec![];
+    for tid in tids {
+        for worker in read_assignees(deps.storage, tid)? {
+            tw_pairs.push(TicketWorkerPair { tid, worker });
+        }
+    }
     Ok(TWPairsReponse { pairs: tw_pairs })
 }
 
@@ -327,7 +1477,139 @@ fn query_ticket_worker(deps: Deps, tid: u64) -> StdResult<String> {
     Ok(worker)
 }
 
+// Query the tickets won by the worker along with their assessment outcomes.
+fn query_worker_tickets(deps: Deps, worker: String) -> StdResult<WorkerTicketsResponse> {
+    let tids = read_worker_tickets(deps.storage, &worker)?;
+
+    let assignments = tids
+        .into_iter()
+        .filter_map(|tid| {
+            // Skip the tickets removed after the assignment.
+            let ticket = read_ticket_for_id(deps.storage, tid).ok()?;
+            Some((tid, ticket.close_timestamp))
+        })
+        .map(|(tid, close_timestamp)| {
+            Ok(WorkerAssignment {
+                tid,
+                close_timestamp,
+                outcome: read_outcome(deps.storage, tid, &worker)?,
+            })
+        })
+        .collect::<StdResult<Vec<WorkerAssignment>>>()?;
+
+    Ok(WorkerTicketsResponse {
+        worker,
+        assignments,
+    })
+}
+
+// Query the lateness penalty of the submission at "at_time".
+fn query_lateness(deps: Deps, tid: u64, at_time: u64) -> StdResult<LatenessResponse> {
+    let ticket = read_ticket_for_id(deps.storage, tid)?;
+    let late_by = at_time.saturating_sub(ticket.close_timestamp);
+    let penalty = ticket.lateness.penalty(late_by);
+    Ok(LatenessResponse {
+        tid,
+        at_time,
+        late_by,
+        slash_perc: Uint128::from(penalty.unwrap_or(MAX_SCORE)),
+        rejected: penalty.is_none(),
+    })
+}
+
+// Simulate the assessment of the worker's submission of "result" at "at_time".
+// The worker need not be assigned yet, so that the ticket can be evaluated before the bet.
+fn query_simulate_assessment(
+    deps: Deps,
+    tid: u64,
+    worker: String,
+    result: String,
+    at_time: u64,
+) -> StdResult<SimulateAssessmentResponse> {
+    // Validation 1. Check if the worker's submission is not assessed yet.
+    deps.api.addr_validate(&worker)?;
+    let ticket = read_ticket_for_id(deps.storage, tid)?;
+    if read_outcome(deps.storage, tid, &worker)?.is_some() {
+        return Err(TAError::AlreadyAssessed.into());
+    }
+
+    // The consensus & the grade are decided later, the held result is taken as correct.
+    let (matched, credit, slash) = match ticket.assessment {
+        AssessmentMode::ExpectedResult | AssessmentMode::PartialCredit => {
+            let (matched, _, credit, slash) = expected_verdict(&ticket, &result).assess();
+            (Some(matched), credit, slash)
+        }
+        AssessmentMode::Consensus | AssessmentMode::Manual { .. } => (None, None, 0),
+    };
+    let penalty = lateness_penalty(&ticket, at_time);
+    let slash_perc = Uint128::from(slash.saturating_add(penalty).min(MAX_SCORE));
+    let late_by = at_time.saturating_sub(ticket.close_timestamp);
+
+    Ok(SimulateAssessmentResponse {
+        tid,
+        worker,
+        at_time,
+        matched,
+        credit,
+        held: matched.is_none(),
+        on_time: late_by == 0,
+        lateness_penalty: Uint128::from(penalty),
+        rejected: ticket.lateness.penalty(late_by).is_none(),
+        slash_perc,
+        collateral: Uint128::from(ticket.collateral),
+        released: Uint128::from(ticket.collateral) - slash_amount(ticket.collateral, slash_perc),
+    })
+}
+
+// Query the submission history of the worker.
+fn query_submissions_by_worker(deps: Deps, worker: String) -> StdResult<Vec<Submission>> {
+    let mut tids = read_worker_tickets(deps.storage, &worker)?;
+    tids.sort_unstable();
+    tids.dedup();
+
+    let mut submissions: Vec<Submission> = vec![];
+    for tid in tids {
+        if let Some(submission) = read_submission(deps.storage, tid, &worker)? {
+            submissions.push(submission);
+        }
+    }
+    Ok(submissions)
+}
+
+// Query the submissions of the "Manual" tickets waiting for the grade.
+fn query_pending_reviews(deps: Deps) -> StdResult<Vec<PendingReview>> {
+    let mut reviews: Vec<PendingReview> = vec![];
+    for ((tid, worker), pending) in read_all_pending_results(deps.storage)? {
+        // Skip the tickets removed after the submission.
+        let ticket = match read_ticket_for_id(deps.storage, tid) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+        if let AssessmentMode::Manual { review_period } = ticket.assessment {
+            reviews.push(PendingReview {
+                tid,
+                worker,
+                result: pending.result,
+                submitted_at: pending.submitted_at,
+                review_deadline: pending.submitted_at.saturating_add(review_period),
+            });
+        }
+    }
+    Ok(reviews)
+}
+
+// Query the worker's statistics & the derived reputation score.
+fn query_worker_reputation(deps: Deps, worker: String) -> StdResult<WorkerReputationResponse> {
+    let stats = read_worker_stats(deps.storage, &worker)?;
+    let score = stats.reputation_score();
+    Ok(WorkerReputationResponse {
+        worker,
+        stats,
+        score,
+    })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+    migrate_contract(deps, CONTRACT_NAME, CONTRACT_VERSION, MIGRATIONS)
 }
@@ -0,0 +1,25 @@
+use cosmwasm_std::{DepsMut, StdResult};
+use cw_storage_plus::Item;
+
+use crate::state::store_ticket;
+use ticket_auction::migration::MigrationStep;
+use ticket_auction::ticket_manager::Ticket;
+
+// Storage migrations of ticket-manager, in the ascending order of the version.
+pub const MIGRATIONS: &[MigrationStep] = &[MigrationStep {
+    version: "0.2.0",
+    migrate: migrate_tickets_to_map,
+}];
+
+// Tickets were stored in a single "Vec" before 0.2.0.
+const LEGACY_TICKETS: Item<Vec<Ticket>> = Item::new("tickets");
+
+// 0.2.0: Move the tickets from the single "Vec" to the map keyed by the ticket id.
+fn migrate_tickets_to_map(deps: DepsMut) -> StdResult<()> {
+    let tickets = LEGACY_TICKETS.may_load(deps.storage)?.unwrap_or_default();
+    for ticket in tickets.iter() {
+        store_ticket(deps.storage, ticket)?;
+    }
+    LEGACY_TICKETS.remove(deps.storage);
+    Ok(())
+}
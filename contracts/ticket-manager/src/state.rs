@@ -6,29 +6,43 @@ use cw_storage_plus::{Item, Map};
 
 use ticket_auction::{
     error::TAError,
-    ticket_manager::{Ticket, TicketWorkerPair},
+    ticket_manager::{
+        Submission, Ticket, TicketLimits, TicketOutcome, TicketWorkerPair, WorkerStats,
+    },
 };
 
-pub const TICKETS: Item<Vec<Ticket>> = Item::new("tickets");
+pub const TICKETS: Map<u64, Ticket> = Map::new("ticket");
 
 // **=================================================
 // ** Tickets: Read and write operations      ========
 // **=================================================
 
-// Store tickets
-pub fn store_tickets(storage: &mut dyn Storage, tickets: Vec<Ticket>) -> StdResult<()> {
-    TICKETS.save(storage, &tickets)
+// Store a ticket
+pub fn store_ticket(storage: &mut dyn Storage, ticket: &Ticket) -> StdResult<()> {
+    TICKETS.save(storage, ticket.id, ticket)
+}
+
+// Remove a ticket with given id
+pub fn remove_ticket_for_id(storage: &mut dyn Storage, tid: u64) {
+    TICKETS.remove(storage, tid)
+}
+
+// Check if the ticket with given id exists
+pub fn has_ticket(storage: &dyn Storage, tid: u64) -> bool {
+    TICKETS.has(storage, tid)
 }
 
 // Read tickets
 pub fn read_tickets(storage: &dyn Storage) -> StdResult<Vec<Ticket>> {
-    TICKETS.load(storage)
+    TICKETS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, t)| t))
+        .collect()
 }
 
 // Read a single ticket with given id
 pub fn read_ticket_for_id(storage: &dyn Storage, tid: u64) -> StdResult<Ticket> {
-    let tickets = TICKETS.load(storage)?;
-    match tickets.into_iter().find(|t| t.id == tid) {
+    match TICKETS.may_load(storage, tid)? {
         Some(t) => Ok(t),
         None => Err(TAError::NotFound.into()),
     }
@@ -39,6 +53,8 @@ pub struct Config {
     pub admin_board: String,
     pub auction_manager: Option<String>,
     pub user_board: Option<String>,
+    #[serde(default)]
+    pub collateral_manager: Option<String>,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -55,6 +71,20 @@ pub fn read_config(storage: &dyn Storage) -> StdResult<Config> {
     CONFIG.load(storage)
 }
 
+pub const LIMITS: Item<TicketLimits> = Item::new("limits");
+// **=================================================
+// ** LIMITS: Read and write operations       ========
+// **=================================================
+// Store the ticket limits
+pub fn store_limits(storage: &mut dyn Storage, limits: &TicketLimits) -> StdResult<()> {
+    LIMITS.save(storage, limits)
+}
+
+// Read the ticket limits, the default limits until admin sets them.
+pub fn read_limits(storage: &dyn Storage) -> StdResult<TicketLimits> {
+    Ok(LIMITS.may_load(storage)?.unwrap_or_default())
+}
+
 pub const TWPAIR: Map<u64, String> = Map::new("TicketWorkerPair");
 // **=================================================
 // ** TWPAIR: Read and write operations       ========
@@ -71,6 +101,30 @@ pub fn read_worker_for_ticket(storage: &dyn Storage, tid: u64) -> StdResult<Stri
     TWPAIR.load(storage, tid)
 }
 
+// Every worker assigned on the ticket, in the order of the assignment.
+// "TWPAIR" keeps the first one.
+pub const ASSIGNEES: Map<u64, Vec<String>> = Map::new("Assignees");
+
+// Append the worker to the assignees of the ticket
+pub fn store_assignee(storage: &mut dyn Storage, tid: u64, worker: &str) -> StdResult<()> {
+    let mut workers = read_assignees(storage, tid)?;
+    workers.push(worker.to_string());
+    ASSIGNEES.save(storage, tid, &workers)
+}
+
+// Read the assignees of the ticket.
+// The tickets assigned before the multi-worker tickets have only "TWPAIR".
+pub fn read_assignees(storage: &dyn Storage, tid: u64) -> StdResult<Vec<String>> {
+    if let Some(workers) = ASSIGNEES.may_load(storage, tid)? {
+        return Ok(workers);
+    }
+    Ok(TWPAIR
+        .may_load(storage, tid)?
+        .filter(|w| !w.is_empty())
+        .into_iter()
+        .collect())
+}
+
 // Read all of tickets(array of won bots)
 pub fn read_all_assigned_tickets(storage: &dyn Storage) -> StdResult<Vec<u64>> {
     let keys = TWPAIR
@@ -79,3 +133,149 @@ pub fn read_all_assigned_tickets(storage: &dyn Storage) -> StdResult<Vec<u64>> {
         .collect::<Vec<u64>>();
     Ok(keys)
 }
+
+// Tickets won by the worker.
+pub const WORKER_TICKETS: Map<&str, Vec<u64>> = Map::new("WorkerTickets");
+// **=================================================
+// ** WORKER_TICKETS: Read and write operations ======
+// **=================================================
+// Append the ticket to the worker's assignments
+pub fn store_worker_ticket(storage: &mut dyn Storage, worker: &str, tid: u64) -> StdResult<()> {
+    let mut tids = read_worker_tickets(storage, worker)?;
+    if !tids.contains(&tid) {
+        tids.push(tid);
+    }
+    WORKER_TICKETS.save(storage, worker, &tids)
+}
+
+// Read the worker's assignments
+pub fn read_worker_tickets(storage: &dyn Storage, worker: &str) -> StdResult<Vec<u64>> {
+    Ok(WORKER_TICKETS
+        .may_load(storage, worker)?
+        .unwrap_or_default())
+}
+
+pub const WORKER_OUTCOMES: Map<(u64, &str), TicketOutcome> = Map::new("WorkerOutcomes");
+// **=================================================
+// ** OUTCOMES: Read and write operations     ========
+// **=================================================
+// Store the assessment outcome of the worker's submission
+pub fn store_outcome(storage: &mut dyn Storage, outcome: TicketOutcome) -> StdResult<()> {
+    WORKER_OUTCOMES.save(storage, (outcome.tid, &outcome.worker), &outcome)
+}
+
+// Read the assessment outcome of the worker's submission
+pub fn read_outcome(
+    storage: &dyn Storage,
+    tid: u64,
+    worker: &str,
+) -> StdResult<Option<TicketOutcome>> {
+    WORKER_OUTCOMES.may_load(storage, (tid, worker))
+}
+
+// Submission held until it is assessed:
+//   - the result of the "Consensus" ticket waiting for the other assignees
+//   - the submission of the "Manual" ticket waiting for the grade
+//   - the last submission of the "resubmission" ticket waiting for the close
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingResult {
+    pub result: String,
+    pub submitted_at: u64,
+}
+
+pub const PENDING_RESULTS: Map<(u64, &str), PendingResult> = Map::new("PendingResults");
+// **=================================================
+// ** PENDING_RESULTS: Read and write operations =====
+// **=================================================
+// Store the worker's result until the ticket is assessed
+pub fn store_pending_result(
+    storage: &mut dyn Storage,
+    tid: u64,
+    worker: &str,
+    pending: &PendingResult,
+) -> StdResult<()> {
+    PENDING_RESULTS.save(storage, (tid, worker), pending)
+}
+
+// Read the pending result of the worker
+pub fn read_pending_result(
+    storage: &dyn Storage,
+    tid: u64,
+    worker: &str,
+) -> StdResult<Option<PendingResult>> {
+    PENDING_RESULTS.may_load(storage, (tid, worker))
+}
+
+// Read every pending result of the ticket. (worker, result)
+pub fn read_pending_results(
+    storage: &dyn Storage,
+    tid: u64,
+) -> StdResult<Vec<(String, PendingResult)>> {
+    PENDING_RESULTS
+        .prefix(tid)
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+// Read every pending result. ((tid, worker), result)
+pub fn read_all_pending_results(
+    storage: &dyn Storage,
+) -> StdResult<Vec<((u64, String), PendingResult)>> {
+    PENDING_RESULTS
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+// Remove the pending result of the worker
+pub fn remove_pending_result(storage: &mut dyn Storage, tid: u64, worker: &str) {
+    PENDING_RESULTS.remove(storage, (tid, worker))
+}
+
+// Read & remove every pending result of the ticket. (worker, result)
+pub fn take_pending_results(
+    storage: &mut dyn Storage,
+    tid: u64,
+) -> StdResult<Vec<(String, PendingResult)>> {
+    let pending = read_pending_results(storage, tid)?;
+    for (worker, _) in pending.iter() {
+        remove_pending_result(storage, tid, worker);
+    }
+    Ok(pending)
+}
+
+// History of the submissions, kept after the assessment.
+pub const SUBMISSIONS: Map<(u64, &str), Submission> = Map::new("Submissions");
+// **=================================================
+// ** SUBMISSIONS: Read and write operations  ========
+// **=================================================
+// Store the worker's submission
+pub fn store_submission(storage: &mut dyn Storage, submission: &Submission) -> StdResult<()> {
+    SUBMISSIONS.save(storage, (submission.tid, &submission.worker), submission)
+}
+
+// Read the worker's submission on the ticket
+pub fn read_submission(
+    storage: &dyn Storage,
+    tid: u64,
+    worker: &str,
+) -> StdResult<Option<Submission>> {
+    SUBMISSIONS.may_load(storage, (tid, worker))
+}
+
+pub const WORKER_STATS: Map<&str, WorkerStats> = Map::new("WorkerStats");
+// **=================================================
+// ** WORKER_STATS: Read and write operations ========
+// **=================================================
+// Store the worker's statistics
+pub fn store_worker_stats(
+    storage: &mut dyn Storage,
+    worker: &str,
+    stats: &WorkerStats,
+) -> StdResult<()> {
+    WORKER_STATS.save(storage, worker, stats)
+}
+
+// Read the worker's statistics(empty for the new worker)
+pub fn read_worker_stats(storage: &dyn Storage, worker: &str) -> StdResult<WorkerStats> {
+    Ok(WORKER_STATS.may_load(storage, worker)?.unwrap_or_default())
+}
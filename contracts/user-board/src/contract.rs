@@ -2,24 +2,27 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     attr, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
-    WasmMsg,
 };
-use ticket_auction::auction_manager::{
-    PlaceBetMsg as AuctionPlaceBetMsg, QueryMsg as AuctionQueryMsg,
-};
-use ticket_auction::ticket_manager::{
-    ExecuteMsg as TicketExecuteMsg, QueryMsg as TicketQueryMsg, TicketResultMsg,
+use cw2::set_contract_version;
+use ticket_auction::auction_manager::PlaceBetMsg as AuctionPlaceBetMsg;
+use ticket_auction::helpers::{
+    AuctionManagerContract, CollateralManagerContract, TicketManagerContract,
 };
+use ticket_auction::migration::migrate_contract;
+use ticket_auction::ticket_manager::{TicketResultMsg, TicketsResponse};
 
 use crate::state::{read_config, store_config, Config};
-use ticket_auction::collateral_manager::{
-    ExecuteMsg as CollateralExecuteMsg, QueryMsg as CollateralQueryMsg, QueryStakeStatusMsg,
-};
+use ticket_auction::collateral_manager::QueryStakeStatusMsg;
 use ticket_auction::error::TAError;
 use ticket_auction::user_board::{
     ExecuteMsg, InstantiateMsg, MigrateMsg, PlaceBetMsg, PostConfigMsg, QueryMsg,
+    WorkerDashboardResponse,
 };
 
+// Contract info recorded by cw2, checked on the migration.
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -42,6 +45,8 @@ pub fn instantiate(
 
     store_config(deps.storage, config)?;
 
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     Ok(Response::new()
         .add_attribute("method", "instantiate")
         .add_attribute("owner", info.sender))
@@ -59,6 +64,9 @@ pub fn execute(
         ExecuteMsg::LockStake { tid } => execute_lock_stake(deps, info, tid),
         ExecuteMsg::PlaceBet(msg) => execute_place_bet(deps, info, msg),
         ExecuteMsg::SubmitResult(msg) => execute_submit_result(deps, info, msg),
+        ExecuteMsg::DisputeAssessment { tid, evidence_uri } => {
+            execute_dispute_assessment(deps, info, tid, evidence_uri)
+        }
 
         // Utilities
         ExecuteMsg::PostConfig(msg) => execute_post_config(deps, info, msg),
@@ -81,24 +89,17 @@ fn execute_lock_stake(deps: DepsMut, info: MessageInfo, tid: u64) -> StdResult<R
         Some(v) => v,
         None => return Err(TAError::NotInitialized.into()),
     };
-    let msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: collateral_manager,
-        msg: to_json_binary(&CollateralExecuteMsg::LockStake { tid })?,
-        funds: info.funds,
-    })];
+    let msgs: Vec<CosmosMsg> = vec![CollateralManagerContract::new(collateral_manager)
+        .lock_stake(tid, info.sender, info.funds)?];
 
     Ok(Response::new()
         .add_messages(msgs)
         .add_attributes(vec![attr("method", "lock stake")]))
 }
 
+// The bet fee sent as "funds" is forwarded to auction_manager, which checks it.
 fn execute_place_bet(deps: DepsMut, info: MessageInfo, msg: PlaceBetMsg) -> StdResult<Response> {
-    // Validation 1: Check if the funds are provided
-    if !info.funds.is_empty() {
-        return Err(TAError::UnnecessaryFunds.into());
-    }
-
-    // Validation 2: Check if the info.sender(worker) already locked stake
+    // Validation 1: Check if the info.sender(worker) already locked stake
     let worker = info.sender.clone();
     let tid = msg.ticket_id;
     let config = read_config(deps.storage)?;
@@ -106,12 +107,10 @@ fn execute_place_bet(deps: DepsMut, info: MessageInfo, msg: PlaceBetMsg) -> StdR
         Some(v) => v,
         None => return Err(TAError::NotInitialized.into()),
     };
-    let is_staked: bool = deps.querier.query_wasm_smart(
-        collateral_manager,
-        &to_json_binary(&CollateralQueryMsg::QueryStakeStatus(QueryStakeStatusMsg {
-            tid,
-            worker,
-        }))?,
+    let is_staked = CollateralManagerContract::new(collateral_manager).query_stake_status(
+        &deps.querier,
+        tid,
+        worker,
     )?;
 
     if !is_staked {
@@ -122,15 +121,14 @@ fn execute_place_bet(deps: DepsMut, info: MessageInfo, msg: PlaceBetMsg) -> StdR
         Some(v) => v,
         None => return Err(TAError::NotInitialized.into()),
     };
-    let msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: auction_manager,
-        msg: to_json_binary(&AuctionPlaceBetMsg {
+    let msgs: Vec<CosmosMsg> = vec![AuctionManagerContract::new(auction_manager).place_bet(
+        AuctionPlaceBetMsg {
             ticket_id: tid,
             workder: info.sender.to_string(),
             bet_amount: msg.bet_amount,
-        })?,
-        funds: vec![],
-    })];
+        },
+        info.funds,
+    )?];
 
     Ok(Response::new()
         .add_messages(msgs)
@@ -146,39 +144,58 @@ fn execute_submit_result(
     if !info.funds.is_empty() {
         return Err(TAError::UnnecessaryFunds.into());
     }
-    // Validation 2: Check if tx send is the right worker for ticket
+    // Validation 2: Check if tx send is one of the workers assigned on the ticket
     let tid = msg.tid;
     let worker = info.sender;
     let config = read_config(deps.storage)?;
     let ticket_manager = match config.ticket_manager {
-        Some(v) => v,
+        Some(v) => TicketManagerContract::new(v),
         None => return Err(TAError::NotInitialized.into()),
     };
-    let right_worker: String = deps.querier.query_wasm_smart(
-        ticket_manager.clone(),
-        &to_json_binary(&TicketQueryMsg::QueryTicketWorker { tid })?,
-    )?;
+    let right_workers = ticket_manager.query_ticket_workers(&deps.querier, tid)?;
 
-    if worker.to_string() != right_worker {
+    if !right_workers.contains(&worker.to_string()) {
         return Err(TAError::NotAuthorized.into());
     }
 
-    // Call the method of "ticket_manager".
-    let msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: ticket_manager,
-        msg: to_json_binary(&TicketExecuteMsg::AssessSubmission(TicketResultMsg {
-            tid,
-            worker: msg.worker,
-            result: msg.result,
-        }))?,
-        funds: vec![],
-    })];
+    // Call the method of "ticket_manager". (the result is always submitted for the sender)
+    let msgs: Vec<CosmosMsg> = vec![ticket_manager.assess_submission(TicketResultMsg {
+        tid,
+        worker: worker.to_string(),
+        result: msg.result,
+    })?];
 
     Ok(Response::new()
         .add_messages(msgs)
         .add_attributes(vec![attr("method", "submit result")]))
 }
 
+// Dispute the slash of the sender's submission, the bond is forwarded to "collateral_manager".
+fn execute_dispute_assessment(
+    deps: DepsMut,
+    info: MessageInfo,
+    tid: u64,
+    evidence_uri: String,
+) -> StdResult<Response> {
+    // Validation 1: Check if tx sender is valid wallet.
+    if deps.api.addr_canonicalize(info.sender.as_str()).is_err() {
+        return Err(TAError::InvalidAddress.into());
+    }
+
+    // Call the method of "collateral_manager". (the dispute is always opened for the sender)
+    let config = read_config(deps.storage)?;
+    let collateral_manager = match config.collateral_manager {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+    let msgs: Vec<CosmosMsg> = vec![CollateralManagerContract::new(collateral_manager)
+        .open_dispute(tid, info.sender, evidence_uri, info.funds)?];
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attributes(vec![attr("method", "dispute assessment")]))
+}
+
 fn execute_post_config(
     deps: DepsMut,
     info: MessageInfo,
@@ -207,7 +224,13 @@ fn execute_post_config(
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::QueryBetAvailTickets {} => to_json_binary(&query_bet_avail_tickets(deps)?),
+        QueryMsg::QueryTickets { category, tag } => {
+            to_json_binary(&query_tickets(deps, category, tag)?)
+        }
         QueryMsg::QueryStakeStatus(msg) => to_json_binary(&query_stake_status(deps, msg)?),
+        QueryMsg::QueryWorkerDashboard { worker } => {
+            to_json_binary(&query_worker_dashboard(deps, worker)?)
+        }
     }
 }
 
@@ -217,11 +240,20 @@ fn query_bet_avail_tickets(deps: Deps) -> StdResult<Vec<u64>> {
         Some(v) => v,
         None => return Err(TAError::NotInitialized.into()),
     };
-    let avail_tickets: Vec<u64> = deps.querier.query_wasm_smart(
-        auction_manager,
-        &to_json_binary(&AuctionQueryMsg::BetAvailableTickets {})?,
-    )?;
-    Ok(avail_tickets)
+    AuctionManagerContract::new(auction_manager).query_bet_available_tickets(&deps.querier)
+}
+
+fn query_tickets(
+    deps: Deps,
+    category: Option<String>,
+    tag: Option<String>,
+) -> StdResult<TicketsResponse> {
+    let config = read_config(deps.storage)?;
+    let ticket_manager = match config.ticket_manager {
+        Some(v) => v,
+        None => return Err(TAError::NotInitialized.into()),
+    };
+    TicketManagerContract::new(ticket_manager).query_tickets(&deps.querier, category, tag)
 }
 
 fn query_stake_status(deps: Deps, msg: QueryStakeStatusMsg) -> StdResult<bool> {
@@ -231,14 +263,57 @@ fn query_stake_status(deps: Deps, msg: QueryStakeStatusMsg) -> StdResult<bool> {
         None => return Err(TAError::NotInitialized.into()),
     };
 
-    let stake_status: bool = deps.querier.query_wasm_smart(
-        collateral_manager,
-        &to_json_binary(&CollateralQueryMsg::QueryStakeStatus(msg))?,
-    )?;
-    Ok(stake_status)
+    CollateralManagerContract::new(collateral_manager).query_stake_status(
+        &deps.querier,
+        msg.tid,
+        msg.worker,
+    )
+}
+
+// Query the worker's stakes, bets, assignments & outcomes in one place.
+fn query_worker_dashboard(deps: Deps, worker: String) -> StdResult<WorkerDashboardResponse> {
+    let worker_addr = deps.api.addr_validate(&worker)?;
+    let config = read_config(deps.storage)?;
+    let collateral_manager = match config.collateral_manager {
+        Some(v) => CollateralManagerContract::new(v),
+        None => return Err(TAError::NotInitialized.into()),
+    };
+    let auction_manager = match config.auction_manager {
+        Some(v) => AuctionManagerContract::new(v),
+        None => return Err(TAError::NotInitialized.into()),
+    };
+    let ticket_manager = match config.ticket_manager {
+        Some(v) => TicketManagerContract::new(v),
+        None => return Err(TAError::NotInitialized.into()),
+    };
+
+    let staked_tickets = collateral_manager.query_worker_stakes(&deps.querier, worker_addr)?;
+    let active_bets = auction_manager.query_worker_active_bets(&deps.querier, worker.clone())?;
+    let worker_tickets = ticket_manager.query_worker_tickets(&deps.querier, worker.clone())?;
+
+    let pending_submissions = worker_tickets
+        .assignments
+        .iter()
+        .filter(|a| a.outcome.is_none())
+        .map(|a| a.tid)
+        .collect::<Vec<u64>>();
+    let outcomes = worker_tickets
+        .assignments
+        .iter()
+        .filter_map(|a| a.outcome.clone())
+        .collect();
+
+    Ok(WorkerDashboardResponse {
+        worker,
+        staked_tickets,
+        active_bets,
+        assignments: worker_tickets.assignments,
+        pending_submissions,
+        outcomes,
+    })
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+    migrate_contract(deps, CONTRACT_NAME, CONTRACT_VERSION, &[])
 }
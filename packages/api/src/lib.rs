@@ -0,0 +1,445 @@
+// API bundle & TypeScript client of the ticket-auction contracts.
+//
+// The input is the schema generated by "cargo schema" in every contract. (`contracts/[contract]/schema/[contract].json`)
+// The output is written under `artifacts/api`:
+//   ticket-auction-api.json : schemas of all the contracts in one versioned bundle
+//   ts/[Contract].ts        : message & response types, query client & execute client of the contract
+//   ts/base.ts, ts/index.ts : interfaces of the chain client (cosmjs compatible) & the exports
+// Everything is ordered by name, so that the same schemas always give the same files.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::{json, Map, Value};
+
+pub const BUNDLE_NAME: &str = "ticket-auction";
+pub const BUNDLE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const HEADER: &str =
+    "// Generated by `cargo run -p ticket-auction-api` from the contract schemas. Do not edit.\n";
+
+// Schema of the single contract. ("write_api" output)
+pub struct ContractSchema {
+    pub name: String,
+    pub schema: Value,
+}
+
+// Generated file, with the path relative to the output directory.
+pub struct Artifact {
+    pub path: PathBuf,
+    pub content: String,
+}
+
+// Read the schemas of all the contracts under `[root]/contracts`.
+pub fn read_schemas(root: &Path) -> Result<Vec<ContractSchema>> {
+    let mut dirs = fs::read_dir(root.join("contracts"))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<std::io::Result<Vec<PathBuf>>>()?;
+    dirs.sort();
+
+    let mut schemas = vec![];
+    for dir in dirs.into_iter().filter(|d| d.is_dir()) {
+        let name = dir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| anyhow!("invalid contract dir {}", dir.display()))?
+            .to_string();
+        let path = dir.join("schema").join(format!("{}.json", name));
+        let raw = fs::read_to_string(&path).with_context(|| {
+            format!("{} (run \"cargo schema\" in the contract)", path.display())
+        })?;
+        let schema: Value = serde_json::from_str(&raw)?;
+        schemas.push(ContractSchema { name, schema });
+    }
+    Ok(schemas)
+}
+
+pub fn generate(schemas: &[ContractSchema]) -> Result<Vec<Artifact>> {
+    let mut artifacts = vec![Artifact {
+        path: PathBuf::from("ticket-auction-api.json"),
+        content: bundle(schemas)?,
+    }];
+    artifacts.push(Artifact {
+        path: PathBuf::from("ts/base.ts"),
+        content: base_ts(),
+    });
+
+    let mut index = HEADER.to_string();
+    index.push_str("export * from \"./base\";\n");
+    for contract in schemas {
+        let module = pascal_case(&contract.name);
+        index.push_str(&format!("export * as {} from \"./{}\";\n", module, module));
+        artifacts.push(Artifact {
+            path: PathBuf::from(format!("ts/{}.ts", module)),
+            content: contract_ts(contract)?,
+        });
+    }
+    artifacts.push(Artifact {
+        path: PathBuf::from("ts/index.ts"),
+        content: index,
+    });
+    Ok(artifacts)
+}
+
+// **=================================================
+// ** API bundle                              ========
+// **=================================================
+fn bundle(schemas: &[ContractSchema]) -> Result<String> {
+    let mut contracts = Map::new();
+    for contract in schemas {
+        contracts.insert(contract.name.clone(), contract.schema.clone());
+    }
+    let bundle = json!({
+        "name": BUNDLE_NAME,
+        "version": BUNDLE_VERSION,
+        "contracts": contracts,
+    });
+    Ok(serde_json::to_string_pretty(&bundle)? + "\n")
+}
+
+// **=================================================
+// ** TypeScript client                       ========
+// **=================================================
+fn base_ts() -> String {
+    let mut ts = HEADER.to_string();
+    ts.push_str(
+        r#"
+export interface Coin {
+  denom: string;
+  amount: string;
+}
+
+// "CosmWasmClient" of cosmjs satisfies this interface.
+export interface CosmWasmQuerier {
+  queryContractSmart(address: string, queryMsg: unknown): Promise<any>;
+}
+
+// "SigningCosmWasmClient" of cosmjs satisfies this interface.
+export interface CosmWasmSigner extends CosmWasmQuerier {
+  execute(
+    senderAddress: string,
+    contractAddress: string,
+    msg: unknown,
+    fee: unknown,
+    memo?: string,
+    funds?: readonly Coin[],
+  ): Promise<unknown>;
+}
+
+export type Fee = number | "auto" | object;
+"#,
+    );
+    ts
+}
+
+// Message of the single ExecuteMsg/QueryMsg variant.
+struct Variant {
+    name: String,
+    // None: unit variant(sent as the string) or the empty struct variant.
+    args: Option<String>,
+    unit: bool,
+}
+
+fn contract_ts(contract: &ContractSchema) -> Result<String> {
+    let schema = &contract.schema;
+    let mut definitions = Map::new();
+    let mut types = vec![];
+
+    for (key, title) in [
+        ("instantiate", "InstantiateMsg"),
+        ("execute", "ExecuteMsg"),
+        ("query", "QueryMsg"),
+        ("migrate", "MigrateMsg"),
+    ] {
+        let root = &schema[key];
+        if root.is_null() {
+            continue;
+        }
+        collect_definitions(&mut definitions, root)?;
+        types.push(named_type(title, &strip_root(root)));
+    }
+
+    // Responses of the queries. Objects & enums are named after the title, the rest is inlined.
+    let mut responses = Map::new();
+    if let Some(map) = schema["responses"].as_object() {
+        for (query, root) in map {
+            collect_definitions(&mut definitions, root)?;
+            let body = strip_root(root);
+            let title = root["title"].as_str().unwrap_or_default();
+            let response = if is_named(&body) && !title.is_empty() {
+                add_definition(&mut definitions, title, &body)?;
+                title.to_string()
+            } else {
+                ts_type(&body)
+            };
+            responses.insert(query.clone(), Value::String(response));
+        }
+    }
+    for (name, body) in definitions.iter() {
+        types.push(named_type(name, body));
+    }
+
+    let mut ts = HEADER.to_string();
+    ts.push_str("\nimport { Coin, CosmWasmQuerier, CosmWasmSigner, Fee } from \"./base\";\n\n");
+    ts.push_str(&format!(
+        "export const CONTRACT_NAME = \"{}\";\nexport const CONTRACT_VERSION = \"{}\";\n\n",
+        contract.name,
+        schema["contract_version"].as_str().unwrap_or_default()
+    ));
+    for t in types {
+        ts.push_str(&t);
+        ts.push('\n');
+    }
+
+    // Query client
+    ts.push_str("export class QueryClient {\n");
+    ts.push_str(
+        "  constructor(readonly client: CosmWasmQuerier, readonly contractAddress: string) {}\n",
+    );
+    for variant in variants(&schema["query"])? {
+        let response = responses
+            .get(&variant.name)
+            .and_then(|r| r.as_str())
+            .ok_or_else(|| anyhow!("{}: no response for {}", contract.name, variant.name))?;
+        ts.push_str(&format!(
+            "\n  {}({}): Promise<{}> {{\n    return this.client.queryContractSmart(this.contractAddress, {});\n  }}\n",
+            camel_case(&variant.name),
+            variant
+                .args
+                .as_ref()
+                .map(|a| format!("args: {}", a))
+                .unwrap_or_default(),
+            response,
+            message(&variant),
+        ));
+    }
+    ts.push_str("}\n\n");
+
+    // Execute client
+    ts.push_str("export class Client extends QueryClient {\n");
+    ts.push_str("  constructor(\n    readonly signer: CosmWasmSigner,\n    readonly sender: string,\n    contractAddress: string,\n  ) {\n    super(signer, contractAddress);\n  }\n");
+    for variant in variants(&schema["execute"])? {
+        let mut params = vec![];
+        if let Some(args) = &variant.args {
+            params.push(format!("args: {}", args));
+        }
+        params.push("fee: Fee = \"auto\"".to_string());
+        params.push("memo?: string".to_string());
+        params.push("funds?: readonly Coin[]".to_string());
+        ts.push_str(&format!(
+            "\n  {}({}): Promise<unknown> {{\n    return this.signer.execute(this.sender, this.contractAddress, {}, fee, memo, funds);\n  }}\n",
+            camel_case(&variant.name),
+            params.join(", "),
+            message(&variant),
+        ));
+    }
+    ts.push_str("}\n");
+    Ok(ts)
+}
+
+fn message(variant: &Variant) -> String {
+    if variant.unit {
+        format!("\"{}\"", variant.name)
+    } else if variant.args.is_some() {
+        format!("{{ {}: args }}", variant.name)
+    } else {
+        format!("{{ {}: {{}} }}", variant.name)
+    }
+}
+
+// Variants of the snake_case enum(ExecuteMsg/QueryMsg).
+fn variants(root: &Value) -> Result<Vec<Variant>> {
+    let mut variants = vec![];
+    for option in root["oneOf"].as_array().into_iter().flatten() {
+        if let Some(names) = option["enum"].as_array() {
+            for name in names.iter().filter_map(|n| n.as_str()) {
+                variants.push(Variant {
+                    name: name.to_string(),
+                    args: None,
+                    unit: true,
+                });
+            }
+            continue;
+        }
+        let (name, body) = option["properties"]
+            .as_object()
+            .and_then(|p| p.iter().next())
+            .ok_or_else(|| anyhow!("unexpected variant {}", option))?;
+        let empty =
+            body["type"] == "object" && body["properties"].as_object().is_none_or(|p| p.is_empty());
+        variants.push(Variant {
+            name: name.clone(),
+            args: if empty { None } else { Some(ts_type(body)) },
+            unit: false,
+        });
+    }
+    Ok(variants)
+}
+
+fn collect_definitions(definitions: &mut Map<String, Value>, root: &Value) -> Result<()> {
+    for (name, body) in root["definitions"].as_object().into_iter().flatten() {
+        add_definition(definitions, name, body)?;
+    }
+    Ok(())
+}
+
+// The same name always stands for the same type within the contract.
+fn add_definition(definitions: &mut Map<String, Value>, name: &str, body: &Value) -> Result<()> {
+    match definitions.get(name) {
+        Some(existing) if existing != body => bail!("conflicting definitions of {}", name),
+        Some(_) => Ok(()),
+        None => {
+            definitions.insert(name.to_string(), body.clone());
+            Ok(())
+        }
+    }
+}
+
+fn strip_root(root: &Value) -> Value {
+    let mut body = root.clone();
+    if let Some(map) = body.as_object_mut() {
+        for key in ["$schema", "title", "definitions"] {
+            map.remove(key);
+        }
+    }
+    body
+}
+
+fn is_named(body: &Value) -> bool {
+    body["type"] == "object" || body.get("oneOf").is_some() || body.get("enum").is_some()
+}
+
+fn named_type(name: &str, body: &Value) -> String {
+    if body["type"] == "object" && body.get("properties").is_some() {
+        let mut ts = format!("export interface {} {{\n", name);
+        for field in fields(body) {
+            ts.push_str(&format!("  {};\n", field));
+        }
+        ts.push_str("}\n");
+        ts
+    } else if let Some(options) = body["oneOf"].as_array() {
+        let mut ts = format!("export type {} =\n", name);
+        for option in options {
+            ts.push_str(&format!("  | {}\n", ts_type(option)));
+        }
+        ts.insert(ts.len() - 1, ';');
+        ts
+    } else {
+        format!("export type {} = {};\n", name, ts_type(body))
+    }
+}
+
+fn fields(body: &Value) -> Vec<String> {
+    let required = body["required"]
+        .as_array()
+        .map(|r| r.iter().filter_map(|v| v.as_str()).collect::<Vec<&str>>())
+        .unwrap_or_default();
+    body["properties"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(name, field)| {
+            let optional = if required.contains(&name.as_str()) {
+                ""
+            } else {
+                "?"
+            };
+            format!("{}{}: {}", name, optional, ts_type(field))
+        })
+        .collect()
+}
+
+fn ts_type(schema: &Value) -> String {
+    if let Some(reference) = schema["$ref"].as_str() {
+        return reference
+            .rsplit('/')
+            .next()
+            .unwrap_or(reference)
+            .to_string();
+    }
+    for key in ["allOf", "anyOf", "oneOf"] {
+        if let Some(options) = schema[key].as_array() {
+            return union(options.iter().map(ts_type).collect());
+        }
+    }
+    if let Some(values) = schema["enum"].as_array() {
+        return union(values.iter().map(|v| v.to_string()).collect());
+    }
+    if let Some(types) = schema["type"].as_array() {
+        return union(
+            types
+                .iter()
+                .map(|t| {
+                    let mut single = schema.clone();
+                    single["type"] = t.clone();
+                    ts_type(&single)
+                })
+                .collect(),
+        );
+    }
+    match schema["type"].as_str() {
+        Some("string") => "string".to_string(),
+        Some("integer") | Some("number") => "number".to_string(),
+        Some("boolean") => "boolean".to_string(),
+        Some("null") => "null".to_string(),
+        Some("array") => match &schema["items"] {
+            Value::Array(items) => format!(
+                "[{}]",
+                items
+                    .iter()
+                    .map(ts_type)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Value::Null => "unknown[]".to_string(),
+            items => {
+                let item = ts_type(items);
+                if item.contains(' ') {
+                    format!("({})[]", item)
+                } else {
+                    format!("{}[]", item)
+                }
+            }
+        },
+        Some("object") => {
+            let fields = fields(schema);
+            if !fields.is_empty() {
+                format!("{{ {} }}", fields.join("; "))
+            } else if schema["additionalProperties"].is_object() {
+                format!(
+                    "Record<string, {}>",
+                    ts_type(&schema["additionalProperties"])
+                )
+            } else {
+                "Record<string, never>".to_string()
+            }
+        }
+        _ => "unknown".to_string(),
+    }
+}
+
+fn union(mut options: Vec<String>) -> String {
+    options.dedup();
+    options.join(" | ")
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split(['-', '_'])
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
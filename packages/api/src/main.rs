@@ -0,0 +1,44 @@
+// Regenerate the API bundle & the TypeScript client under `artifacts/api`.
+//
+//   cargo run -p ticket-auction-api            : write the files
+//   cargo run -p ticket-auction-api -- --check : fail if the files are not up to date
+use std::fs;
+use std::path::PathBuf;
+use std::process::exit;
+
+use anyhow::Result;
+use ticket_auction_api::{generate, read_schemas};
+
+fn main() -> Result<()> {
+    let check = std::env::args().any(|arg| arg == "--check");
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()?;
+    let out_dir = root.join("artifacts").join("api");
+
+    let artifacts = generate(&read_schemas(&root)?)?;
+    let mut stale = vec![];
+    for artifact in artifacts.iter() {
+        let path = out_dir.join(&artifact.path);
+        let current = fs::read_to_string(&path).unwrap_or_default();
+        if current == artifact.content {
+            continue;
+        }
+        if check {
+            stale.push(artifact.path.display().to_string());
+        } else {
+            fs::create_dir_all(path.parent().unwrap_or(&out_dir))?;
+            fs::write(&path, &artifact.content)?;
+            println!("updated {}", path.display());
+        }
+    }
+
+    if !stale.is_empty() {
+        eprintln!(
+            "out of date: {} (run \"cargo run -p ticket-auction-api\")",
+            stale.join(", ")
+        );
+        exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use std::fs;
+use std::path::PathBuf;
+
+use ticket_auction_api::{generate, read_schemas, BUNDLE_VERSION};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+#[test]
+fn artifacts_are_up_to_date() {
+    let artifacts = generate(&read_schemas(&root()).unwrap()).unwrap();
+    for artifact in artifacts {
+        let path = root().join("artifacts/api").join(&artifact.path);
+        let current = fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            current == artifact.content,
+            "{} is out of date, run \"cargo run -p ticket-auction-api\"",
+            artifact.path.display()
+        );
+    }
+}
+
+#[test]
+fn bundle_covers_every_contract() {
+    let schemas = read_schemas(&root()).unwrap();
+    let names = schemas
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(
+        names,
+        vec![
+            "admin-board",
+            "auction-manager",
+            "collateral-manager",
+            "ticket-manager",
+            "user-board"
+        ]
+    );
+
+    let artifacts = generate(&schemas).unwrap();
+    let bundle: serde_json::Value = serde_json::from_str(&artifacts[0].content).unwrap();
+    assert_eq!(bundle["version"], BUNDLE_VERSION);
+    assert_eq!(
+        bundle["contracts"]["ticket-manager"]["contract_version"],
+        "0.2.0"
+    );
+
+    // Same schemas, same files.
+    let again = generate(&schemas).unwrap();
+    for (a, b) in artifacts.iter().zip(again.iter()) {
+        assert_eq!(a.content, b.content);
+    }
+}
@@ -0,0 +1,57 @@
+// Deployment state of the suite, kept in the local JSON file. (default: "ta-cli.json")
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::plan::Target;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct Config {
+    pub chain_id: Option<String>,
+    // Admin wallet, the sender of the admin-board messages.
+    pub admin: Option<String>,
+    pub admin_board: Option<String>,
+    pub ticket_manager: Option<String>,
+    pub user_board: Option<String>,
+    pub auction_manager: Option<String>,
+    pub collateral_manager: Option<String>,
+}
+
+impl Config {
+    // Missing file is the empty config.
+    pub fn load(path: &Path) -> Result<Config> {
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+        let raw = fs::read_to_string(path).with_context(|| path.display().to_string())?;
+        serde_json::from_str(&raw).with_context(|| path.display().to_string())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| path.display().to_string())
+    }
+
+    pub fn admin(&self) -> Result<String> {
+        self.admin
+            .clone()
+            .ok_or_else(|| anyhow!("\"admin\" is not set (ta-cli config set --admin ...)"))
+    }
+
+    pub fn address(&self, target: Target) -> Result<String> {
+        let address = match target {
+            Target::AdminBoard => &self.admin_board,
+            Target::UserBoard => &self.user_board,
+            Target::AuctionManager => &self.auction_manager,
+        };
+        address.clone().ok_or_else(|| {
+            anyhow!(
+                "\"{}\" is not set (ta-cli config set --{} ...)",
+                target.name(),
+                target.name().replace('_', "-")
+            )
+        })
+    }
+}
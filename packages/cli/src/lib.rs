@@ -0,0 +1,9 @@
+// Operator CLI of the ticket-auction suite. (binary "ta-cli")
+//
+// Every command builds the admin-board/user-board execute message and prints it as
+// MsgExecuteContract JSON with the addresses of the config file, ready to be signed.
+// With "--plan [file]" the message is recorded in the plan instead, which "simulate"
+// runs against the suite deployed on the simulated chain.
+pub mod config;
+pub mod plan;
+pub mod simulate;
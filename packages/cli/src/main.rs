@@ -0,0 +1,913 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use cosmwasm_std::{coins, Binary, Decimal, Uint128};
+use serde::de::DeserializeOwned;
+use ticket_auction::{
+    admin_board::{ChildKind, ExecuteMsg as AdminExecuteMsg, PostConfigMsg},
+    collateral_manager::DisputeParams,
+    matcher::ResultMatcher,
+    ticket_manager::{
+        AddTicketMsg, AssessmentMode, AuctionParams, GradeSubmissionMsg, LatenessPenalty,
+        LatenessTier, TicketLimits, TicketMetadata, TicketResultMsg, UpdateTicketMsg,
+    },
+    user_board::{ExecuteMsg as UserExecuteMsg, PlaceBetMsg},
+};
+use ticket_auction_cli::{
+    config::Config,
+    plan::{Plan, Step},
+    simulate::{default_plan, simulate, DEFAULT_FUNDS},
+};
+use ticket_auction_multitest::DENOM;
+
+#[derive(Parser)]
+#[command(
+    name = "ta-cli",
+    version,
+    about = "Operate the ticket-auction contracts"
+)]
+struct Cli {
+    /// Deployment state. (addresses of the admin wallet & the contracts)
+    #[arg(long, global = true, default_value = "ta-cli.json")]
+    config: PathBuf,
+    /// Record the message in the plan file instead of printing it.
+    #[arg(long, global = true)]
+    plan: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Show or update the deployment state.
+    #[command(subcommand)]
+    Config(ConfigCmd),
+    /// Messages of the admin wallet to admin-board.
+    #[command(subcommand)]
+    Admin(Box<AdminCmd>),
+    /// Messages of the worker to user-board.
+    User {
+        /// Worker address (account name in the plan).
+        #[arg(long)]
+        sender: String,
+        #[command(subcommand)]
+        cmd: UserCmd,
+    },
+    /// Messages of the grader to admin-board.
+    Grader {
+        /// Grader address (account name in the plan).
+        #[arg(long)]
+        sender: String,
+        #[command(subcommand)]
+        cmd: GraderCmd,
+    },
+    /// Messages of the arbiter to admin-board.
+    Arbiter {
+        /// Arbiter address (account name in the plan).
+        #[arg(long)]
+        sender: String,
+        #[command(subcommand)]
+        cmd: ArbiterCmd,
+    },
+    /// Advance the block time of the plan.
+    Advance { seconds: u64 },
+    /// Run the plan("--plan") or the whole default lifecycle on the simulated chain.
+    Simulate {
+        /// uluna given to every account of the plan.
+        #[arg(long, default_value_t = DEFAULT_FUNDS)]
+        funds: u128,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCmd {
+    /// Print the config.
+    Show,
+    /// Set the given fields of the config.
+    Set(ConfigArgs),
+}
+
+#[derive(Args)]
+struct ConfigArgs {
+    #[arg(long)]
+    chain_id: Option<String>,
+    #[arg(long)]
+    admin: Option<String>,
+    #[arg(long)]
+    admin_board: Option<String>,
+    #[arg(long)]
+    ticket_manager: Option<String>,
+    #[arg(long)]
+    user_board: Option<String>,
+    #[arg(long)]
+    auction_manager: Option<String>,
+    #[arg(long)]
+    collateral_manager: Option<String>,
+}
+
+/// Timestamp in seconds, "+N" is N seconds from now. (from the plan's block time with "--plan")
+#[derive(Clone, Copy)]
+enum Time {
+    At(u64),
+    After(u64),
+}
+
+impl Time {
+    fn resolve(self, now: u64) -> u64 {
+        match self {
+            Time::At(t) => t,
+            Time::After(seconds) => now + seconds,
+        }
+    }
+}
+
+fn parse_time(s: &str) -> Result<Time, String> {
+    match s.strip_prefix('+') {
+        Some(rel) => rel.parse().map(Time::After),
+        None => s.parse().map(Time::At),
+    }
+    .map_err(|e| format!("{} (seconds or +seconds)", e))
+}
+
+// "AFTER:SLASH", eg. "3600:200" for 20% once an hour late.
+fn parse_tier(s: &str) -> Result<LatenessTier, String> {
+    let (after, slash_perc) = s
+        .split_once(':')
+        .ok_or_else(|| "expected AFTER:SLASH".to_string())?;
+    Ok(LatenessTier {
+        after: after.parse().map_err(|e| format!("{} (seconds)", e))?,
+        slash_perc: slash_perc
+            .parse()
+            .map_err(|e| format!("{} (per mille)", e))?,
+    })
+}
+
+#[derive(Args)]
+struct AuctionArgs {
+    #[arg(long)]
+    min_reputation: Option<u64>,
+    #[arg(long)]
+    reserve_price: Option<u64>,
+    #[arg(long)]
+    min_bid: Option<u64>,
+    #[arg(long)]
+    min_decrement: Option<u64>,
+}
+
+impl AuctionArgs {
+    fn params(&self) -> AuctionParams {
+        AuctionParams {
+            min_reputation: self.min_reputation,
+            scoring: None,
+            reserve_price: self.reserve_price,
+            min_bid: self.min_bid,
+            min_decrement: self.min_decrement,
+            soft_close: None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.params() == AuctionParams::default()
+    }
+}
+
+#[derive(Args)]
+struct MetadataArgs {
+    #[arg(long)]
+    title: Option<String>,
+    #[arg(long)]
+    description: Option<String>,
+    #[arg(long)]
+    category: Option<String>,
+    /// Repeat for every tag.
+    #[arg(long = "tag")]
+    tags: Vec<String>,
+    #[arg(long)]
+    content_uri: Option<String>,
+    /// sha256 of the content in lowercase hex.
+    #[arg(long)]
+    content_hash: Option<String>,
+}
+
+impl MetadataArgs {
+    fn metadata(&self) -> TicketMetadata {
+        TicketMetadata {
+            title: self.title.clone().unwrap_or_default(),
+            description: self.description.clone().unwrap_or_default(),
+            category: self.category.clone(),
+            tags: self.tags.clone(),
+            content_uri: self.content_uri.clone(),
+            content_hash: self.content_hash.clone(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.metadata() == TicketMetadata::default()
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Child {
+    TicketManager,
+    UserBoard,
+    AuctionManager,
+    CollateralManager,
+}
+
+impl From<Child> for ChildKind {
+    fn from(child: Child) -> ChildKind {
+        match child {
+            Child::TicketManager => ChildKind::TicketManager,
+            Child::UserBoard => ChildKind::UserBoard,
+            Child::AuctionManager => ChildKind::AuctionManager,
+            Child::CollateralManager => ChildKind::CollateralManager,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Assessment {
+    ExpectedResult,
+    Consensus,
+    Manual,
+    PartialCredit,
+}
+
+impl Assessment {
+    fn mode(self, review_period: u64) -> AssessmentMode {
+        match self {
+            Assessment::ExpectedResult => AssessmentMode::ExpectedResult,
+            Assessment::Consensus => AssessmentMode::Consensus,
+            Assessment::Manual => AssessmentMode::Manual { review_period },
+            Assessment::PartialCredit => AssessmentMode::PartialCredit,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Matcher {
+    Exact,
+    CaseInsensitive,
+    Trimmed,
+    /// Decimal numbers within "--tolerance".
+    Numeric,
+    /// The result or any "--alternative".
+    OneOf,
+    /// "*" & "?" wildcards in the result.
+    Pattern,
+    /// The result is sha256 of the normalized answer.
+    NormalizedHash,
+}
+
+#[derive(Args)]
+struct MatcherArgs {
+    /// How the submission is compared with the result.
+    #[arg(long, value_enum)]
+    matcher: Option<Matcher>,
+    /// Max difference of the "numeric" matcher.
+    #[arg(long, default_value = "0")]
+    tolerance: Decimal,
+    /// Repeat for every accepted alternative of the "one-of" matcher.
+    #[arg(long = "alternative")]
+    alternatives: Vec<String>,
+}
+
+impl MatcherArgs {
+    fn strategy(&self) -> Option<ResultMatcher> {
+        let matcher = match self.matcher? {
+            Matcher::Exact => ResultMatcher::Exact,
+            Matcher::CaseInsensitive => ResultMatcher::CaseInsensitive,
+            Matcher::Trimmed => ResultMatcher::Trimmed,
+            Matcher::Numeric => ResultMatcher::Numeric {
+                tolerance: self.tolerance,
+            },
+            Matcher::OneOf => ResultMatcher::OneOf {
+                alternatives: self.alternatives.clone(),
+            },
+            Matcher::Pattern => ResultMatcher::Pattern,
+            Matcher::NormalizedHash => ResultMatcher::NormalizedHash,
+        };
+        Some(matcher)
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Lateness {
+    /// 30% once closed.
+    Flat,
+    /// "--per-hour" for every started hour, up to "--max-penalty".
+    Linear,
+    /// The last "--tier" passed.
+    Stepped,
+}
+
+#[derive(Args)]
+struct LatenessArgs {
+    /// Slash for the result submitted after the close.
+    #[arg(long, value_enum)]
+    lateness: Option<Lateness>,
+    /// Per mille. ("linear" lateness)
+    #[arg(long, default_value_t = 0)]
+    per_hour: u64,
+    /// Per mille. ("linear" lateness)
+    #[arg(long, default_value_t = 1000)]
+    max_penalty: u64,
+    /// Repeat for every tier as "AFTER:SLASH", seconds after the close & per mille.
+    /// ("stepped" lateness)
+    #[arg(long = "tier", value_parser = parse_tier)]
+    tiers: Vec<LatenessTier>,
+    /// Seconds after the close until the result is accepted. (1 day by default)
+    #[arg(long)]
+    cutoff: Option<u64>,
+}
+
+impl LatenessArgs {
+    fn penalty(&self) -> Option<LatenessPenalty> {
+        let penalty = match self.lateness? {
+            Lateness::Flat => LatenessPenalty::Flat {
+                cutoff: self.cutoff,
+            },
+            Lateness::Linear => LatenessPenalty::Linear {
+                per_hour: self.per_hour,
+                max: self.max_penalty,
+                cutoff: self.cutoff,
+            },
+            Lateness::Stepped => LatenessPenalty::Stepped {
+                tiers: self.tiers.clone(),
+                cutoff: self.cutoff,
+            },
+        };
+        Some(penalty)
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Ruling {
+    /// Keep the slash & the dispute bond.
+    Uphold,
+    /// Return the slash & the dispute bond to the worker.
+    Reverse,
+}
+
+#[derive(Subcommand)]
+enum AdminCmd {
+    /// Add the ticket. ("+N": N seconds from now)
+    AddTicket {
+        #[arg(long)]
+        id: u64,
+        #[arg(long, value_parser = parse_time)]
+        bet_finish: Time,
+        #[arg(long, value_parser = parse_time)]
+        close: Time,
+        #[arg(long)]
+        result: String,
+        #[arg(long)]
+        collateral: u64,
+        /// Number of the workers to assign. (the lowest bidders)
+        #[arg(long, default_value_t = 1)]
+        slots: u32,
+        #[arg(long, value_enum, default_value = "expected-result")]
+        assessment: Assessment,
+        /// Seconds to grade the submission before the auto-approval. ("manual" assessment)
+        #[arg(long, default_value_t = 86_400)]
+        review_period: u64,
+        /// Allow replacing the submission until the close. (the last one is assessed)
+        #[arg(long)]
+        resubmission: bool,
+        #[command(flatten)]
+        matcher: MatcherArgs,
+        #[command(flatten)]
+        lateness: LatenessArgs,
+        #[command(flatten)]
+        auction: AuctionArgs,
+        #[command(flatten)]
+        metadata: MetadataArgs,
+    },
+    /// Update the given fields of the ticket. (the metadata is replaced as a whole)
+    UpdateTicket {
+        #[arg(long)]
+        id: u64,
+        #[arg(long, value_parser = parse_time)]
+        bet_finish: Option<Time>,
+        #[arg(long, value_parser = parse_time)]
+        close: Option<Time>,
+        #[arg(long)]
+        result: Option<String>,
+        #[arg(long)]
+        collateral: Option<u64>,
+        #[arg(long)]
+        slots: Option<u32>,
+        #[arg(long, value_enum)]
+        assessment: Option<Assessment>,
+        #[arg(long, default_value_t = 86_400)]
+        review_period: u64,
+        #[arg(long)]
+        resubmission: Option<bool>,
+        #[command(flatten)]
+        matcher: MatcherArgs,
+        #[command(flatten)]
+        lateness: LatenessArgs,
+        #[command(flatten)]
+        auction: AuctionArgs,
+        #[command(flatten)]
+        metadata: MetadataArgs,
+    },
+    /// Remove the ticket.
+    CancelTicket {
+        #[arg(long)]
+        tid: u64,
+    },
+    /// Decide the winning bet after the bet finishes.
+    DecideWinner {
+        #[arg(long)]
+        tid: u64,
+    },
+    /// Add the tickets of the JSON file. (array of "add_ticket" messages, all or nothing)
+    AddTickets {
+        #[arg(long)]
+        file: PathBuf,
+    },
+    /// Update the tickets with the JSON file. (array of "update_ticket" messages, all or nothing)
+    UpdateTickets {
+        #[arg(long)]
+        file: PathBuf,
+    },
+    /// Remove the tickets. (eg. "--tids 1,2,3", all or nothing)
+    CancelTickets {
+        #[arg(long, value_delimiter = ',', required = true)]
+        tids: Vec<u64>,
+    },
+    /// Decide the winning bets of the tickets. (eg. "--tids 1,2,3", all or nothing)
+    DecideWinners {
+        #[arg(long, value_delimiter = ',', required = true)]
+        tids: Vec<u64>,
+    },
+    /// Slash the winner who did not submit before the ticket closed.
+    ReportNoShow {
+        #[arg(long)]
+        tid: u64,
+    },
+    /// Reward(uluna) paid to the keeper for every settled ticket, out of the bet fees.
+    SetKeeperReward {
+        #[arg(long)]
+        amount: u128,
+        /// uluna, sent with every bet.
+        #[arg(long, default_value_t = 0)]
+        bet_fee: u128,
+    },
+    /// Bounds of the new & updated tickets. (durations in seconds, collateral in uluna)
+    SetTicketLimits {
+        #[arg(long, default_value_t = 0)]
+        min_bet_duration: u64,
+        #[arg(long)]
+        max_bet_duration: Option<u64>,
+        #[arg(long, default_value_t = 0)]
+        min_work_duration: u64,
+        #[arg(long)]
+        max_work_duration: Option<u64>,
+        #[arg(long, default_value_t = 1)]
+        min_collateral: u64,
+        #[arg(long)]
+        max_collateral: Option<u64>,
+    },
+    /// Allow the address to grade the submissions of the "manual" tickets.
+    AddGrader {
+        #[arg(long)]
+        address: String,
+    },
+    /// Revoke the grader role.
+    RemoveGrader {
+        #[arg(long)]
+        address: String,
+    },
+    /// Allow the address to resolve the disputes of the slashes.
+    AddArbiter {
+        #[arg(long)]
+        address: String,
+    },
+    /// Revoke the arbiter role.
+    RemoveArbiter {
+        #[arg(long)]
+        address: String,
+    },
+    /// Hold the slashes for the dispute window. (0 seconds finalizes them right away)
+    SetDisputeParams {
+        /// Seconds.
+        #[arg(long)]
+        window: u64,
+        /// uluna sent by the worker to dispute.
+        #[arg(long)]
+        bond: u128,
+    },
+    /// Register the contract addresses of the config in admin-board.
+    PostConfig,
+    /// Create the child contract from the uploaded code.
+    Create {
+        #[arg(value_enum)]
+        contract: Child,
+        #[arg(long)]
+        code_id: u64,
+    },
+    /// Migrate the child contract to the new code. (empty MigrateMsg)
+    Migrate {
+        #[arg(value_enum)]
+        contract: Child,
+        #[arg(long)]
+        code_id: u64,
+    },
+}
+
+#[derive(Subcommand)]
+enum UserCmd {
+    /// Lock the collateral of the ticket.
+    LockStake {
+        #[arg(long)]
+        tid: u64,
+        /// uluna, the collateral of the ticket.
+        #[arg(long)]
+        amount: u128,
+    },
+    /// Bet on the ticket. (the lowest bet wins by default)
+    PlaceBet {
+        #[arg(long)]
+        tid: u64,
+        #[arg(long)]
+        amount: u64,
+        /// uluna, the bet fee set by the admin.
+        #[arg(long, default_value_t = 0)]
+        fee: u128,
+    },
+    /// Submit the result of the won ticket.
+    SubmitResult {
+        #[arg(long)]
+        tid: u64,
+        #[arg(long)]
+        result: String,
+    },
+    /// Dispute the slash held in the dispute window.
+    DisputeAssessment {
+        #[arg(long)]
+        tid: u64,
+        #[arg(long)]
+        evidence_uri: String,
+        /// uluna, the dispute bond.
+        #[arg(long)]
+        bond: u128,
+    },
+}
+
+#[derive(Subcommand)]
+enum GraderCmd {
+    /// Grade the submission of the "manual" ticket. (score in per mille)
+    Grade {
+        #[arg(long)]
+        tid: u64,
+        /// Required when several submissions of the ticket wait for the grade.
+        #[arg(long)]
+        worker: Option<String>,
+        #[arg(long)]
+        score: u64,
+        #[arg(long, default_value = "")]
+        notes: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ArbiterCmd {
+    /// Resolve the dispute of the worker's slash.
+    Resolve {
+        #[arg(long)]
+        tid: u64,
+        #[arg(long)]
+        worker: String,
+        #[arg(value_enum)]
+        ruling: Ruling,
+    },
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let raw = fs::read_to_string(path).with_context(|| path.display().to_string())?;
+    serde_json::from_str(&raw).with_context(|| path.display().to_string())
+}
+
+fn admin_msg(cmd: AdminCmd, config: &Config, now: u64) -> Result<AdminExecuteMsg> {
+    let msg = match cmd {
+        AdminCmd::AddTicket {
+            id,
+            bet_finish,
+            close,
+            result,
+            collateral,
+            slots,
+            assessment,
+            review_period,
+            resubmission,
+            matcher,
+            lateness,
+            auction,
+            metadata,
+        } => AdminExecuteMsg::AddTicket(AddTicketMsg {
+            id,
+            bet_finish_timestamp: bet_finish.resolve(now),
+            close_timestamp: close.resolve(now),
+            result,
+            collateral,
+            auction: auction.params(),
+            metadata: metadata.metadata(),
+            creator: None,
+            slots,
+            assessment: assessment.mode(review_period),
+            resubmission,
+            matcher: matcher.strategy().unwrap_or_default(),
+            lateness: lateness.penalty().unwrap_or_default(),
+        }),
+        AdminCmd::UpdateTicket {
+            id,
+            bet_finish,
+            close,
+            result,
+            collateral,
+            slots,
+            assessment,
+            review_period,
+            resubmission,
+            matcher,
+            lateness,
+            auction,
+            metadata,
+        } => AdminExecuteMsg::UpdateTicket(UpdateTicketMsg {
+            id,
+            bet_finish_timestamp: bet_finish.map(|t| t.resolve(now)),
+            close_timestamp: close.map(|t| t.resolve(now)),
+            result,
+            collateral,
+            auction: if auction.is_empty() {
+                None
+            } else {
+                Some(auction.params())
+            },
+            metadata: if metadata.is_empty() {
+                None
+            } else {
+                Some(metadata.metadata())
+            },
+            slots,
+            assessment: assessment.map(|a| a.mode(review_period)),
+            resubmission,
+            matcher: matcher.strategy(),
+            lateness: lateness.penalty(),
+        }),
+        AdminCmd::CancelTicket { tid } => AdminExecuteMsg::RemoveTicket { tid },
+        AdminCmd::DecideWinner { tid } => AdminExecuteMsg::DecideWinningBet { tid },
+        AdminCmd::AddTickets { file } => AdminExecuteMsg::AddTickets(read_json(&file)?),
+        AdminCmd::UpdateTickets { file } => AdminExecuteMsg::UpdateTickets(read_json(&file)?),
+        AdminCmd::CancelTickets { tids } => AdminExecuteMsg::CancelTickets { tids },
+        AdminCmd::DecideWinners { tids } => AdminExecuteMsg::DecideWinningBets { tids },
+        AdminCmd::ReportNoShow { tid } => AdminExecuteMsg::ReportNoShow { tid },
+        AdminCmd::SetKeeperReward { amount, bet_fee } => AdminExecuteMsg::SetKeeperReward {
+            amount: Uint128::from(amount),
+            bet_fee: Uint128::from(bet_fee),
+        },
+        AdminCmd::SetTicketLimits {
+            min_bet_duration,
+            max_bet_duration,
+            min_work_duration,
+            max_work_duration,
+            min_collateral,
+            max_collateral,
+        } => AdminExecuteMsg::SetTicketLimits(TicketLimits {
+            min_bet_duration,
+            max_bet_duration,
+            min_work_duration,
+            max_work_duration,
+            min_collateral,
+            max_collateral,
+        }),
+        AdminCmd::AddGrader { address } => AdminExecuteMsg::AddGrader { address },
+        AdminCmd::RemoveGrader { address } => AdminExecuteMsg::RemoveGrader { address },
+        AdminCmd::AddArbiter { address } => AdminExecuteMsg::AddArbiter { address },
+        AdminCmd::RemoveArbiter { address } => AdminExecuteMsg::RemoveArbiter { address },
+        AdminCmd::SetDisputeParams { window, bond } => {
+            AdminExecuteMsg::SetDisputeParams(DisputeParams {
+                window,
+                bond: Uint128::from(bond),
+            })
+        }
+        AdminCmd::PostConfig => AdminExecuteMsg::PostConfig(PostConfigMsg {
+            ticket_manager: config.ticket_manager.clone(),
+            collateral_manager: config.collateral_manager.clone(),
+            auction_manager: config.auction_manager.clone(),
+            user_board: config.user_board.clone(),
+        }),
+        AdminCmd::Create { contract, code_id } => match contract {
+            Child::TicketManager => AdminExecuteMsg::CreateTicketManager { code_id },
+            Child::UserBoard => AdminExecuteMsg::CreateUsrBoardManager { code_id },
+            Child::AuctionManager => AdminExecuteMsg::CreateAuctionManager { code_id },
+            Child::CollateralManager => AdminExecuteMsg::CreateCollateralManager { code_id },
+        },
+        AdminCmd::Migrate { contract, code_id } => AdminExecuteMsg::MigrateChild {
+            contract: contract.into(),
+            new_code_id: code_id,
+            msg: Binary::from(b"{}"),
+        },
+    };
+    Ok(msg)
+}
+
+fn user_step(sender: &str, cmd: UserCmd) -> Result<Step> {
+    match cmd {
+        UserCmd::LockStake { tid, amount } => Step::user(
+            sender,
+            &UserExecuteMsg::LockStake { tid },
+            coins(amount, DENOM),
+        ),
+        UserCmd::PlaceBet { tid, amount, fee } => Step::user(
+            sender,
+            &UserExecuteMsg::PlaceBet(PlaceBetMsg {
+                ticket_id: tid,
+                bet_amount: amount,
+            }),
+            match fee {
+                0 => vec![],
+                fee => coins(fee, DENOM),
+            },
+        ),
+        UserCmd::SubmitResult { tid, result } => Step::user(
+            sender,
+            &UserExecuteMsg::SubmitResult(TicketResultMsg {
+                tid,
+                worker: sender.to_string(),
+                result,
+            }),
+            vec![],
+        ),
+        UserCmd::DisputeAssessment {
+            tid,
+            evidence_uri,
+            bond,
+        } => Step::user(
+            sender,
+            &UserExecuteMsg::DisputeAssessment { tid, evidence_uri },
+            coins(bond, DENOM),
+        ),
+    }
+}
+
+fn grader_step(sender: &str, cmd: GraderCmd) -> Result<Step> {
+    match cmd {
+        GraderCmd::Grade {
+            tid,
+            worker,
+            score,
+            notes,
+        } => Step::member(
+            sender,
+            &AdminExecuteMsg::GradeSubmission(GradeSubmissionMsg {
+                tid,
+                worker,
+                score,
+                notes,
+                grader: None,
+            }),
+        ),
+    }
+}
+
+fn arbiter_step(sender: &str, cmd: ArbiterCmd) -> Result<Step> {
+    match cmd {
+        ArbiterCmd::Resolve {
+            tid,
+            worker,
+            ruling,
+        } => Step::member(
+            sender,
+            &AdminExecuteMsg::ResolveDispute {
+                tid,
+                worker,
+                uphold: matches!(ruling, Ruling::Uphold),
+            },
+        ),
+    }
+}
+
+fn wall_clock() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let mut config = Config::load(&cli.config)?;
+    let mut plan = match &cli.plan {
+        Some(path) => Some(Plan::load_or_new(path, wall_clock())?),
+        None => None,
+    };
+    let now = plan.as_ref().map(|p| p.now()).unwrap_or_else(wall_clock);
+
+    let step = match cli.command {
+        Command::Config(ConfigCmd::Show) => {
+            println!("{}", serde_json::to_string_pretty(&config)?);
+            return Ok(());
+        }
+        Command::Config(ConfigCmd::Set(args)) => {
+            let ConfigArgs {
+                chain_id,
+                admin,
+                admin_board,
+                ticket_manager,
+                user_board,
+                auction_manager,
+                collateral_manager,
+            } = args;
+            for (field, value) in [
+                (&mut config.chain_id, chain_id),
+                (&mut config.admin, admin),
+                (&mut config.admin_board, admin_board),
+                (&mut config.ticket_manager, ticket_manager),
+                (&mut config.user_board, user_board),
+                (&mut config.auction_manager, auction_manager),
+                (&mut config.collateral_manager, collateral_manager),
+            ] {
+                if value.is_some() {
+                    *field = value;
+                }
+            }
+            config.save(&cli.config)?;
+            println!("{}", serde_json::to_string_pretty(&config)?);
+            return Ok(());
+        }
+        Command::Admin(cmd) => Step::admin(&admin_msg(*cmd, &config, now)?)?,
+        Command::User { sender, cmd } => user_step(&sender, cmd)?,
+        Command::Grader { sender, cmd } => grader_step(&sender, cmd)?,
+        Command::Arbiter { sender, cmd } => arbiter_step(&sender, cmd)?,
+        Command::Advance { seconds } => Step::AdvanceTime(seconds),
+        Command::Simulate { funds } => {
+            let plan = match plan {
+                Some(plan) => plan,
+                None => default_plan(wall_clock())?,
+            };
+            return print_simulation(&plan, funds);
+        }
+    };
+
+    match (&cli.plan, plan.as_mut()) {
+        (Some(path), Some(plan)) => {
+            println!("{}: {}", path.display(), step.describe());
+            plan.steps.push(step);
+            plan.save(path)
+        }
+        _ => {
+            if let Step::AdvanceTime(_) = step {
+                bail!("\"advance\" is only for the plan (--plan [file])");
+            }
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&step.envelope(&config)?)?
+            );
+            Ok(())
+        }
+    }
+}
+
+fn print_simulation(plan: &Plan, funds: u128) -> Result<()> {
+    let report = simulate(plan, funds)?;
+    for (i, step) in report.steps.iter().enumerate() {
+        match &step.result {
+            Ok(methods) if methods.is_empty() => println!("#{} {}: ok", i + 1, step.description),
+            Ok(methods) => println!(
+                "#{} {}: ok ({})",
+                i + 1,
+                step.description,
+                methods.join(", ")
+            ),
+            Err(e) => println!("#{} {}: FAILED {}", i + 1, step.description, e),
+        }
+    }
+    for (tid, worker) in report.assignments.iter() {
+        println!("ticket {}: assigned to {}", tid, worker);
+    }
+    for (account, balance) in report.balances.iter() {
+        println!("{}: {} {}", account, balance, DENOM);
+    }
+    println!(
+        "collateral: {} locked, {} slashed",
+        report.locked, report.slashed
+    );
+
+    if !report.is_ok() {
+        exit(1);
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = run(Cli::parse()) {
+        eprintln!("error: {:#}", e);
+        exit(1);
+    }
+}
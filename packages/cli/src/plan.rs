@@ -0,0 +1,181 @@
+// Messages built by the CLI & the plans replayed by "simulate".
+//
+// A plan is the list of the steps recorded with "--plan [file]", starting at "start_time".
+// The sender "admin" stands for the admin wallet, the other senders are the account names.
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use cosmwasm_std::Coin;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use ticket_auction::{
+    admin_board::ExecuteMsg as AdminExecuteMsg, user_board::ExecuteMsg as UserExecuteMsg,
+};
+
+pub const ADMIN: &str = "admin";
+
+// Contract receiving the message.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Target {
+    AdminBoard,
+    UserBoard,
+    AuctionManager,
+}
+
+impl Target {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Target::AdminBoard => "admin_board",
+            Target::UserBoard => "user_board",
+            Target::AuctionManager => "auction_manager",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Step {
+    Execute {
+        sender: String,
+        contract: Target,
+        msg: Value,
+        #[serde(default)]
+        funds: Vec<Coin>,
+    },
+    AdvanceTime(u64),
+}
+
+impl Step {
+    // Admin wallet -> admin-board
+    pub fn admin(msg: &AdminExecuteMsg) -> Result<Step> {
+        Ok(Step::Execute {
+            sender: ADMIN.to_string(),
+            contract: Target::AdminBoard,
+            msg: serde_json::to_value(msg)?,
+            funds: vec![],
+        })
+    }
+
+    // Grader or arbiter -> admin-board
+    pub fn member(sender: &str, msg: &AdminExecuteMsg) -> Result<Step> {
+        Ok(Step::Execute {
+            sender: sender.to_string(),
+            contract: Target::AdminBoard,
+            msg: serde_json::to_value(msg)?,
+            funds: vec![],
+        })
+    }
+
+    // Worker -> user-board
+    pub fn user(sender: &str, msg: &UserExecuteMsg, funds: Vec<Coin>) -> Result<Step> {
+        Ok(Step::Execute {
+            sender: sender.to_string(),
+            contract: Target::UserBoard,
+            msg: serde_json::to_value(msg)?,
+            funds,
+        })
+    }
+
+    // MsgExecuteContract with the addresses of the config, ready to be signed.
+    pub fn envelope(&self, config: &crate::config::Config) -> Result<Value> {
+        match self {
+            Step::Execute {
+                sender,
+                contract,
+                msg,
+                funds,
+            } => {
+                let sender = if sender == ADMIN {
+                    config.admin()?
+                } else {
+                    sender.clone()
+                };
+                Ok(json!({
+                    "sender": sender,
+                    "contract": config.address(*contract)?,
+                    "msg": msg,
+                    "funds": funds,
+                }))
+            }
+            Step::AdvanceTime(_) => Ok(serde_json::to_value(self)?),
+        }
+    }
+
+    // eg. "admin -> admin_board: add_ticket"
+    pub fn describe(&self) -> String {
+        match self {
+            Step::Execute {
+                sender,
+                contract,
+                msg,
+                ..
+            } => {
+                let method = match msg {
+                    Value::String(name) => name.clone(),
+                    Value::Object(map) => map.keys().next().cloned().unwrap_or_default(),
+                    _ => String::new(),
+                };
+                format!("{} -> {}: {}", sender, contract.name(), method)
+            }
+            Step::AdvanceTime(seconds) => format!("advance time by {}s", seconds),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Plan {
+    // Block time at the first step. (seconds)
+    pub start_time: u64,
+    pub steps: Vec<Step>,
+}
+
+impl Plan {
+    pub fn new(start_time: u64) -> Plan {
+        Plan {
+            start_time,
+            steps: vec![],
+        }
+    }
+
+    // Missing file is the new plan starting at "start_time".
+    pub fn load_or_new(path: &Path, start_time: u64) -> Result<Plan> {
+        if !path.exists() {
+            return Ok(Plan::new(start_time));
+        }
+        let raw = fs::read_to_string(path).with_context(|| path.display().to_string())?;
+        serde_json::from_str(&raw).with_context(|| path.display().to_string())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| path.display().to_string())
+    }
+
+    // Block time after the steps so far.
+    pub fn now(&self) -> u64 {
+        self.start_time
+            + self
+                .steps
+                .iter()
+                .map(|s| match s {
+                    Step::AdvanceTime(seconds) => *seconds,
+                    _ => 0,
+                })
+                .sum::<u64>()
+    }
+
+    // Accounts sending the messages, except the admin.
+    pub fn accounts(&self) -> Vec<String> {
+        let mut accounts: Vec<String> = vec![];
+        for step in self.steps.iter() {
+            if let Step::Execute { sender, .. } = step {
+                if sender != ADMIN && !accounts.contains(sender) {
+                    accounts.push(sender.clone());
+                }
+            }
+        }
+        accounts
+    }
+}
@@ -0,0 +1,186 @@
+// Dry run of the plan on the simulated chain(cw-multi-test).
+//
+// The suite is deployed as in the README, the block time is set to the plan's "start_time",
+// every account of the plan gets "funds" uluna & the steps are run in order.
+// Failing steps are reported, the next steps still run.
+use anyhow::Result;
+use cosmwasm_std::{coins, Addr, Binary, CosmosMsg, Timestamp, Uint128, WasmMsg};
+use cw_multi_test::Executor;
+use ticket_auction::{
+    admin_board::ExecuteMsg as AdminExecuteMsg,
+    matcher::ResultMatcher,
+    ticket_manager::{
+        AddTicketMsg, AssessmentMode, AuctionParams, LatenessPenalty, TicketMetadata,
+        TicketResultMsg,
+    },
+    user_board::{ExecuteMsg as UserExecuteMsg, PlaceBetMsg},
+};
+use ticket_auction_multitest::{Suite, SuiteBuilder, DENOM};
+
+use crate::plan::{Plan, Step, Target, ADMIN};
+
+pub const DEFAULT_FUNDS: u128 = 1_000_000;
+
+pub struct StepReport {
+    pub description: String,
+    // "method" attributes of the contracts, or the error.
+    pub result: Result<Vec<String>, String>,
+}
+
+pub struct Report {
+    pub steps: Vec<StepReport>,
+    // (ticket id, assigned account)
+    pub assignments: Vec<(u64, String)>,
+    // (account, uluna balance)
+    pub balances: Vec<(String, u128)>,
+    pub locked: Uint128,
+    pub slashed: Uint128,
+}
+
+impl Report {
+    pub fn is_ok(&self) -> bool {
+        self.steps.iter().all(|s| s.result.is_ok())
+    }
+}
+
+pub fn simulate(plan: &Plan, funds: u128) -> Result<Report> {
+    let accounts = plan.accounts();
+    let mut builder = SuiteBuilder::new();
+    for account in accounts.iter() {
+        builder = builder.with_funds(account, funds);
+    }
+    let mut suite = builder.build();
+    suite
+        .app
+        .update_block(|block| block.time = Timestamp::from_seconds(plan.start_time));
+
+    let mut steps = vec![];
+    for step in plan.steps.iter() {
+        let result = run_step(&mut suite, step).map_err(|e| e.root_cause().to_string());
+        steps.push(StepReport {
+            description: step.describe(),
+            result,
+        });
+    }
+
+    let name_of = |addr: &str| {
+        accounts
+            .iter()
+            .find(|a| suite.addr(a).as_str() == addr)
+            .cloned()
+            .unwrap_or_else(|| addr.to_string())
+    };
+    let assignments = suite
+        .ticket_worker_pairs()?
+        .into_iter()
+        .filter(|p| p.tid != 0 && !p.worker.is_empty())
+        .map(|p| (p.tid, name_of(&p.worker)))
+        .collect();
+    let balances = accounts
+        .iter()
+        .map(|a| (a.clone(), suite.balance(&suite.addr(a))))
+        .collect();
+    let treasury = suite.treasury()?;
+
+    Ok(Report {
+        steps,
+        assignments,
+        balances,
+        locked: treasury.locked,
+        slashed: treasury.slashed,
+    })
+}
+
+fn run_step(suite: &mut Suite, step: &Step) -> Result<Vec<String>> {
+    let (sender, contract, msg, funds) = match step {
+        Step::AdvanceTime(seconds) => {
+            suite.advance_time(*seconds);
+            return Ok(vec![]);
+        }
+        Step::Execute {
+            sender,
+            contract,
+            msg,
+            funds,
+        } => (sender, contract, msg, funds),
+    };
+    let sender = if sender == ADMIN {
+        suite.admin.clone()
+    } else {
+        suite.addr(sender)
+    };
+    let contract: &Addr = match contract {
+        Target::AdminBoard => &suite.admin_board,
+        Target::UserBoard => &suite.user_board,
+        Target::AuctionManager => &suite.auction_manager,
+    };
+    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg: Binary::from(serde_json::to_vec(msg)?),
+        funds: funds.clone(),
+    });
+
+    let res = suite.app.execute(sender, msg)?;
+    Ok(res
+        .events
+        .iter()
+        .flat_map(|e| e.attributes.iter())
+        .filter(|a| a.key == "method")
+        .map(|a| a.value.clone())
+        .collect())
+}
+
+// Whole ticket lifecycle: the admin adds a ticket, "alice" & "bob" stake & bet,
+// the admin decides the winner(alice, lower bet) after the bet finishes & alice submits the result.
+pub fn default_plan(start_time: u64) -> Result<Plan> {
+    let mut plan = Plan::new(start_time);
+    let collateral = 100u64;
+    plan.steps
+        .push(Step::admin(&AdminExecuteMsg::AddTicket(AddTicketMsg {
+            id: 1,
+            bet_finish_timestamp: start_time + 3_600,
+            close_timestamp: start_time + 3 * 3_600,
+            result: "42".to_string(),
+            collateral,
+            auction: AuctionParams::default(),
+            metadata: TicketMetadata {
+                title: "Answer".to_string(),
+                category: Some("qa".to_string()),
+                ..TicketMetadata::default()
+            },
+            creator: None,
+            slots: 1,
+            assessment: AssessmentMode::ExpectedResult,
+            resubmission: false,
+            matcher: ResultMatcher::default(),
+            lateness: LatenessPenalty::default(),
+        }))?);
+    for (worker, bet_amount) in [("alice", 50u64), ("bob", 60u64)] {
+        plan.steps.push(Step::user(
+            worker,
+            &UserExecuteMsg::LockStake { tid: 1 },
+            coins(collateral as u128, DENOM),
+        )?);
+        plan.steps.push(Step::user(
+            worker,
+            &UserExecuteMsg::PlaceBet(PlaceBetMsg {
+                ticket_id: 1,
+                bet_amount,
+            }),
+            vec![],
+        )?);
+    }
+    plan.steps.push(Step::AdvanceTime(3_600));
+    plan.steps
+        .push(Step::admin(&AdminExecuteMsg::DecideWinningBet { tid: 1 })?);
+    plan.steps.push(Step::user(
+        "alice",
+        &UserExecuteMsg::SubmitResult(TicketResultMsg {
+            tid: 1,
+            worker: "alice".to_string(),
+            result: "42".to_string(),
+        }),
+        vec![],
+    )?);
+    Ok(plan)
+}
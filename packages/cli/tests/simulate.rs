@@ -0,0 +1,81 @@
+use cosmwasm_std::coins;
+use serde_json::json;
+use ticket_auction::admin_board::ExecuteMsg as AdminExecuteMsg;
+use ticket_auction::user_board::ExecuteMsg as UserExecuteMsg;
+use ticket_auction_cli::{
+    config::Config,
+    plan::{Plan, Step},
+    simulate::{default_plan, simulate, DEFAULT_FUNDS},
+};
+
+const START: u64 = 1_700_000_000;
+
+#[test]
+fn default_plan_runs_the_lifecycle() {
+    let plan = default_plan(START).unwrap();
+    let report = simulate(&plan, DEFAULT_FUNDS).unwrap();
+
+    assert!(report.is_ok());
+    assert_eq!(report.assignments, vec![(1, "alice".to_string())]);
+    // alice got the stake back, bob's stake is still locked.
+    assert_eq!(
+        report.balances,
+        vec![
+            ("alice".to_string(), DEFAULT_FUNDS),
+            ("bob".to_string(), DEFAULT_FUNDS - 100)
+        ]
+    );
+    assert_eq!(report.locked.u128(), 100);
+}
+
+#[test]
+fn failing_step_is_reported() {
+    let mut plan = Plan::new(START);
+    plan.steps
+        .push(Step::admin(&AdminExecuteMsg::DecideWinningBet { tid: 5 }).unwrap());
+    plan.steps.push(
+        Step::user(
+            "carol",
+            &UserExecuteMsg::LockStake { tid: 5 },
+            coins(10, "uluna"),
+        )
+        .unwrap(),
+    );
+    plan.steps.push(Step::AdvanceTime(10));
+
+    let report = simulate(&plan, 1_000).unwrap();
+    assert!(!report.is_ok());
+    assert!(report.steps[0].result.is_err());
+    // The next steps still run.
+    assert!(report.steps[2].result.is_ok());
+    assert_eq!(report.balances, vec![("carol".to_string(), 1_000)]);
+}
+
+#[test]
+fn plan_time_follows_the_advances() {
+    let mut plan = Plan::new(START);
+    plan.steps.push(Step::AdvanceTime(60));
+    plan.steps.push(Step::AdvanceTime(40));
+    assert_eq!(plan.now(), START + 100);
+}
+
+#[test]
+fn envelope_uses_the_config_addresses() {
+    let step = Step::admin(&AdminExecuteMsg::RemoveTicket { tid: 3 }).unwrap();
+    assert!(step.envelope(&Config::default()).is_err());
+
+    let config = Config {
+        admin: Some("terra1admin".to_string()),
+        admin_board: Some("terra1board".to_string()),
+        ..Config::default()
+    };
+    assert_eq!(
+        step.envelope(&config).unwrap(),
+        json!({
+            "sender": "terra1admin",
+            "contract": "terra1board",
+            "msg": { "remove_ticket": { "tid": 3 } },
+            "funds": [],
+        })
+    );
+}
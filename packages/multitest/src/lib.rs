@@ -0,0 +1,803 @@
+// Multi-contract test harness for the ticket-auction suite.
+//
+// "Suite" deploys admin-board, ticket-manager, user-board, auction-manager and
+// collateral-manager on the simulated chain(cw-multi-test) exactly as the README describes:
+// admin-board is instantiated by the admin wallet and creates the other contracts,
+// then the addresses are registered with "PostConfig".
+// The helpers send the same messages as the admin wallet & workers would do on the chain.
+use anyhow::{anyhow, Result as AnyResult};
+use cosmwasm_std::testing::MockApi;
+use cosmwasm_std::{coins, Addr, Coin, Empty};
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+
+use ticket_auction::{
+    admin_board::{
+        ChildInfo, ChildKind, ChildrenResponse, ExecuteMsg as AdminExecuteMsg,
+        InstantiateMsg as AdminInstantiateMsg, PostConfigMsg, QueryMsg as AdminQueryMsg,
+    },
+    auction_manager::{
+        BetDetail, BidScoresResponse, ExecuteMsg as AuctionExecuteMsg, FeePoolResponse,
+        QueryMsg as AuctionQueryMsg,
+    },
+    collateral_manager::{
+        DisputeParams, ExecuteMsg as CollateralExecuteMsg, HeldSlash,
+        QueryMsg as CollateralQueryMsg, QueryStakeStatusMsg, TreasuryResponse,
+    },
+    matcher::ResultMatcher,
+    ticket_manager::{
+        AddTicketMsg, AssessmentMode, AuctionParams, ExecuteMsg as TicketExecuteMsg,
+        GradeSubmissionMsg, LatenessPenalty, LatenessResponse, PendingReview,
+        QueryMsg as TicketQueryMsg, SimulateAssessmentResponse, Submission, TWPairsReponse,
+        TicketInfoResponse, TicketMetadata, TicketResultMsg, TicketWorkerPair, TicketsResponse,
+        UpdateTicketMsg, WorkerReputationResponse,
+    },
+    user_board::{
+        ExecuteMsg as UserExecuteMsg, PlaceBetMsg, QueryMsg as UserQueryMsg,
+        WorkerDashboardResponse,
+    },
+};
+
+pub const DENOM: &str = "uluna";
+
+// Seconds between the ticket creation & "bet_finish_timestamp" in "Suite::ticket"
+pub const BET_DURATION: u64 = 1_000;
+// Seconds between "bet_finish_timestamp" & "close_timestamp" in "Suite::ticket"
+pub const WORK_DURATION: u64 = 10_000;
+
+// Initial balance of the workers in "suite_with_workers"
+pub const INITIAL: u128 = 10_000;
+// Collateral of the tickets shared by the tests
+pub const COLLATERAL: u64 = 100;
+
+pub fn contract_admin_board() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new(
+            admin_board::contract::execute,
+            admin_board::contract::instantiate,
+            admin_board::contract::query,
+        )
+        .with_migrate(admin_board::contract::migrate),
+    )
+}
+
+pub fn contract_ticket_manager() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new(
+            ticket_manager::contract::execute,
+            ticket_manager::contract::instantiate,
+            ticket_manager::contract::query,
+        )
+        .with_migrate(ticket_manager::contract::migrate),
+    )
+}
+
+pub fn contract_user_board() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new(
+            user_board::contract::execute,
+            user_board::contract::instantiate,
+            user_board::contract::query,
+        )
+        .with_migrate(user_board::contract::migrate),
+    )
+}
+
+pub fn contract_auction_manager() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new(
+            auction_manager::contract::execute,
+            auction_manager::contract::instantiate,
+            auction_manager::contract::query,
+        )
+        .with_migrate(auction_manager::contract::migrate),
+    )
+}
+
+pub fn contract_collateral_manager() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new(
+            collateral_manager::contract::execute,
+            collateral_manager::contract::instantiate,
+            collateral_manager::contract::query,
+        )
+        .with_migrate(collateral_manager::contract::migrate),
+    )
+}
+
+// Suite with "count" workers funded by "INITIAL". (see "SuiteBuilder::with_workers")
+pub fn suite_with_workers(count: usize) -> Suite {
+    SuiteBuilder::new().with_workers(count).build()
+}
+
+// Check if the transaction failed with the error containing "msg".
+pub fn assert_err(res: AnyResult<AppResponse>, msg: &str) {
+    let err = res.unwrap_err().root_cause().to_string();
+    assert!(err.contains(msg), "{}", err);
+}
+
+// Builder of the "Suite" with the initial balances.
+#[derive(Default)]
+pub struct SuiteBuilder {
+    balances: Vec<(String, u128)>,
+}
+
+impl SuiteBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Give "amount" uluna to the account named "name". (see "Suite::addr")
+    pub fn with_funds(mut self, name: &str, amount: u128) -> Self {
+        self.balances.push((name.to_string(), amount));
+        self
+    }
+
+    // Give "INITIAL" uluna to each of the workers "worker1" ~ "worker{count}".
+    pub fn with_workers(mut self, count: usize) -> Self {
+        for i in 1..=count {
+            self = self.with_funds(&format!("worker{}", i), INITIAL);
+        }
+        self
+    }
+
+    pub fn build(self) -> Suite {
+        let balances = self.balances;
+        let api = MockApi::default();
+        let mut app = App::new(|router, _, storage| {
+            for (name, amount) in balances.iter() {
+                router
+                    .bank
+                    .init_balance(storage, &api.addr_make(name), coins(*amount, DENOM))
+                    .unwrap();
+            }
+        });
+        let admin = app.api().addr_make("admin");
+
+        // Upload the codes.
+        let admin_board_code = app.store_code(contract_admin_board());
+        let ticket_manager_code = app.store_code(contract_ticket_manager());
+        let user_board_code = app.store_code(contract_user_board());
+        let auction_manager_code = app.store_code(contract_auction_manager());
+        let collateral_manager_code = app.store_code(contract_collateral_manager());
+
+        // Deploy admin-board & let it create the others.
+        let admin_board = app
+            .instantiate_contract(
+                admin_board_code,
+                admin.clone(),
+                &AdminInstantiateMsg {},
+                &[],
+                "admin_board",
+                None,
+            )
+            .unwrap();
+
+        let ticket_manager = create_child(
+            &mut app,
+            &admin,
+            &admin_board,
+            AdminExecuteMsg::CreateTicketManager {
+                code_id: ticket_manager_code,
+            },
+        );
+        let user_board = create_child(
+            &mut app,
+            &admin,
+            &admin_board,
+            AdminExecuteMsg::CreateUsrBoardManager {
+                code_id: user_board_code,
+            },
+        );
+        app.execute_contract(
+            admin.clone(),
+            admin_board.clone(),
+            &AdminExecuteMsg::PostConfig(PostConfigMsg {
+                ticket_manager: Some(ticket_manager.to_string()),
+                collateral_manager: None,
+                auction_manager: None,
+                user_board: Some(user_board.to_string()),
+            }),
+            &[],
+        )
+        .unwrap();
+
+        let auction_manager = create_child(
+            &mut app,
+            &admin,
+            &admin_board,
+            AdminExecuteMsg::CreateAuctionManager {
+                code_id: auction_manager_code,
+            },
+        );
+        let collateral_manager = create_child(
+            &mut app,
+            &admin,
+            &admin_board,
+            AdminExecuteMsg::CreateCollateralManager {
+                code_id: collateral_manager_code,
+            },
+        );
+        app.execute_contract(
+            admin.clone(),
+            admin_board.clone(),
+            &AdminExecuteMsg::PostConfig(PostConfigMsg {
+                ticket_manager: Some(ticket_manager.to_string()),
+                collateral_manager: Some(collateral_manager.to_string()),
+                auction_manager: Some(auction_manager.to_string()),
+                user_board: Some(user_board.to_string()),
+            }),
+            &[],
+        )
+        .unwrap();
+
+        Suite {
+            app,
+            admin,
+            admin_board,
+            ticket_manager,
+            user_board,
+            auction_manager,
+            collateral_manager,
+        }
+    }
+}
+
+// Execute "Create*" on admin-board & return the address of the instantiated contract.
+fn create_child(app: &mut App, admin: &Addr, admin_board: &Addr, msg: AdminExecuteMsg) -> Addr {
+    let res = app
+        .execute_contract(admin.clone(), admin_board.clone(), &msg, &[])
+        .unwrap();
+    let addr = res
+        .events
+        .iter()
+        .filter(|e| e.ty == "instantiate")
+        .flat_map(|e| e.attributes.iter())
+        .find(|a| a.key == "_contract_address")
+        .map(|a| a.value.clone())
+        .unwrap();
+    Addr::unchecked(addr)
+}
+
+// The deployed ticket-auction suite.
+pub struct Suite {
+    pub app: App,
+    pub admin: Addr,
+    pub admin_board: Addr,
+    pub ticket_manager: Addr,
+    pub user_board: Addr,
+    pub auction_manager: Addr,
+    pub collateral_manager: Addr,
+}
+
+impl Suite {
+    // Address of the account named "name".
+    pub fn addr(&self, name: &str) -> Addr {
+        self.app.api().addr_make(name)
+    }
+
+    pub fn block_time(&self) -> u64 {
+        self.app.block_info().time.seconds()
+    }
+
+    pub fn advance_time(&mut self, seconds: u64) {
+        self.app.update_block(|block| {
+            block.time = block.time.plus_seconds(seconds);
+            block.height += 1;
+        });
+    }
+
+    pub fn balance(&self, addr: &Addr) -> u128 {
+        self.app
+            .wrap()
+            .query_balance(addr, DENOM)
+            .unwrap()
+            .amount
+            .u128()
+    }
+
+    // Ticket opened for the bets now, bet finishes after "BET_DURATION"
+    // & closes "WORK_DURATION" later.
+    pub fn ticket(&self, id: u64, collateral: u64, result: &str) -> AddTicketMsg {
+        let now = self.block_time();
+        AddTicketMsg {
+            id,
+            bet_finish_timestamp: now + BET_DURATION,
+            close_timestamp: now + BET_DURATION + WORK_DURATION,
+            result: result.to_string(),
+            collateral,
+            auction: AuctionParams::default(),
+            metadata: TicketMetadata::default(),
+            creator: None,
+            slots: 1,
+            assessment: AssessmentMode::default(),
+            resubmission: false,
+            matcher: ResultMatcher::default(),
+            lateness: LatenessPenalty::default(),
+        }
+    }
+
+    // **=================================================
+    // ** Admin wallet -> admin-board             ========
+    // **=================================================
+    pub fn execute_admin(&mut self, msg: AdminExecuteMsg) -> AnyResult<AppResponse> {
+        self.app
+            .execute_contract(self.admin.clone(), self.admin_board.clone(), &msg, &[])
+    }
+
+    pub fn add_ticket(&mut self, msg: AddTicketMsg) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::AddTicket(msg))
+    }
+
+    pub fn update_ticket(&mut self, msg: UpdateTicketMsg) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::UpdateTicket(msg))
+    }
+
+    pub fn remove_ticket(&mut self, tid: u64) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::RemoveTicket { tid })
+    }
+
+    pub fn decide_winning_bet(&mut self, tid: u64) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::DecideWinningBet { tid })
+    }
+
+    pub fn report_no_show(&mut self, tid: u64) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::ReportNoShow { tid })
+    }
+
+    pub fn add_tickets(&mut self, msgs: Vec<AddTicketMsg>) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::AddTickets(msgs))
+    }
+
+    pub fn update_tickets(&mut self, msgs: Vec<UpdateTicketMsg>) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::UpdateTickets(msgs))
+    }
+
+    pub fn cancel_tickets(&mut self, tids: Vec<u64>) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::CancelTickets { tids })
+    }
+
+    pub fn decide_winning_bets(&mut self, tids: Vec<u64>) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::DecideWinningBets { tids })
+    }
+
+    pub fn add_grader(&mut self, grader: &Addr) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::AddGrader {
+            address: grader.to_string(),
+        })
+    }
+
+    pub fn add_arbiter(&mut self, arbiter: &Addr) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::AddArbiter {
+            address: arbiter.to_string(),
+        })
+    }
+
+    pub fn set_dispute_params(&mut self, window: u64, bond: u128) -> AnyResult<AppResponse> {
+        self.execute_admin(AdminExecuteMsg::SetDisputeParams(DisputeParams {
+            window,
+            bond: bond.into(),
+        }))
+    }
+
+    // **=================================================
+    // ** Grader -> admin-board                   ========
+    // **=================================================
+    pub fn grade_submission(
+        &mut self,
+        grader: &Addr,
+        tid: u64,
+        worker: Option<&Addr>,
+        score: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            grader.clone(),
+            self.admin_board.clone(),
+            &AdminExecuteMsg::GradeSubmission(GradeSubmissionMsg {
+                tid,
+                worker: worker.map(|w| w.to_string()),
+                score,
+                notes: String::new(),
+                grader: None,
+            }),
+            &[],
+        )
+    }
+
+    // **=================================================
+    // ** Arbiter -> admin-board                  ========
+    // **=================================================
+    pub fn resolve_dispute(
+        &mut self,
+        arbiter: &Addr,
+        tid: u64,
+        worker: &Addr,
+        uphold: bool,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            arbiter.clone(),
+            self.admin_board.clone(),
+            &AdminExecuteMsg::ResolveDispute {
+                tid,
+                worker: worker.to_string(),
+                uphold,
+            },
+            &[],
+        )
+    }
+
+    // **=================================================
+    // ** Keeper -> ticket-manager                ========
+    // **=================================================
+    pub fn auto_approve(&mut self, keeper: &Addr, tid: u64) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            keeper.clone(),
+            self.ticket_manager.clone(),
+            &TicketExecuteMsg::AutoApprove { tid },
+            &[],
+        )
+    }
+
+    pub fn close_submissions(&mut self, keeper: &Addr, tid: u64) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            keeper.clone(),
+            self.ticket_manager.clone(),
+            &TicketExecuteMsg::CloseSubmissions { tid },
+            &[],
+        )
+    }
+
+    // **=================================================
+    // ** Worker -> user-board                    ========
+    // **=================================================
+    pub fn lock_stake(&mut self, worker: &Addr, tid: u64, amount: u128) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            worker.clone(),
+            self.user_board.clone(),
+            &UserExecuteMsg::LockStake { tid },
+            &coins(amount, DENOM),
+        )
+    }
+
+    pub fn place_bet(
+        &mut self,
+        worker: &Addr,
+        tid: u64,
+        bet_amount: u64,
+    ) -> AnyResult<AppResponse> {
+        self.place_bet_with_fee(worker, tid, bet_amount, 0)
+    }
+
+    // Place the bet, sending the bet fee.
+    pub fn place_bet_with_fee(
+        &mut self,
+        worker: &Addr,
+        tid: u64,
+        bet_amount: u64,
+        fee: u128,
+    ) -> AnyResult<AppResponse> {
+        let funds = match fee {
+            0 => vec![],
+            fee => coins(fee, DENOM),
+        };
+        self.app.execute_contract(
+            worker.clone(),
+            self.user_board.clone(),
+            &UserExecuteMsg::PlaceBet(PlaceBetMsg {
+                ticket_id: tid,
+                bet_amount,
+            }),
+            &funds,
+        )
+    }
+
+    pub fn submit_result(
+        &mut self,
+        worker: &Addr,
+        tid: u64,
+        result: &str,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            worker.clone(),
+            self.user_board.clone(),
+            &UserExecuteMsg::SubmitResult(TicketResultMsg {
+                tid,
+                worker: worker.to_string(),
+                result: result.to_string(),
+            }),
+            &[],
+        )
+    }
+
+    pub fn dispute_assessment(
+        &mut self,
+        worker: &Addr,
+        tid: u64,
+        evidence_uri: &str,
+        bond: u128,
+    ) -> AnyResult<AppResponse> {
+        let funds = match bond {
+            0 => vec![],
+            bond => coins(bond, DENOM),
+        };
+        self.app.execute_contract(
+            worker.clone(),
+            self.user_board.clone(),
+            &UserExecuteMsg::DisputeAssessment {
+                tid,
+                evidence_uri: evidence_uri.to_string(),
+            },
+            &funds,
+        )
+    }
+
+    // **=================================================
+    // ** Keeper -> collateral-manager            ========
+    // **=================================================
+    pub fn finalize_slash(
+        &mut self,
+        keeper: &Addr,
+        tid: u64,
+        worker: &Addr,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            keeper.clone(),
+            self.collateral_manager.clone(),
+            &CollateralExecuteMsg::FinalizeSlash {
+                tid,
+                worker: worker.to_string(),
+            },
+            &[],
+        )
+    }
+
+    // **=================================================
+    // ** Keeper -> auction-manager               ========
+    // **=================================================
+    pub fn execute_auction(
+        &mut self,
+        sender: &Addr,
+        msg: AuctionExecuteMsg,
+        funds: &[Coin],
+    ) -> AnyResult<AppResponse> {
+        self.app
+            .execute_contract(sender.clone(), self.auction_manager.clone(), &msg, funds)
+    }
+
+    pub fn settle_expired(&mut self, keeper: &Addr, limit: Option<u32>) -> AnyResult<AppResponse> {
+        self.execute_auction(keeper, AuctionExecuteMsg::SettleExpired { limit }, &[])
+    }
+
+    // **=================================================
+    // ** Lifecycle                               ========
+    // **=================================================
+    // Lock the ticket collateral & bet for every worker, then decide the winning bet
+    // after "bet_finish_timestamp". Return the winner.
+    pub fn run_auction(&mut self, tid: u64, bets: &[(&Addr, u64)]) -> AnyResult<Addr> {
+        let ticket = self.ticket_info(tid)?;
+        for (worker, bet_amount) in bets {
+            self.lock_stake(worker, tid, ticket.collateral as u128)?;
+            self.place_bet(worker, tid, *bet_amount)?;
+        }
+        let now = self.block_time();
+        let ticket = self.ticket_info(tid)?;
+        if now < ticket.bet_finish_timestamp {
+            self.advance_time(ticket.bet_finish_timestamp - now);
+        }
+        self.decide_winning_bet(tid)?;
+        self.ticket_worker(tid)
+    }
+
+    // Run the auction & let the winner submit "result" right away.
+    pub fn run_lifecycle(
+        &mut self,
+        tid: u64,
+        bets: &[(&Addr, u64)],
+        result: &str,
+    ) -> AnyResult<Addr> {
+        let winner = self.run_auction(tid, bets)?;
+        self.submit_result(&winner, tid, result)?;
+        Ok(winner)
+    }
+
+    // **=================================================
+    // ** Queries                                 ========
+    // **=================================================
+    pub fn ticket_info(&self, tid: u64) -> AnyResult<TicketInfoResponse> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.ticket_manager.clone(),
+            &TicketQueryMsg::QueryTicketInfo { tid },
+        )?)
+    }
+
+    // Ids of the tickets matching the filters.
+    pub fn tickets(&self, category: Option<&str>, tag: Option<&str>) -> AnyResult<Vec<u64>> {
+        let res: TicketsResponse = self.app.wrap().query_wasm_smart(
+            self.ticket_manager.clone(),
+            &TicketQueryMsg::QueryTickets {
+                category: category.map(String::from),
+                tag: tag.map(String::from),
+            },
+        )?;
+        Ok(res.tickets.into_iter().map(|t| t.id).collect())
+    }
+
+    pub fn ticket_worker(&self, tid: u64) -> AnyResult<Addr> {
+        let worker: String = self.app.wrap().query_wasm_smart(
+            self.ticket_manager.clone(),
+            &TicketQueryMsg::QueryTicketWorker { tid },
+        )?;
+        if worker.is_empty() {
+            return Err(anyhow!("no worker assigned on ticket {}", tid));
+        }
+        Ok(Addr::unchecked(worker))
+    }
+
+    pub fn ticket_workers(&self, tid: u64) -> AnyResult<Vec<Addr>> {
+        let workers: Vec<String> = self.app.wrap().query_wasm_smart(
+            self.ticket_manager.clone(),
+            &TicketQueryMsg::QueryTicketWorkers { tid },
+        )?;
+        Ok(workers.into_iter().map(Addr::unchecked).collect())
+    }
+
+    pub fn pending_reviews(&self) -> AnyResult<Vec<PendingReview>> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.ticket_manager.clone(),
+            &TicketQueryMsg::QueryPendingReviews {},
+        )?)
+    }
+
+    pub fn submission(&self, tid: u64, worker: &Addr) -> AnyResult<Option<Submission>> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.ticket_manager.clone(),
+            &TicketQueryMsg::QuerySubmission {
+                tid,
+                worker: worker.to_string(),
+            },
+        )?)
+    }
+
+    pub fn lateness_penalty(&self, tid: u64, at_time: Option<u64>) -> AnyResult<LatenessResponse> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.ticket_manager.clone(),
+            &TicketQueryMsg::QueryLatenessPenalty { tid, at_time },
+        )?)
+    }
+
+    pub fn simulate_assessment(
+        &self,
+        tid: u64,
+        worker: &Addr,
+        result: &str,
+        at_time: Option<u64>,
+    ) -> AnyResult<SimulateAssessmentResponse> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.ticket_manager.clone(),
+            &TicketQueryMsg::SimulateAssessment {
+                tid,
+                worker: worker.to_string(),
+                result: result.to_string(),
+                at_time,
+            },
+        )?)
+    }
+
+    pub fn worker_submissions(&self, worker: &Addr) -> AnyResult<Vec<Submission>> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.ticket_manager.clone(),
+            &TicketQueryMsg::QuerySubmissionsByWorker {
+                worker: worker.to_string(),
+            },
+        )?)
+    }
+
+    pub fn worker_reputation(&self, worker: &Addr) -> AnyResult<WorkerReputationResponse> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.ticket_manager.clone(),
+            &TicketQueryMsg::QueryWorkerReputation {
+                worker: worker.to_string(),
+            },
+        )?)
+    }
+
+    pub fn worker_dashboard(&self, worker: &Addr) -> AnyResult<WorkerDashboardResponse> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.user_board.clone(),
+            &UserQueryMsg::QueryWorkerDashboard {
+                worker: worker.to_string(),
+            },
+        )?)
+    }
+
+    pub fn stake_status(&self, tid: u64, worker: &Addr) -> AnyResult<bool> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.collateral_manager.clone(),
+            &CollateralQueryMsg::QueryStakeStatus(QueryStakeStatusMsg {
+                tid,
+                worker: worker.clone(),
+            }),
+        )?)
+    }
+
+    pub fn active_bets(&self, tid: u64) -> AnyResult<Vec<BetDetail>> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.auction_manager.clone(),
+            &AuctionQueryMsg::CurrActiveBets { tid },
+        )?)
+    }
+
+    pub fn fee_pool(&self) -> AnyResult<FeePoolResponse> {
+        Ok(self
+            .app
+            .wrap()
+            .query_wasm_smart(self.auction_manager.clone(), &AuctionQueryMsg::FeePool {})?)
+    }
+
+    pub fn bid_scores(&self, tid: u64) -> AnyResult<BidScoresResponse> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.auction_manager.clone(),
+            &AuctionQueryMsg::BidScores { tid },
+        )?)
+    }
+
+    pub fn worker_stakes(&self, worker: &Addr) -> AnyResult<Vec<u64>> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.collateral_manager.clone(),
+            &CollateralQueryMsg::QueryWorkerStakes {
+                worker: worker.clone(),
+            },
+        )?)
+    }
+
+    pub fn treasury(&self) -> AnyResult<TreasuryResponse> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.collateral_manager.clone(),
+            &CollateralQueryMsg::QueryTreasury {},
+        )?)
+    }
+
+    pub fn held_slash(&self, tid: u64, worker: &Addr) -> AnyResult<Option<HeldSlash>> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.collateral_manager.clone(),
+            &CollateralQueryMsg::QueryHeldSlash {
+                tid,
+                worker: worker.clone(),
+            },
+        )?)
+    }
+
+    pub fn disputes(&self) -> AnyResult<Vec<HeldSlash>> {
+        Ok(self.app.wrap().query_wasm_smart(
+            self.collateral_manager.clone(),
+            &CollateralQueryMsg::QueryDisputes {},
+        )?)
+    }
+
+    pub fn ticket_worker_pairs(&self) -> AnyResult<Vec<TicketWorkerPair>> {
+        let res: TWPairsReponse = self.app.wrap().query_wasm_smart(
+            self.ticket_manager.clone(),
+            &TicketQueryMsg::QueryTicketWorkerPairs {},
+        )?;
+        Ok(res.pairs)
+    }
+
+    pub fn children(&self) -> AnyResult<Vec<ChildInfo>> {
+        let res: ChildrenResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(self.admin_board.clone(), &AdminQueryMsg::QueryChildren {})?;
+        Ok(res.children)
+    }
+
+    pub fn child(&self, kind: ChildKind) -> AnyResult<ChildInfo> {
+        self.children()?
+            .into_iter()
+            .find(|c| c.kind == kind)
+            .ok_or_else(|| anyhow!("{:?} not created", kind))
+    }
+
+    // Uluna held by collateral-manager.
+    pub fn collateral_balance(&self) -> u128 {
+        self.balance(&self.collateral_manager)
+    }
+}
@@ -0,0 +1,385 @@
+use auction_manager::state::CONFIG;
+use cosmwasm_std::Uint128;
+use cw_multi_test::Executor;
+use ticket_auction::admin_board::{ExecuteMsg as AdminExecuteMsg, PostConfigMsg};
+use ticket_auction::auction_manager::{ExecuteMsg as AuctionExecuteMsg, PlaceBetMsg};
+use ticket_auction::ticket_manager::{
+    AuctionParams, BidScoring, SoftClose, TicketLimits, DEFAULT_LATENESS_CUTOFF,
+};
+use ticket_auction_multitest::{assert_err, Suite, SuiteBuilder, BET_DURATION, WORK_DURATION};
+
+const INITIAL: u128 = 10_000;
+const COLLATERAL: u64 = 100;
+
+fn suite() -> Suite {
+    SuiteBuilder::new()
+        .with_funds("worker1", INITIAL)
+        .with_funds("worker2", INITIAL)
+        .with_funds("worker3", INITIAL)
+        .with_funds("keeper", INITIAL)
+        .build()
+}
+
+fn add_ticket(suite: &mut Suite, tid: u64, auction: AuctionParams) {
+    let mut msg = suite.ticket(tid, COLLATERAL, "42");
+    msg.auction = auction;
+    suite.add_ticket(msg).unwrap();
+}
+
+#[test]
+fn bet_amount_follows_auction_rules() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    let worker2 = suite.addr("worker2");
+    add_ticket(
+        &mut suite,
+        1,
+        AuctionParams {
+            reserve_price: Some(80),
+            min_bid: Some(10),
+            min_decrement: Some(5),
+            ..AuctionParams::default()
+        },
+    );
+    suite.lock_stake(&worker1, 1, 100).unwrap();
+    suite.lock_stake(&worker2, 1, 100).unwrap();
+
+    suite.place_bet(&worker1, 1, 81).unwrap_err();
+    suite.place_bet(&worker1, 1, 9).unwrap_err();
+    suite.place_bet(&worker1, 1, 50).unwrap();
+    suite.place_bet(&worker2, 1, 46).unwrap_err();
+    suite.place_bet(&worker2, 1, 45).unwrap();
+
+    // Bet is closed after "bet_finish_timestamp".
+    suite.advance_time(BET_DURATION);
+    suite.place_bet(&worker1, 1, 20).unwrap_err();
+}
+
+#[test]
+fn zero_bet_is_rejected() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    add_ticket(&mut suite, 1, AuctionParams::default());
+    suite.lock_stake(&worker1, 1, 100).unwrap();
+
+    suite.place_bet(&worker1, 1, 0).unwrap_err();
+}
+
+#[test]
+fn bet_is_only_accepted_from_user_board() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    add_ticket(&mut suite, 1, AuctionParams::default());
+
+    // The unstaked bet sent to auction_manager directly.
+    let err = suite
+        .app
+        .execute_contract(
+            worker1.clone(),
+            suite.auction_manager.clone(),
+            &AuctionExecuteMsg::PlaceBet(PlaceBetMsg {
+                ticket_id: 1,
+                workder: worker1.to_string(),
+                bet_amount: 10,
+            }),
+            &[],
+        )
+        .unwrap_err();
+    assert!(
+        err.root_cause().to_string().contains("Not authorized"),
+        "{}",
+        err
+    );
+    assert!(suite.active_bets(1).unwrap().is_empty());
+}
+
+#[test]
+fn migrated_config_gets_user_board_from_admin() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    add_ticket(&mut suite, 1, AuctionParams::default());
+    suite.lock_stake(&worker1, 1, COLLATERAL as u128).unwrap();
+
+    // Config stored before user_board was recorded.
+    {
+        let mut storage = suite.app.contract_storage_mut(&suite.auction_manager);
+        let mut config = CONFIG.load(storage.as_ref()).unwrap();
+        config.user_board = None;
+        CONFIG.save(storage.as_mut(), &config).unwrap();
+    }
+    assert_err(suite.place_bet(&worker1, 1, 50), "Config not initialized");
+
+    let user_board = suite.user_board.to_string();
+    suite
+        .execute_admin(AdminExecuteMsg::PostConfig(PostConfigMsg {
+            ticket_manager: Some(suite.ticket_manager.to_string()),
+            collateral_manager: Some(suite.collateral_manager.to_string()),
+            auction_manager: Some(suite.auction_manager.to_string()),
+            user_board: Some(user_board),
+        }))
+        .unwrap();
+    suite.place_bet(&worker1, 1, 50).unwrap();
+    assert_eq!(suite.active_bets(1).unwrap().len(), 1);
+}
+
+#[test]
+fn ties_go_to_the_earliest_bet() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    let worker2 = suite.addr("worker2");
+    add_ticket(&mut suite, 1, AuctionParams::default());
+
+    suite.lock_stake(&worker2, 1, 100).unwrap();
+    suite.place_bet(&worker2, 1, 50).unwrap();
+    suite.advance_time(10);
+    suite.lock_stake(&worker1, 1, 100).unwrap();
+    suite.place_bet(&worker1, 1, 50).unwrap();
+
+    suite.advance_time(BET_DURATION);
+    suite.decide_winning_bet(1).unwrap();
+    assert_eq!(suite.ticket_worker(1).unwrap(), worker2);
+
+    let scores = suite.bid_scores(1).unwrap();
+    assert!(scores.decided);
+    assert_eq!(scores.scores[0].worker, worker2.to_string());
+    assert_eq!(scores.scores[1].worker, worker1.to_string());
+}
+
+#[test]
+fn reputation_is_required_and_weighted() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    let worker2 = suite.addr("worker2");
+
+    // worker1 doesn't show up on the first ticket, worker2 completes the second.
+    add_ticket(&mut suite, 1, AuctionParams::default());
+    add_ticket(&mut suite, 2, AuctionParams::default());
+    suite.lock_stake(&worker1, 1, 100).unwrap();
+    suite.place_bet(&worker1, 1, 10).unwrap();
+    suite.lock_stake(&worker2, 2, 100).unwrap();
+    suite.place_bet(&worker2, 2, 10).unwrap();
+    suite.advance_time(BET_DURATION);
+    suite.decide_winning_bet(1).unwrap();
+    suite.decide_winning_bet(2).unwrap();
+    suite.submit_result(&worker2, 2, "42").unwrap();
+    suite.advance_time(WORK_DURATION + DEFAULT_LATENESS_CUTOFF + 1);
+    suite.report_no_show(1).unwrap();
+    assert_eq!(suite.worker_reputation(&worker1).unwrap().score, 0);
+    assert_eq!(suite.worker_reputation(&worker2).unwrap().score, 1000);
+
+    // Minimum reputation
+    add_ticket(
+        &mut suite,
+        3,
+        AuctionParams {
+            min_reputation: Some(600),
+            ..AuctionParams::default()
+        },
+    );
+    suite.lock_stake(&worker1, 3, 100).unwrap();
+    suite.place_bet(&worker1, 3, 10).unwrap_err();
+    suite.lock_stake(&worker2, 3, 100).unwrap();
+    suite.place_bet(&worker2, 3, 10).unwrap();
+
+    // Completion weighted: the cheaper but unreliable worker loses.
+    add_ticket(
+        &mut suite,
+        4,
+        AuctionParams {
+            scoring: Some(BidScoring {
+                price_weight: 1,
+                completion_weight: 1,
+                punctuality_weight: 0,
+            }),
+            ..AuctionParams::default()
+        },
+    );
+    let winner = suite
+        .run_auction(4, &[(&worker1, 50), (&worker2, 60)])
+        .unwrap();
+    assert_eq!(winner, worker2);
+
+    let scores = suite.bid_scores(4).unwrap();
+    assert_eq!(scores.scores[0].worker, worker2.to_string());
+    assert_eq!(scores.scores[0].price_score, 833);
+    assert_eq!(scores.scores[0].completion_score, 1000);
+    assert_eq!(scores.scores[0].total_score, 916);
+    assert_eq!(scores.scores[1].price_score, 1000);
+    assert_eq!(scores.scores[1].completion_score, 0);
+    assert_eq!(scores.scores[1].total_score, 500);
+
+    // The weights are bounded & not all zero.
+    for (price_weight, completion_weight) in [(0, 0), (1001, 1), (1, u64::MAX)] {
+        let mut msg = suite.ticket(5, COLLATERAL, "42");
+        msg.auction.scoring = Some(BidScoring {
+            price_weight,
+            completion_weight,
+            punctuality_weight: 0,
+        });
+        let err = suite.add_ticket(msg).unwrap_err();
+        assert!(
+            err.root_cause()
+                .to_string()
+                .contains("Invalid auction params"),
+            "{}",
+            err
+        );
+    }
+}
+
+#[test]
+fn late_best_bet_extends_bet_finish() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    let worker2 = suite.addr("worker2");
+    add_ticket(
+        &mut suite,
+        1,
+        AuctionParams {
+            soft_close: Some(SoftClose {
+                window: 60,
+                extension: 100,
+                max_extension: 150,
+            }),
+            ..AuctionParams::default()
+        },
+    );
+    let bet_finish = suite.ticket_info(1).unwrap().bet_finish_timestamp;
+    suite.lock_stake(&worker1, 1, 100).unwrap();
+    suite.lock_stake(&worker2, 1, 100).unwrap();
+
+    // Outside the window
+    suite.place_bet(&worker1, 1, 50).unwrap();
+    assert_eq!(
+        suite.ticket_info(1).unwrap().bet_finish_timestamp,
+        bet_finish
+    );
+
+    // New best bet within the window
+    suite.advance_time(BET_DURATION - 30);
+    suite.place_bet(&worker2, 1, 40).unwrap();
+    assert_eq!(
+        suite.ticket_info(1).unwrap().bet_finish_timestamp,
+        bet_finish + 100
+    );
+
+    // Not the best bet
+    suite.advance_time(90);
+    suite.place_bet(&worker1, 1, 45).unwrap();
+    assert_eq!(
+        suite.ticket_info(1).unwrap().bet_finish_timestamp,
+        bet_finish + 100
+    );
+
+    // Capped by "max_extension"
+    suite.place_bet(&worker1, 1, 30).unwrap();
+    assert_eq!(
+        suite.ticket_info(1).unwrap().bet_finish_timestamp,
+        bet_finish + 150
+    );
+    suite.decide_winning_bet(1).unwrap_err();
+}
+
+#[test]
+fn soft_close_keeps_min_work_duration() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    let worker2 = suite.addr("worker2");
+    suite
+        .execute_admin(AdminExecuteMsg::SetTicketLimits(TicketLimits {
+            min_work_duration: WORK_DURATION - 100,
+            ..TicketLimits::default()
+        }))
+        .unwrap();
+    add_ticket(
+        &mut suite,
+        1,
+        AuctionParams {
+            soft_close: Some(SoftClose {
+                window: 60,
+                extension: 80,
+                max_extension: 1_000,
+            }),
+            ..AuctionParams::default()
+        },
+    );
+    let ticket = suite.ticket_info(1).unwrap();
+    let latest_finish = ticket.close_timestamp - (WORK_DURATION - 100);
+    suite.lock_stake(&worker1, 1, 100).unwrap();
+    suite.lock_stake(&worker2, 1, 100).unwrap();
+
+    suite.advance_time(BET_DURATION - 30);
+    suite.place_bet(&worker1, 1, 50).unwrap();
+    assert_eq!(
+        suite.ticket_info(1).unwrap().bet_finish_timestamp,
+        ticket.bet_finish_timestamp + 80
+    );
+
+    // The bet just before the cap is extended up to the minimum work duration only.
+    suite.advance_time(70);
+    suite.place_bet(&worker2, 1, 40).unwrap();
+    assert_eq!(
+        suite.ticket_info(1).unwrap().bet_finish_timestamp,
+        latest_finish
+    );
+
+    // No room is left, the bet is accepted without the extension.
+    suite.place_bet(&worker1, 1, 30).unwrap();
+    assert_eq!(
+        suite.ticket_info(1).unwrap().bet_finish_timestamp,
+        latest_finish
+    );
+    suite.advance_time(latest_finish - suite.block_time() + 1);
+    suite.decide_winning_bet(1).unwrap();
+    assert_eq!(
+        ticket.close_timestamp - suite.ticket_info(1).unwrap().bet_finish_timestamp,
+        WORK_DURATION - 100
+    );
+}
+
+#[test]
+fn keeper_settles_expired_auctions() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    let keeper = suite.addr("keeper");
+    add_ticket(&mut suite, 1, AuctionParams::default());
+    add_ticket(&mut suite, 2, AuctionParams::default());
+
+    // Keeper reward paid out of the bet fees.
+    suite
+        .execute_admin(AdminExecuteMsg::SetKeeperReward {
+            amount: Uint128::from(5u128),
+            bet_fee: Uint128::from(4u128),
+        })
+        .unwrap();
+    for tid in [1, 2] {
+        suite.lock_stake(&worker1, tid, 100).unwrap();
+        suite.place_bet(&worker1, tid, 50).unwrap_err();
+        suite.place_bet_with_fee(&worker1, tid, 50, 3).unwrap_err();
+        suite.place_bet_with_fee(&worker1, tid, 50, 4).unwrap();
+    }
+    assert_eq!(suite.fee_pool().unwrap().fee_pool.u128(), 8);
+
+    // Too early
+    suite
+        .execute_auction(
+            &keeper,
+            ticket_auction::auction_manager::ExecuteMsg::DecideWinningBet { tid: 1 },
+            &[],
+        )
+        .unwrap_err();
+
+    suite.advance_time(BET_DURATION);
+    suite.settle_expired(&keeper, Some(1)).unwrap();
+    assert_eq!(suite.ticket_worker(1).unwrap(), worker1);
+    suite.ticket_worker(2).unwrap_err();
+
+    assert_eq!(suite.balance(&keeper), INITIAL + 5);
+
+    // The rest of the fee pool does not cover the reward, so nothing is paid.
+    suite.settle_expired(&keeper, None).unwrap();
+    assert_eq!(suite.ticket_worker(2).unwrap(), worker1);
+    assert_eq!(suite.balance(&keeper), INITIAL + 5);
+    assert_eq!(suite.fee_pool().unwrap().fee_pool.u128(), 3);
+    assert_eq!(suite.balance(&suite.auction_manager), 3);
+}
@@ -0,0 +1,177 @@
+use ticket_auction::batch::MAX_BATCH_SIZE;
+use ticket_auction::ticket_manager::{AuctionParams, UpdateTicketMsg};
+use ticket_auction_multitest::{Suite, SuiteBuilder, BET_DURATION};
+
+const INITIAL: u128 = 10_000;
+const COLLATERAL: u64 = 100;
+
+fn suite() -> Suite {
+    SuiteBuilder::new()
+        .with_funds("worker1", INITIAL)
+        .with_funds("worker2", INITIAL)
+        .build()
+}
+
+fn update(id: u64, collateral: u64) -> UpdateTicketMsg {
+    UpdateTicketMsg {
+        id,
+        bet_finish_timestamp: None,
+        close_timestamp: None,
+        result: None,
+        collateral: Some(collateral),
+        auction: None,
+        metadata: None,
+        slots: None,
+        assessment: None,
+        matcher: None,
+        lateness: None,
+        resubmission: None,
+    }
+}
+
+#[test]
+fn add_tickets_stores_every_ticket() {
+    let mut suite = suite();
+    let tickets = (1..=3)
+        .map(|id| suite.ticket(id, COLLATERAL, "42"))
+        .collect();
+
+    let res = suite.add_tickets(tickets).unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "add_tickets")
+            .add_attribute("tids", "1,2,3")
+    ));
+    for id in 1..=3 {
+        assert_eq!(suite.ticket_info(id).unwrap().collateral, COLLATERAL);
+    }
+}
+
+#[test]
+fn invalid_item_fails_whole_batch_with_every_error() {
+    let mut suite = suite();
+    let mut invalid = suite.ticket(2, COLLATERAL, "42");
+    invalid.auction = AuctionParams {
+        min_bid: Some(10),
+        reserve_price: Some(5),
+        ..AuctionParams::default()
+    };
+    let tickets = vec![
+        suite.ticket(1, COLLATERAL, "42"),
+        invalid,
+        suite.ticket(1, COLLATERAL, "43"),
+    ];
+
+    let err = suite.add_tickets(tickets).unwrap_err();
+    let err = err.root_cause().to_string();
+    assert!(
+        err.contains("#1 (tid 2): Invalid auction params"),
+        "{}",
+        err
+    );
+    assert!(
+        err.contains("#2 (tid 1): Duplicate ticket id in the batch"),
+        "{}",
+        err
+    );
+    assert!(!err.contains("#0"), "{}", err);
+
+    // The valid item is not stored either.
+    suite.ticket_info(1).unwrap_err();
+}
+
+#[test]
+fn batch_size_is_capped() {
+    let mut suite = suite();
+    suite.add_tickets(vec![]).unwrap_err();
+
+    let tickets = (1..=MAX_BATCH_SIZE as u64 + 1)
+        .map(|id| suite.ticket(id, COLLATERAL, "42"))
+        .collect();
+    let err = suite.add_tickets(tickets).unwrap_err();
+    assert!(err.root_cause().to_string().contains("maximum size"));
+
+    let tickets = (1..=MAX_BATCH_SIZE as u64)
+        .map(|id| suite.ticket(id, COLLATERAL, "42"))
+        .collect();
+    suite.add_tickets(tickets).unwrap();
+}
+
+#[test]
+fn update_tickets_is_atomic() {
+    let mut suite = suite();
+    let tickets = (1..=2)
+        .map(|id| suite.ticket(id, COLLATERAL, "42"))
+        .collect();
+    suite.add_tickets(tickets).unwrap();
+
+    // Ticket 3 doesn't exist, ticket 1 is not updated.
+    let err = suite
+        .update_tickets(vec![update(1, 200), update(3, 200)])
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("#1 (tid 3)"));
+    assert_eq!(suite.ticket_info(1).unwrap().collateral, COLLATERAL);
+
+    suite
+        .update_tickets(vec![update(1, 200), update(2, 300)])
+        .unwrap();
+    assert_eq!(suite.ticket_info(1).unwrap().collateral, 200);
+    assert_eq!(suite.ticket_info(2).unwrap().collateral, 300);
+}
+
+#[test]
+fn cancel_tickets_requires_every_ticket() {
+    let mut suite = suite();
+    let tickets = (1..=3)
+        .map(|id| suite.ticket(id, COLLATERAL, "42"))
+        .collect();
+    suite.add_tickets(tickets).unwrap();
+
+    let err = suite.cancel_tickets(vec![1, 4]).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("#1 (tid 4): Not found"));
+    suite.ticket_info(1).unwrap();
+
+    suite.cancel_tickets(vec![1, 2]).unwrap();
+    suite.ticket_info(1).unwrap_err();
+    suite.ticket_info(2).unwrap_err();
+    suite.ticket_info(3).unwrap();
+}
+
+#[test]
+fn decide_winning_bets_settles_every_ticket() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    let worker2 = suite.addr("worker2");
+    let tickets = (1..=3)
+        .map(|id| suite.ticket(id, COLLATERAL, "42"))
+        .collect();
+    suite.add_tickets(tickets).unwrap();
+
+    // worker2 bets lower on ticket 1, worker1 on ticket 2.
+    for (tid, bet2) in [(1, 30), (2, 70)] {
+        suite.lock_stake(&worker1, tid, COLLATERAL as u128).unwrap();
+        suite.place_bet(&worker1, tid, 50).unwrap();
+        suite.lock_stake(&worker2, tid, COLLATERAL as u128).unwrap();
+        suite.place_bet(&worker2, tid, bet2).unwrap();
+    }
+
+    // Not decidable before the bet finishes.
+    let err = suite.decide_winning_bets(vec![1, 2]).unwrap_err();
+    assert!(err.root_cause().to_string().contains("Bet not finished"));
+
+    suite.advance_time(BET_DURATION);
+    // Ticket 3 has no bet, nothing is decided.
+    let err = suite.decide_winning_bets(vec![1, 2, 3]).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("#2 (tid 3): Not found"));
+    suite.ticket_worker(1).unwrap_err();
+
+    suite.decide_winning_bets(vec![1, 2]).unwrap();
+    assert_eq!(suite.ticket_worker(1).unwrap(), worker2);
+    assert_eq!(suite.ticket_worker(2).unwrap(), worker1);
+}
@@ -0,0 +1,137 @@
+use ticket_auction::ticket_manager::{QueryMsg as TicketQueryMsg, WorkerTicketsResponse};
+use ticket_auction_multitest::{assert_err, suite_with_workers, Suite, COLLATERAL, INITIAL};
+
+const WINDOW: u64 = 5_000;
+const BOND: u128 = 20;
+
+fn suite() -> Suite {
+    let mut suite = suite_with_workers(1);
+    let arbiter = suite.addr("arbiter");
+    suite.add_arbiter(&arbiter).unwrap();
+    suite.set_dispute_params(WINDOW, BOND).unwrap();
+
+    // The wrong result slashes the half of the stake.
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+    suite.submit_result(&worker1, 1, "41").unwrap();
+    suite
+}
+
+#[test]
+fn upheld_dispute_keeps_slash_and_bond() {
+    let mut suite = suite();
+    let arbiter = suite.addr("arbiter");
+    let worker1 = suite.addr("worker1");
+
+    // The slashed half is held, not yet in the treasury.
+    assert_eq!(suite.balance(&worker1), INITIAL - 50);
+    let held = suite.held_slash(1, &worker1).unwrap().unwrap();
+    assert_eq!(held.amount.u128(), 50);
+    assert_eq!(held.release_at, suite.block_time() + WINDOW);
+    let treasury = suite.treasury().unwrap();
+    assert_eq!(treasury.held.u128(), 50);
+    assert!(treasury.slashed.is_zero());
+
+    // The exact bond & the evidence are required.
+    assert_err(
+        suite.dispute_assessment(&worker1, 1, "ipfs://evidence", BOND - 1),
+        "Insufficient",
+    );
+    assert_err(
+        suite.dispute_assessment(&worker1, 1, " ", BOND),
+        "Invalid evidence",
+    );
+    suite
+        .dispute_assessment(&worker1, 1, "ipfs://evidence", BOND)
+        .unwrap();
+    assert_err(
+        suite.dispute_assessment(&worker1, 1, "ipfs://evidence", BOND),
+        "already disputed",
+    );
+    let disputes = suite.disputes().unwrap();
+    assert_eq!(disputes.len(), 1);
+    assert_eq!(
+        disputes[0].dispute.as_ref().unwrap().evidence_uri,
+        "ipfs://evidence"
+    );
+
+    // The disputed slash waits for the arbiter, even after the window.
+    suite.advance_time(WINDOW + 1);
+    assert_err(
+        suite.finalize_slash(&arbiter, 1, &worker1),
+        "already disputed",
+    );
+    assert_err(
+        suite.resolve_dispute(&worker1, 1, &worker1, false),
+        "Not authorized",
+    );
+
+    suite.resolve_dispute(&arbiter, 1, &worker1, true).unwrap();
+    assert_eq!(suite.balance(&worker1), INITIAL - 50 - BOND);
+    let treasury = suite.treasury().unwrap();
+    assert_eq!(treasury.slashed.u128(), 50 + BOND);
+    assert!(treasury.held.is_zero());
+    assert!(suite.held_slash(1, &worker1).unwrap().is_none());
+    assert!(suite.disputes().unwrap().is_empty());
+}
+
+#[test]
+fn reversed_dispute_returns_slash_and_bond() {
+    let mut suite = suite();
+    let arbiter = suite.addr("arbiter");
+    let worker1 = suite.addr("worker1");
+
+    suite
+        .dispute_assessment(&worker1, 1, "ipfs://evidence", BOND)
+        .unwrap();
+    suite.resolve_dispute(&arbiter, 1, &worker1, false).unwrap();
+    assert_eq!(suite.balance(&worker1), INITIAL);
+    assert!(suite.treasury().unwrap().held.is_zero());
+
+    // The worker's history no longer counts the slash.
+    let res: WorkerTicketsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            suite.ticket_manager.clone(),
+            &TicketQueryMsg::QueryWorkerTickets {
+                worker: worker1.to_string(),
+            },
+        )
+        .unwrap();
+    let outcome = res.assignments[0].outcome.clone().unwrap();
+    assert!(outcome.reversed);
+    assert!(outcome.slash_perc.is_zero());
+    let stats = suite.worker_reputation(&worker1).unwrap().stats;
+    assert!(stats.total_slashed.is_zero());
+    assert!(stats.slash_perc_sum.is_zero());
+
+    assert_err(
+        suite.resolve_dispute(&arbiter, 1, &worker1, false),
+        "Not found",
+    );
+}
+
+#[test]
+fn undisputed_slash_is_finalized_after_window() {
+    let mut suite = suite();
+    let keeper = suite.addr("keeper");
+    let worker1 = suite.addr("worker1");
+
+    assert_err(
+        suite.finalize_slash(&keeper, 1, &worker1),
+        "Dispute window not passed",
+    );
+
+    suite.advance_time(WINDOW + 1);
+    assert_err(
+        suite.dispute_assessment(&worker1, 1, "ipfs://evidence", BOND),
+        "No held slash",
+    );
+    suite.finalize_slash(&keeper, 1, &worker1).unwrap();
+    let treasury = suite.treasury().unwrap();
+    assert_eq!(treasury.slashed.u128(), 50);
+    assert!(treasury.held.is_zero());
+    assert_err(suite.finalize_slash(&keeper, 1, &worker1), "Not found");
+}
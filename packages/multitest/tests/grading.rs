@@ -0,0 +1,160 @@
+use cosmwasm_std::Addr;
+use ticket_auction::admin_board::{ExecuteMsg as AdminExecuteMsg, QueryMsg as AdminQueryMsg};
+use ticket_auction::ticket_manager::{
+    AddTicketMsg, AssessmentMode, LatenessPenalty, QueryMsg as TicketQueryMsg,
+    WorkerTicketsResponse,
+};
+use ticket_auction_multitest::{
+    assert_err, suite_with_workers, Suite, COLLATERAL, INITIAL, WORK_DURATION,
+};
+
+const REVIEW_PERIOD: u64 = 2 * WORK_DURATION;
+
+fn suite() -> Suite {
+    let mut suite = suite_with_workers(2);
+    let grader = suite.addr("grader");
+    suite.add_grader(&grader).unwrap();
+    suite
+}
+
+fn manual_ticket(suite: &Suite, slots: u32) -> AddTicketMsg {
+    AddTicketMsg {
+        slots,
+        assessment: AssessmentMode::Manual {
+            review_period: REVIEW_PERIOD,
+        },
+        ..suite.ticket(1, COLLATERAL, "")
+    }
+}
+
+#[test]
+fn grader_score_sets_slash() {
+    let mut suite = suite();
+    let grader = suite.addr("grader");
+    let worker1 = suite.addr("worker1");
+    let msg = AddTicketMsg {
+        assessment: AssessmentMode::Manual { review_period: 0 },
+        ..manual_ticket(&suite, 1)
+    };
+    assert_err(suite.add_ticket(msg), "Invalid review period");
+    suite.add_ticket(manual_ticket(&suite, 1)).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+
+    suite.submit_result(&worker1, 1, "ipfs://work").unwrap();
+    // The stake stays locked until the review.
+    assert_eq!(suite.balance(&worker1), INITIAL - COLLATERAL as u128);
+    let reviews = suite.pending_reviews().unwrap();
+    assert_eq!(reviews.len(), 1);
+    assert_eq!(reviews[0].result, "ipfs://work");
+    assert_eq!(
+        reviews[0].review_deadline,
+        reviews[0].submitted_at + REVIEW_PERIOD
+    );
+
+    // Only the grader grades, within the max score.
+    assert_err(
+        suite.grade_submission(&worker1, 1, None, 1000),
+        "Not authorized",
+    );
+    assert_err(
+        suite.grade_submission(&grader, 1, None, 1001),
+        "Invalid grade",
+    );
+
+    suite.grade_submission(&grader, 1, None, 700).unwrap();
+    assert_eq!(suite.balance(&worker1), INITIAL - 30);
+    assert!(suite.pending_reviews().unwrap().is_empty());
+
+    let res: WorkerTicketsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            suite.ticket_manager.clone(),
+            &TicketQueryMsg::QueryWorkerTickets {
+                worker: worker1.to_string(),
+            },
+        )
+        .unwrap();
+    let outcome = res.assignments[0].outcome.clone().unwrap();
+    assert!(outcome.correct);
+    assert_eq!(outcome.slash_perc.u128(), 300);
+    let grade = outcome.grade.unwrap();
+    assert_eq!(grade.score, 700);
+    assert_eq!(grade.grader, Some(grader.to_string()));
+
+    assert_err(
+        suite.grade_submission(&grader, 1, None, 700),
+        "waiting for the grade",
+    );
+}
+
+#[test]
+fn ungraded_submission_is_auto_approved() {
+    let mut suite = suite();
+    let grader = suite.addr("grader");
+    let keeper = suite.addr("keeper");
+    let workers = [suite.addr("worker1"), suite.addr("worker2")];
+    // No late result is accepted, the no-show is reportable right after the close.
+    let msg = AddTicketMsg {
+        lateness: LatenessPenalty::Flat { cutoff: Some(0) },
+        ..manual_ticket(&suite, 2)
+    };
+    suite.add_ticket(msg).unwrap();
+    suite
+        .run_auction(1, &[(&workers[0], 50), (&workers[1], 60)])
+        .unwrap();
+
+    suite.submit_result(&workers[0], 1, "a").unwrap();
+    suite.submit_result(&workers[1], 1, "b").unwrap();
+    // The worker must be given with several submissions waiting.
+    assert_err(
+        suite.grade_submission(&grader, 1, None, 0),
+        "waiting for the grade",
+    );
+    suite
+        .grade_submission(&grader, 1, Some(&workers[1]), 0)
+        .unwrap();
+    assert_eq!(suite.balance(&workers[1]), INITIAL - COLLATERAL as u128);
+
+    // The no-show report leaves the submission for the review.
+    suite.advance_time(WORK_DURATION + 1);
+    assert_err(suite.report_no_show(1), "already assessed");
+
+    assert_err(suite.auto_approve(&keeper, 1), "Review deadline");
+    suite.advance_time(REVIEW_PERIOD);
+    suite.auto_approve(&keeper, 1).unwrap();
+    assert_eq!(suite.balance(&workers[0]), INITIAL);
+    assert_err(suite.auto_approve(&keeper, 1), "waiting for the grade");
+}
+
+#[test]
+fn grader_role_is_managed_by_admin() {
+    let mut suite = suite();
+    let grader = suite.addr("grader");
+    let worker1 = suite.addr("worker1");
+
+    let graders: Vec<Addr> = suite
+        .app
+        .wrap()
+        .query_wasm_smart(suite.admin_board.clone(), &AdminQueryMsg::QueryGraders {})
+        .unwrap();
+    assert_eq!(graders, vec![grader.clone()]);
+
+    // The ticket assessed against the expected result is not graded.
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+    assert_err(
+        suite.grade_submission(&grader, 1, None, 1000),
+        "waiting for the grade",
+    );
+
+    suite
+        .execute_admin(AdminExecuteMsg::RemoveGrader {
+            address: grader.to_string(),
+        })
+        .unwrap();
+    assert_err(
+        suite.grade_submission(&grader, 1, None, 1000),
+        "Not authorized",
+    );
+}
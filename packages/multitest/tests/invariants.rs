@@ -0,0 +1,310 @@
+// Property-based scenarios over the whole suite.
+//
+// Random sequences of worker, admin & keeper actions are run against the deployed contracts.
+// Failing actions are fine (the chain rejects them), but after every step the invariants
+// on the collateral, the disputes, the fee pool & the ticket assignment must hold.
+use std::collections::HashMap;
+
+use cosmwasm_std::{Addr, Uint128};
+use proptest::prelude::*;
+use proptest::sample::Index;
+use ticket_auction::admin_board::ExecuteMsg as AdminExecuteMsg;
+use ticket_auction::collateral_manager::HeldSlash;
+use ticket_auction::ticket_manager::{AddTicketMsg, UpdateTicketMsg};
+use ticket_auction_multitest::{Suite, SuiteBuilder};
+
+const INITIAL: u128 = 100_000;
+const WORKERS: [&str; 3] = ["worker1", "worker2", "worker3"];
+const TICKETS: u64 = 3;
+const MAX_SLOTS: u32 = 3;
+const KEEPER_REWARD: u128 = 2;
+const BET_FEE: u128 = 1;
+const DISPUTE_WINDOW: u64 = 40_000;
+const DISPUTE_BOND: u128 = 20;
+
+#[derive(Clone, Debug)]
+enum Op {
+    LockStake {
+        worker: usize,
+        tid: u64,
+    },
+    PlaceBet {
+        worker: usize,
+        tid: u64,
+        amount: u64,
+    },
+    AdvanceTime(u64),
+    DecideWinningBet {
+        tid: u64,
+    },
+    SettleExpired,
+    SubmitResult {
+        worker: usize,
+        tid: u64,
+        correct: bool,
+    },
+    ReportNoShow {
+        tid: u64,
+    },
+    UpdateCollateral {
+        tid: u64,
+        collateral: u64,
+    },
+    // The held slashes are picked by the index, so that most of the disputes are accepted.
+    OpenDispute(Index),
+    ResolveDispute {
+        held: Index,
+        uphold: bool,
+    },
+    FinalizeSlash(Index),
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+    let worker = 0..WORKERS.len();
+    let tid = 1..=TICKETS;
+    prop_oneof![
+        1 => (worker.clone(), tid.clone()).prop_map(|(worker, tid)| Op::LockStake { worker, tid }),
+        5 => (worker.clone(), tid.clone(), 0..150u64)
+            .prop_map(|(worker, tid, amount)| Op::PlaceBet { worker, tid, amount }),
+        2 => (0..600u64).prop_map(Op::AdvanceTime),
+        1 => (0..50_000u64).prop_map(Op::AdvanceTime),
+        1 => tid.clone().prop_map(|tid| Op::DecideWinningBet { tid }),
+        1 => Just(Op::SettleExpired),
+        2 => (worker, tid.clone(), any::<bool>())
+            .prop_map(|(worker, tid, correct)| Op::SubmitResult { worker, tid, correct }),
+        1 => tid.clone().prop_map(|tid| Op::ReportNoShow { tid }),
+        1 => (tid, 50..200u64)
+            .prop_map(|(tid, collateral)| Op::UpdateCollateral { tid, collateral }),
+        3 => any::<Index>().prop_map(Op::OpenDispute),
+        1 => (any::<Index>(), any::<bool>())
+            .prop_map(|(held, uphold)| Op::ResolveDispute { held, uphold }),
+        1 => any::<Index>().prop_map(Op::FinalizeSlash),
+    ]
+}
+
+// What the test knows about the scenario so far.
+#[derive(Default)]
+struct Model {
+    // Amount sent by the worker when locking the stake on the ticket.
+    stakes: HashMap<(u64, Addr), u128>,
+    // Workers assigned on the ticket.
+    assigned: HashMap<u64, Vec<Addr>>,
+}
+
+fn setup(slots: &[u32]) -> (Suite, Vec<Addr>, Addr) {
+    let mut builder = SuiteBuilder::new().with_funds("keeper", INITIAL);
+    for name in WORKERS {
+        builder = builder.with_funds(name, INITIAL);
+    }
+    let mut suite = builder.build();
+
+    for (tid, slots) in (1..=TICKETS).zip(slots) {
+        let msg = AddTicketMsg {
+            slots: *slots,
+            ..suite.ticket(tid, 100, "42")
+        };
+        suite.add_ticket(msg).unwrap();
+    }
+    suite
+        .execute_admin(AdminExecuteMsg::SetKeeperReward {
+            amount: Uint128::from(KEEPER_REWARD),
+            bet_fee: Uint128::from(BET_FEE),
+        })
+        .unwrap();
+    let arbiter = suite.addr("arbiter");
+    suite.add_arbiter(&arbiter).unwrap();
+    suite
+        .set_dispute_params(DISPUTE_WINDOW, DISPUTE_BOND)
+        .unwrap();
+
+    let keeper = suite.addr("keeper");
+    let workers = WORKERS.iter().map(|name| suite.addr(name)).collect();
+    (suite, workers, keeper)
+}
+
+fn lock_stake(suite: &mut Suite, model: &mut Model, worker: &Addr, tid: u64) {
+    let Ok(ticket) = suite.ticket_info(tid) else {
+        return;
+    };
+    let amount = ticket.collateral as u128;
+    if suite.lock_stake(worker, tid, amount).is_ok() {
+        model.stakes.insert((tid, worker.clone()), amount);
+    }
+}
+
+// Slashes of the workers held in the dispute window.
+fn held_slashes(suite: &Suite, workers: &[Addr]) -> Vec<HeldSlash> {
+    workers
+        .iter()
+        .flat_map(|worker| {
+            (1..=TICKETS).filter_map(move |tid| suite.held_slash(tid, worker).unwrap())
+        })
+        .collect()
+}
+
+fn pick_held_slash(suite: &Suite, workers: &[Addr], index: Index) -> Option<HeldSlash> {
+    let held = held_slashes(suite, workers);
+    match held.is_empty() {
+        true => None,
+        false => Some(index.get(&held).clone()),
+    }
+}
+
+fn apply(suite: &mut Suite, model: &mut Model, workers: &[Addr], keeper: &Addr, op: Op) {
+    // Rejected transactions leave no trace on the chain, so the results are ignored.
+    match op {
+        Op::LockStake { worker, tid } => lock_stake(suite, model, &workers[worker], tid),
+        Op::PlaceBet {
+            worker,
+            tid,
+            amount,
+        } => {
+            // The worker stakes first, so that most of the bets are accepted.
+            let worker = &workers[worker];
+            if !model.stakes.contains_key(&(tid, worker.clone())) {
+                lock_stake(suite, model, worker, tid);
+            }
+            let _ = suite.place_bet_with_fee(worker, tid, amount, BET_FEE);
+        }
+        Op::AdvanceTime(seconds) => suite.advance_time(seconds),
+        Op::DecideWinningBet { tid } => {
+            let _ = suite.decide_winning_bet(tid);
+        }
+        Op::SettleExpired => {
+            let _ = suite.settle_expired(keeper, None);
+        }
+        Op::SubmitResult {
+            worker,
+            tid,
+            correct,
+        } => {
+            // The worker is picked among the assignees, so that most of the results are accepted.
+            let assignees = suite.ticket_workers(tid).unwrap_or_default();
+            let worker = match assignees.is_empty() {
+                true => &workers[worker],
+                false => &assignees[worker % assignees.len()],
+            };
+            let result = if correct { "42" } else { "0" };
+            let _ = suite.submit_result(worker, tid, result);
+        }
+        Op::ReportNoShow { tid } => {
+            let _ = suite.report_no_show(tid);
+        }
+        Op::UpdateCollateral { tid, collateral } => {
+            let _ = suite.update_ticket(UpdateTicketMsg {
+                id: tid,
+                bet_finish_timestamp: None,
+                close_timestamp: None,
+                result: None,
+                collateral: Some(collateral),
+                auction: None,
+                metadata: None,
+                slots: None,
+                assessment: None,
+                matcher: None,
+                lateness: None,
+                resubmission: None,
+            });
+        }
+        Op::OpenDispute(index) => {
+            let Some(held) = pick_held_slash(suite, workers, index) else {
+                return;
+            };
+            let _ =
+                suite.dispute_assessment(&held.worker, held.tid, "ipfs://evidence", DISPUTE_BOND);
+        }
+        Op::ResolveDispute { held, uphold } => {
+            let Some(held) = pick_held_slash(suite, workers, held) else {
+                return;
+            };
+            let arbiter = suite.addr("arbiter");
+            let _ = suite.resolve_dispute(&arbiter, held.tid, &held.worker, uphold);
+        }
+        Op::FinalizeSlash(index) => {
+            let Some(held) = pick_held_slash(suite, workers, index) else {
+                return;
+            };
+            let _ = suite.finalize_slash(keeper, held.tid, &held.worker);
+        }
+    }
+}
+
+fn check_invariants(suite: &Suite, model: &mut Model, workers: &[Addr]) {
+    // 1. collateral-manager holds exactly the active stakes, the slashed amounts,
+    //    the held slashes & the dispute bonds.
+    let treasury = suite.treasury().unwrap();
+    assert_eq!(
+        suite.collateral_balance(),
+        (treasury.locked + treasury.slashed + treasury.held).u128()
+    );
+
+    let mut total_locked = 0u128;
+    let mut total_held = 0u128;
+    for worker in workers {
+        let locked = suite
+            .worker_stakes(worker)
+            .unwrap()
+            .into_iter()
+            .map(|tid| model.stakes[&(tid, worker.clone())])
+            .sum::<u128>();
+        total_locked += locked;
+
+        // The slashes held in the dispute window & the bonds of the open disputes.
+        let held = held_slashes(suite, std::slice::from_ref(worker))
+            .into_iter()
+            .map(|held| (held.amount + held.dispute.map_or(Uint128::zero(), |d| d.bond)).u128())
+            .sum::<u128>();
+        total_held += held;
+
+        // 2. No worker gets back more than the stake & the bond. (no reward for the workers)
+        assert!(suite.balance(worker) + locked + held <= INITIAL);
+    }
+    assert_eq!(treasury.locked.u128(), total_locked);
+    assert_eq!(treasury.held.u128(), total_held);
+
+    // 4. auction-manager holds exactly the fee pool, the keeper is only paid out of the bet fees.
+    let fee_pool = suite.fee_pool().unwrap().fee_pool.u128();
+    assert_eq!(suite.balance(&suite.auction_manager), fee_pool);
+
+    // 3. Each ticket has at most "slots" distinct assigned workers, who have staked on it
+    //    & never change once assigned.
+    for tid in 1..=TICKETS {
+        let assignees = suite.ticket_workers(tid).unwrap();
+        let slots = suite.ticket_info(tid).unwrap().slots;
+        assert!(assignees.len() <= slots as usize);
+        for (i, worker) in assignees.iter().enumerate() {
+            assert!(!assignees[..i].contains(worker));
+            assert!(model.stakes.contains_key(&(tid, worker.clone())));
+        }
+        if assignees.is_empty() {
+            continue;
+        }
+        let assigned = model
+            .assigned
+            .entry(tid)
+            .or_insert_with(|| assignees.clone());
+        assert_eq!(*assigned, assignees);
+    }
+    // The pairs list the same assignees. (tid 0 is the placeholder)
+    let pairs = suite.ticket_worker_pairs().unwrap();
+    for pair in pairs.into_iter().filter(|p| p.tid != 0) {
+        assert!(model.assigned[&pair.tid].contains(&Addr::unchecked(pair.worker)));
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn collateral_is_conserved(
+        slots in prop::collection::vec(1..=MAX_SLOTS, TICKETS as usize),
+        ops in prop::collection::vec(op_strategy(), 1..60),
+    ) {
+        let (mut suite, workers, keeper) = setup(&slots);
+        let mut model = Model::default();
+        for op in ops {
+            apply(&mut suite, &mut model, &workers, &keeper, op);
+            check_invariants(&suite, &mut model, &workers);
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use ticket_auction::ticket_manager::{
+    AddTicketMsg, LatenessPenalty, LatenessTier, DEFAULT_LATENESS_CUTOFF,
+};
+use ticket_auction_multitest::{assert_err, suite_with_workers, Suite, COLLATERAL, INITIAL};
+
+const HOUR: u64 = 3_600;
+
+fn late_ticket(suite: &Suite, id: u64, lateness: LatenessPenalty) -> AddTicketMsg {
+    AddTicketMsg {
+        lateness,
+        ..suite.ticket(id, COLLATERAL, "42")
+    }
+}
+
+fn tier(after: u64, slash_perc: u64) -> LatenessTier {
+    LatenessTier { after, slash_perc }
+}
+
+// Slash(per mille) of the lateness penalty "late_by" seconds after the close.
+fn penalty(suite: &Suite, tid: u64, late_by: u64) -> u128 {
+    let close = suite.ticket_info(tid).unwrap().close_timestamp;
+    let res = suite.lateness_penalty(tid, Some(close + late_by)).unwrap();
+    assert_eq!(res.late_by, late_by);
+    assert!(!res.rejected);
+    res.slash_perc.u128()
+}
+
+fn advance_past_close(suite: &mut Suite, tid: u64, late_by: u64) {
+    let close = suite.ticket_info(tid).unwrap().close_timestamp;
+    suite.advance_time(close + late_by - suite.block_time());
+}
+
+#[test]
+fn linear_penalty_grows_per_started_hour() {
+    let mut suite = suite_with_workers(2);
+    let worker1 = suite.addr("worker1");
+    let lateness = LatenessPenalty::Linear {
+        per_hour: 100,
+        max: 400,
+        cutoff: None,
+    };
+    suite.add_ticket(late_ticket(&suite, 1, lateness)).unwrap();
+
+    // The flat 30% of the default ticket, until the default cutoff.
+    suite.add_ticket(suite.ticket(2, COLLATERAL, "42")).unwrap();
+    assert_eq!(penalty(&suite, 2, 1), 300);
+    assert_eq!(penalty(&suite, 2, DEFAULT_LATENESS_CUTOFF), 300);
+    let close = suite.ticket_info(2).unwrap().close_timestamp;
+    let res = suite
+        .lateness_penalty(2, Some(close + DEFAULT_LATENESS_CUTOFF + 1))
+        .unwrap();
+    assert!(res.rejected);
+
+    assert_eq!(penalty(&suite, 1, 0), 0);
+    assert_eq!(penalty(&suite, 1, 1), 100);
+    assert_eq!(penalty(&suite, 1, HOUR), 100);
+    assert_eq!(penalty(&suite, 1, HOUR + 1), 200);
+    assert_eq!(penalty(&suite, 1, 10 * HOUR), 400);
+    // The block time by default.
+    let res = suite.lateness_penalty(1, None).unwrap();
+    assert_eq!(res.at_time, suite.block_time());
+    assert_eq!(res.late_by, 0);
+
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+    advance_past_close(&mut suite, 1, 2 * HOUR + 1);
+    suite.submit_result(&worker1, 1, "42").unwrap();
+    let assessment = suite
+        .submission(1, &worker1)
+        .unwrap()
+        .unwrap()
+        .assessment
+        .unwrap();
+    assert!(assessment.correct && !assessment.on_time);
+    assert_eq!(assessment.slash_perc.u128(), 300);
+    assert_eq!(suite.balance(&worker1), INITIAL - 30);
+}
+
+#[test]
+fn stepped_penalty_applies_last_tier_passed() {
+    let mut suite = suite_with_workers(2);
+    let worker1 = suite.addr("worker1");
+    let lateness = LatenessPenalty::Stepped {
+        tiers: vec![tier(0, 100), tier(HOUR, 300), tier(24 * HOUR, 600)],
+        cutoff: Some(48 * HOUR),
+    };
+    suite.add_ticket(late_ticket(&suite, 1, lateness)).unwrap();
+
+    assert_eq!(penalty(&suite, 1, 0), 0);
+    assert_eq!(penalty(&suite, 1, 1), 100);
+    assert_eq!(penalty(&suite, 1, HOUR), 100);
+    assert_eq!(penalty(&suite, 1, HOUR + 1), 300);
+    assert_eq!(penalty(&suite, 1, 30 * HOUR), 600);
+
+    // The wrong result adds the 50% slash.
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+    advance_past_close(&mut suite, 1, 2 * HOUR);
+    suite.submit_result(&worker1, 1, "41").unwrap();
+    assert_eq!(suite.balance(&worker1), INITIAL - 80);
+}
+
+#[test]
+fn cutoff_rejects_late_submission_as_no_show() {
+    let mut suite = suite_with_workers(2);
+    let workers = [suite.addr("worker1"), suite.addr("worker2")];
+    let lateness = LatenessPenalty::Linear {
+        per_hour: 100,
+        max: 400,
+        cutoff: Some(600),
+    };
+    let msg = AddTicketMsg {
+        slots: 2,
+        ..late_ticket(&suite, 1, lateness)
+    };
+    suite.add_ticket(msg).unwrap();
+    suite
+        .run_auction(1, &[(&workers[0], 50), (&workers[1], 60)])
+        .unwrap();
+
+    // Accepted with the slash until the cutoff, the no-show is not reportable meanwhile.
+    advance_past_close(&mut suite, 1, 600);
+    suite.submit_result(&workers[0], 1, "42").unwrap();
+    assert_eq!(suite.balance(&workers[0]), INITIAL - 10);
+    assert_err(suite.report_no_show(1), "not closed");
+
+    suite.advance_time(1);
+    let res = suite.lateness_penalty(1, None).unwrap();
+    assert!(res.rejected);
+    assert_eq!(res.slash_perc.u128(), 1000);
+    assert_err(
+        suite.submit_result(&workers[1], 1, "42"),
+        "Submission deadline passed",
+    );
+    suite.report_no_show(1).unwrap();
+    assert_eq!(suite.balance(&workers[1]), INITIAL - COLLATERAL as u128);
+    assert_eq!(
+        suite.worker_reputation(&workers[1]).unwrap().stats.no_shows,
+        1
+    );
+}
+
+#[test]
+fn lateness_penalty_is_validated() {
+    let mut suite = suite_with_workers(2);
+    let invalid = [
+        LatenessPenalty::Linear {
+            per_hour: 0,
+            max: 500,
+            cutoff: None,
+        },
+        LatenessPenalty::Linear {
+            per_hour: 100,
+            max: 1001,
+            cutoff: None,
+        },
+        LatenessPenalty::Stepped {
+            tiers: vec![],
+            cutoff: None,
+        },
+        LatenessPenalty::Stepped {
+            tiers: vec![tier(HOUR, 300), tier(HOUR, 500)],
+            cutoff: None,
+        },
+        LatenessPenalty::Stepped {
+            tiers: vec![tier(0, 300), tier(HOUR, 200)],
+            cutoff: None,
+        },
+        LatenessPenalty::Stepped {
+            tiers: vec![tier(0, 1001)],
+            cutoff: None,
+        },
+    ];
+    for lateness in invalid {
+        assert_err(
+            suite.add_ticket(late_ticket(&suite, 1, lateness)),
+            "Invalid lateness penalty",
+        );
+    }
+}
@@ -0,0 +1,84 @@
+// The messages are snake_case (cw_serde), the legacy PascalCase names are still accepted.
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, CosmosMsg, QueryRequest, WasmMsg, WasmQuery,
+};
+use cw_multi_test::Executor;
+use ticket_auction::admin_board::{ChildKind, ExecuteMsg as AdminExecuteMsg};
+use ticket_auction::auction_manager::QueryMsg as AuctionQueryMsg;
+use ticket_auction::ticket_manager::{QueryMsg as TicketQueryMsg, TicketInfoResponse};
+use ticket_auction_multitest::SuiteBuilder;
+
+#[test]
+fn messages_are_snake_case() {
+    let msg = to_json_binary(&TicketQueryMsg::QueryTicketInfo { tid: 1 }).unwrap();
+    assert_eq!(msg.as_slice(), br#"{"query_ticket_info":{"tid":1}}"#);
+
+    let msg = to_json_binary(&AuctionQueryMsg::BetAvailableTickets {}).unwrap();
+    assert_eq!(msg.as_slice(), br#""bet_available_tickets""#);
+}
+
+#[test]
+fn legacy_variants_are_accepted() {
+    let legacy: TicketQueryMsg = from_json(br#"{"QueryTicketInfo":{"tid":1}}"#).unwrap();
+    assert_eq!(legacy, TicketQueryMsg::QueryTicketInfo { tid: 1 });
+
+    let legacy: AuctionQueryMsg = from_json(br#""BetAvailableTickets""#).unwrap();
+    assert_eq!(legacy, AuctionQueryMsg::BetAvailableTickets {});
+
+    let legacy: AdminExecuteMsg = from_json(br#"{"RemoveTicket":{"tid":1}}"#).unwrap();
+    assert_eq!(legacy, AdminExecuteMsg::RemoveTicket { tid: 1 });
+}
+
+#[test]
+fn new_variants_are_snake_case_only() {
+    let legacy = from_json::<AdminExecuteMsg>(
+        br#"{"MigrateChild":{"contract":"TicketManager","new_code_id":7,"msg":"e30="}}"#,
+    );
+    assert!(legacy.is_err());
+
+    let current: AdminExecuteMsg = from_json(
+        br#"{"migrate_child":{"contract":"ticket_manager","new_code_id":7,"msg":"e30="}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        current,
+        AdminExecuteMsg::MigrateChild {
+            contract: ChildKind::TicketManager,
+            new_code_id: 7,
+            msg: Binary::from(b"{}"),
+        }
+    );
+}
+
+#[test]
+fn legacy_messages_work_on_chain() {
+    let mut suite = SuiteBuilder::new().build();
+    let finish = suite.block_time() + 1000;
+    let legacy = format!(
+        r#"{{"AddTicket":{{"id":1,"bet_finish_timestamp":{},"close_timestamp":{},"result":"42","collateral":100}}}}"#,
+        finish,
+        finish + 1000
+    );
+    suite
+        .app
+        .execute(
+            suite.admin.clone(),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: suite.admin_board.to_string(),
+                msg: Binary::from(legacy.as_bytes()),
+                funds: vec![],
+            }),
+        )
+        .unwrap();
+
+    let ticket: TicketInfoResponse = suite
+        .app
+        .wrap()
+        .query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: suite.ticket_manager.to_string(),
+            msg: Binary::from(br#"{"QueryTicketInfo":{"tid":1}}"#),
+        }))
+        .unwrap();
+    assert_eq!(ticket, suite.ticket_info(1).unwrap());
+    assert_eq!(ticket.bet_finish_timestamp, finish);
+}
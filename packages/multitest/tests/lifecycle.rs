@@ -0,0 +1,186 @@
+use cw_multi_test::Executor;
+use ticket_auction::ticket_manager::DEFAULT_LATENESS_CUTOFF;
+use ticket_auction_multitest::{Suite, SuiteBuilder, WORK_DURATION};
+
+const INITIAL: u128 = 10_000;
+const COLLATERAL: u64 = 100;
+
+fn suite() -> Suite {
+    SuiteBuilder::new()
+        .with_funds("worker1", INITIAL)
+        .with_funds("worker2", INITIAL)
+        .with_funds("keeper", INITIAL)
+        .build()
+}
+
+#[test]
+fn correct_submission_on_time_releases_whole_stake() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    let worker2 = suite.addr("worker2");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+
+    let winner = suite
+        .run_lifecycle(1, &[(&worker1, 50), (&worker2, 30)], "42")
+        .unwrap();
+
+    // The lowest bet wins & gets the stake back.
+    assert_eq!(winner, worker2);
+    assert_eq!(suite.balance(&worker2), INITIAL);
+    assert!(!suite.stake_status(1, &worker2).unwrap());
+
+    // The stake of the failed bet is still locked.
+    assert_eq!(suite.balance(&worker1), INITIAL - COLLATERAL as u128);
+    assert!(suite.stake_status(1, &worker1).unwrap());
+    assert_eq!(suite.collateral_balance(), COLLATERAL as u128);
+}
+
+#[test]
+fn wrong_result_is_slashed_by_half() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+
+    suite.run_lifecycle(1, &[(&worker1, 50)], "41").unwrap();
+
+    assert_eq!(suite.balance(&worker1), INITIAL - 50);
+    assert_eq!(suite.collateral_balance(), 50);
+
+    let reputation = suite.worker_reputation(&worker1).unwrap();
+    assert_eq!(reputation.stats.completed, 1);
+    assert_eq!(reputation.stats.wrong, 1);
+    assert_eq!(reputation.stats.total_slashed.u128(), 50);
+    assert_eq!(reputation.score, 500);
+}
+
+#[test]
+fn late_wrong_result_is_slashed_for_both() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+    suite.advance_time(WORK_DURATION + 1);
+    suite.submit_result(&worker1, 1, "41").unwrap();
+
+    // 50% for the wrong result + 30% for the delay
+    assert_eq!(suite.balance(&worker1), INITIAL - 80);
+    assert_eq!(suite.worker_reputation(&worker1).unwrap().stats.late, 1);
+}
+
+#[test]
+fn no_show_loses_whole_stake() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+
+    // Not reportable while the late result is still accepted.
+    suite.report_no_show(1).unwrap_err();
+    suite.advance_time(WORK_DURATION + DEFAULT_LATENESS_CUTOFF);
+    suite.report_no_show(1).unwrap_err();
+
+    suite.advance_time(1);
+    suite.report_no_show(1).unwrap();
+
+    assert_eq!(suite.balance(&worker1), INITIAL - COLLATERAL as u128);
+    assert!(!suite.stake_status(1, &worker1).unwrap());
+    let reputation = suite.worker_reputation(&worker1).unwrap();
+    assert_eq!(reputation.stats.no_shows, 1);
+    assert_eq!(reputation.score, 0);
+
+    // The result can't be submitted after the no-show.
+    suite.submit_result(&worker1, 1, "42").unwrap_err();
+}
+
+#[test]
+fn only_assignee_submits_once() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    let worker2 = suite.addr("worker2");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+    suite
+        .run_auction(1, &[(&worker1, 50), (&worker2, 60)])
+        .unwrap();
+
+    suite.submit_result(&worker2, 1, "42").unwrap_err();
+    suite.submit_result(&worker1, 1, "42").unwrap();
+    suite.submit_result(&worker1, 1, "42").unwrap_err();
+    assert_eq!(suite.balance(&worker1), INITIAL);
+}
+
+#[test]
+fn stake_must_match_ticket_collateral() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+
+    suite.lock_stake(&worker1, 1, 99).unwrap_err();
+    suite.lock_stake(&worker1, 1, 101).unwrap_err();
+    suite.lock_stake(&worker1, 1, 100).unwrap();
+    suite.lock_stake(&worker1, 1, 100).unwrap_err();
+
+    assert_eq!(suite.collateral_balance(), 100);
+    assert!(suite.stake_status(1, &worker1).unwrap());
+}
+
+#[test]
+fn bet_requires_stake() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+
+    suite.place_bet(&worker1, 1, 50).unwrap_err();
+    suite.lock_stake(&worker1, 1, 100).unwrap();
+    suite.place_bet(&worker1, 1, 50).unwrap();
+
+    let bets = suite.active_bets(1).unwrap();
+    assert_eq!(bets.len(), 1);
+    assert_eq!(bets[0].worker, worker1);
+}
+
+#[test]
+fn only_admin_manages_tickets() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    let msg = suite.ticket(1, COLLATERAL, "42");
+
+    suite
+        .app
+        .execute_contract(
+            worker1,
+            suite.admin_board.clone(),
+            &ticket_auction::admin_board::ExecuteMsg::AddTicket(msg.clone()),
+            &[],
+        )
+        .unwrap_err();
+    suite.add_ticket(msg).unwrap();
+    suite.remove_ticket(1).unwrap();
+    suite.ticket_info(1).unwrap_err();
+}
+
+#[test]
+fn dashboard_follows_worker_activity() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+    suite.add_ticket(suite.ticket(2, COLLATERAL, "43")).unwrap();
+
+    suite.lock_stake(&worker1, 2, 100).unwrap();
+    suite.place_bet(&worker1, 2, 70).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+
+    let dashboard = suite.worker_dashboard(&worker1).unwrap();
+    assert_eq!(dashboard.staked_tickets, vec![2, 1]);
+    assert_eq!(dashboard.active_bets.len(), 1);
+    assert_eq!(dashboard.active_bets[0].tid, 2);
+    assert_eq!(dashboard.pending_submissions, vec![1]);
+    assert!(dashboard.outcomes.is_empty());
+
+    suite.submit_result(&worker1, 1, "42").unwrap();
+    let dashboard = suite.worker_dashboard(&worker1).unwrap();
+    assert_eq!(dashboard.staked_tickets, vec![2]);
+    assert!(dashboard.pending_submissions.is_empty());
+    assert_eq!(dashboard.outcomes.len(), 1);
+    assert!(dashboard.outcomes[0].correct);
+}
@@ -0,0 +1,168 @@
+use std::str::FromStr;
+
+use cosmwasm_std::Decimal;
+use ticket_auction::matcher::{normalize, sha256_hex, ResultMatcher, MAX_ALTERNATIVES};
+use ticket_auction::ticket_manager::{AddTicketMsg, AssessmentMode};
+use ticket_auction_multitest::{assert_err, suite_with_workers, Suite, COLLATERAL, INITIAL};
+
+fn matched_ticket(suite: &Suite, id: u64, matcher: ResultMatcher, result: &str) -> AddTicketMsg {
+    AddTicketMsg {
+        matcher,
+        ..suite.ticket(id, COLLATERAL, result)
+    }
+}
+
+// Run the ticket through the auction & the submission, returning if it is assessed as correct.
+fn assess(
+    suite: &mut Suite,
+    id: u64,
+    matcher: ResultMatcher,
+    expected: &str,
+    submitted: &str,
+) -> bool {
+    let worker1 = suite.addr("worker1");
+    suite
+        .add_ticket(matched_ticket(suite, id, matcher, expected))
+        .unwrap();
+    suite.run_auction(id, &[(&worker1, 50)]).unwrap();
+    suite.submit_result(&worker1, id, submitted).unwrap();
+    suite
+        .submission(id, &worker1)
+        .unwrap()
+        .unwrap()
+        .assessment
+        .unwrap()
+        .correct
+}
+
+// Assess the (matcher, expected, submitted, correct) cases on the tickets 1, 2, ...
+fn assert_cases(suite: &mut Suite, cases: &[(ResultMatcher, &str, &str, bool)]) {
+    for (i, (matcher, expected, submitted, correct)) in cases.iter().enumerate() {
+        let id = i as u64 + 1;
+        assert_eq!(
+            assess(suite, id, matcher.clone(), expected, submitted),
+            *correct,
+            "{:?} {:?} {:?}",
+            matcher,
+            expected,
+            submitted
+        );
+    }
+}
+
+#[test]
+fn text_matchers_ignore_formatting() {
+    let mut suite = suite_with_workers(1);
+    let worker1 = suite.addr("worker1");
+    assert_cases(
+        &mut suite,
+        &[
+            (ResultMatcher::Exact, "Paris", "paris", false),
+            (ResultMatcher::CaseInsensitive, "Paris", "PARIS", true),
+            (ResultMatcher::CaseInsensitive, "Paris", " paris", false),
+            (ResultMatcher::Trimmed, "Paris", " Paris\n", true),
+            (ResultMatcher::Trimmed, "Paris", "paris", false),
+        ],
+    );
+    // 3 of 5 wrong, the half of the stake slashed each.
+    assert_eq!(suite.balance(&worker1), INITIAL - 3 * 50);
+}
+
+#[test]
+fn value_matchers_accept_equivalent_results() {
+    let mut suite = suite_with_workers(1);
+    let numeric = ResultMatcher::Numeric {
+        tolerance: Decimal::from_str("0.01").unwrap(),
+    };
+    let one_of = ResultMatcher::OneOf {
+        alternatives: vec!["NYC".to_string(), "New York City".to_string()],
+    };
+    assert_cases(
+        &mut suite,
+        &[
+            (numeric.clone(), "3.14", "3.1416", true),
+            (numeric.clone(), "-2", " -2.005 ", true),
+            (numeric.clone(), "3.14", "3.16", false),
+            (numeric, "3.14", "pi", false),
+            (one_of.clone(), "New York", "New York", true),
+            (one_of.clone(), "New York", "NYC", true),
+            (one_of, "New York", "nyc", false),
+            (ResultMatcher::Pattern, "ipfs://*", "ipfs://Qm1", true),
+            (ResultMatcher::Pattern, "v?.*.0", "v1.12.0", true),
+            (ResultMatcher::Pattern, "v?.*.0", "v10.1.0", false),
+        ],
+    );
+}
+
+#[test]
+fn normalized_hash_hides_the_answer() {
+    let mut suite = suite_with_workers(1);
+    let hash = sha256_hex(normalize("  Mount   Everest ").as_bytes());
+    assert_eq!(hash, sha256_hex(b"mount everest"));
+
+    assert_cases(
+        &mut suite,
+        &[
+            (
+                ResultMatcher::NormalizedHash,
+                &hash,
+                "MOUNT everest\n",
+                true,
+            ),
+            (ResultMatcher::NormalizedHash, &hash, "K2", false),
+        ],
+    );
+    // Only the hash is on the chain.
+    assert_eq!(suite.ticket_info(1).unwrap().result, hash);
+}
+
+#[test]
+fn matcher_is_validated_with_expected_result() {
+    let mut suite = suite_with_workers(1);
+    let invalid = [
+        (
+            ResultMatcher::Numeric {
+                tolerance: Decimal::zero(),
+            },
+            "forty-two",
+        ),
+        (
+            ResultMatcher::OneOf {
+                alternatives: vec![],
+            },
+            "42",
+        ),
+        (
+            ResultMatcher::OneOf {
+                alternatives: vec!["42".to_string(); MAX_ALTERNATIVES + 1],
+            },
+            "42",
+        ),
+        (ResultMatcher::Pattern, ""),
+        (ResultMatcher::NormalizedHash, "42"),
+    ];
+    for (matcher, result) in invalid {
+        assert_err(
+            suite.add_ticket(matched_ticket(&suite, 1, matcher, result)),
+            "Invalid result matcher",
+        );
+    }
+
+    // The matcher is compared field by field on the partial credit.
+    let msg = AddTicketMsg {
+        assessment: AssessmentMode::PartialCredit,
+        ..matched_ticket(
+            &suite,
+            1,
+            ResultMatcher::CaseInsensitive,
+            r#"{"city":"Paris","country":"France"}"#,
+        )
+    };
+    suite.add_ticket(msg).unwrap();
+    let worker1 = suite.addr("worker1");
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+    suite
+        .submit_result(&worker1, 1, r#"{"city":"PARIS","country":"france"}"#)
+        .unwrap();
+    assert_eq!(suite.balance(&worker1), INITIAL);
+}
@@ -0,0 +1,151 @@
+use ticket_auction::ticket_manager::{
+    AddTicketMsg, TicketMetadata, TicketsResponse, UpdateTicketMsg, MAX_TAGS, MAX_TITLE_LEN,
+};
+use ticket_auction::user_board::QueryMsg as UserQueryMsg;
+use ticket_auction_multitest::{Suite, SuiteBuilder};
+
+const COLLATERAL: u64 = 100;
+const HASH: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+fn suite() -> Suite {
+    SuiteBuilder::new().build()
+}
+
+fn metadata(category: &str, tags: &[&str]) -> TicketMetadata {
+    TicketMetadata {
+        title: "Label the images".to_string(),
+        description: "Label every image of the set.".to_string(),
+        category: Some(category.to_string()),
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        content_uri: Some("ipfs://bafybeigdyrzt".to_string()),
+        content_hash: Some(HASH.to_string()),
+    }
+}
+
+fn ticket_with(suite: &Suite, id: u64, metadata: TicketMetadata) -> AddTicketMsg {
+    AddTicketMsg {
+        metadata,
+        ..suite.ticket(id, COLLATERAL, "42")
+    }
+}
+
+fn update_metadata(id: u64, metadata: TicketMetadata) -> UpdateTicketMsg {
+    UpdateTicketMsg {
+        id,
+        bet_finish_timestamp: None,
+        close_timestamp: None,
+        result: None,
+        collateral: None,
+        auction: None,
+        metadata: Some(metadata),
+        slots: None,
+        assessment: None,
+        matcher: None,
+        lateness: None,
+        resubmission: None,
+    }
+}
+
+#[test]
+fn ticket_keeps_metadata_and_creator() {
+    let mut suite = suite();
+    let mut msg = ticket_with(&suite, 1, metadata("labeling", &["images"]));
+    // The creator is always the admin wallet.
+    msg.creator = Some("someone".to_string());
+    suite.add_ticket(msg).unwrap();
+
+    let info = suite.ticket_info(1).unwrap();
+    assert_eq!(info.metadata, metadata("labeling", &["images"]));
+    assert_eq!(info.creator, suite.admin.to_string());
+
+    // Legacy ticket without the metadata.
+    suite.add_ticket(suite.ticket(2, COLLATERAL, "42")).unwrap();
+    let info = suite.ticket_info(2).unwrap();
+    assert_eq!(info.metadata, TicketMetadata::default());
+    assert_eq!(info.creator, suite.admin.to_string());
+}
+
+#[test]
+fn metadata_limits_are_validated() {
+    let mut suite = suite();
+    let invalid = vec![
+        TicketMetadata {
+            title: "x".repeat(MAX_TITLE_LEN + 1),
+            ..metadata("labeling", &[])
+        },
+        metadata("Labeling", &[]),
+        metadata("labeling", &["images", "images"]),
+        TicketMetadata {
+            tags: (0..=MAX_TAGS).map(|i| format!("tag{}", i)).collect(),
+            ..metadata("labeling", &[])
+        },
+        TicketMetadata {
+            content_uri: None,
+            ..metadata("labeling", &[])
+        },
+        TicketMetadata {
+            content_hash: Some("abc".to_string()),
+            ..metadata("labeling", &[])
+        },
+    ];
+    for (i, m) in invalid.into_iter().enumerate() {
+        let err = suite
+            .add_ticket(ticket_with(&suite, i as u64, m))
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Invalid ticket metadata"));
+    }
+
+    // The update is validated as well.
+    suite
+        .add_ticket(ticket_with(&suite, 1, metadata("labeling", &[])))
+        .unwrap();
+    suite
+        .update_ticket(update_metadata(1, metadata("labeling", &["bad tag"])))
+        .unwrap_err();
+    suite
+        .update_ticket(update_metadata(1, metadata("review", &["text"])))
+        .unwrap();
+    assert_eq!(
+        suite.ticket_info(1).unwrap().metadata,
+        metadata("review", &["text"])
+    );
+}
+
+#[test]
+fn tickets_are_filtered_by_category_and_tag() {
+    let mut suite = suite();
+    let tickets = vec![
+        ticket_with(&suite, 1, metadata("labeling", &["images", "urgent"])),
+        ticket_with(&suite, 2, metadata("labeling", &["text"])),
+        ticket_with(&suite, 3, metadata("review", &["urgent"])),
+        suite.ticket(4, COLLATERAL, "42"),
+    ];
+    suite.add_tickets(tickets).unwrap();
+
+    assert_eq!(suite.tickets(None, None).unwrap(), vec![1, 2, 3, 4]);
+    assert_eq!(suite.tickets(Some("labeling"), None).unwrap(), vec![1, 2]);
+    assert_eq!(suite.tickets(None, Some("urgent")).unwrap(), vec![1, 3]);
+    assert_eq!(
+        suite.tickets(Some("labeling"), Some("urgent")).unwrap(),
+        vec![1]
+    );
+    assert!(suite.tickets(Some("other"), None).unwrap().is_empty());
+
+    // Workers get the same view through user-board.
+    let res: TicketsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            suite.user_board.clone(),
+            &UserQueryMsg::QueryTickets {
+                category: None,
+                tag: Some("urgent".to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.tickets.len(), 2);
+    assert_eq!(res.tickets[0].metadata.title, "Label the images");
+}
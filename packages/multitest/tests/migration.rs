@@ -0,0 +1,244 @@
+use collateral_manager::state::{remove_stake_amount, store_worker_stakes, TREASURY};
+use cosmwasm_std::{to_json_binary, Empty};
+use cw_multi_test::Executor;
+use ticket_auction::admin_board::{ChildKind, ExecuteMsg as AdminExecuteMsg, MigrateAllMsg};
+use ticket_auction::matcher::ResultMatcher;
+use ticket_auction::ticket_manager::{
+    AssessmentMode, AuctionParams, LatenessPenalty, TicketInfoResponse, TicketMetadata,
+};
+use ticket_auction_multitest::{
+    contract_auction_manager, contract_collateral_manager, contract_ticket_manager,
+    contract_user_board, suite_with_workers, Suite, SuiteBuilder, INITIAL,
+};
+
+fn suite() -> Suite {
+    SuiteBuilder::new().build()
+}
+
+// Ticket as stored before the versioning, with the 5 fields only.
+fn legacy_ticket(id: u64) -> String {
+    format!(
+        r#"{{"id":{},"bet_finish_timestamp":100,"close_timestamp":200,"result":"42","collateral":100}}"#,
+        id
+    )
+}
+
+// Children are migrated by their admin, admin-board.
+fn migrate(suite: &mut Suite, code_id: u64) -> anyhow::Result<cw_multi_test::AppResponse> {
+    let contract = suite.ticket_manager.clone();
+    let admin_board = suite.admin_board.clone();
+    suite
+        .app
+        .migrate_contract(admin_board, contract, &Empty {}, code_id)
+}
+
+#[test]
+fn instantiate_records_contract_version() {
+    let suite = suite();
+    let info = cw2::query_contract_info(&suite.app.wrap(), suite.ticket_manager.clone()).unwrap();
+    assert_eq!(info.contract, "ticket-manager");
+    assert_eq!(info.version, "0.2.0");
+
+    let info = cw2::query_contract_info(&suite.app.wrap(), suite.user_board.clone()).unwrap();
+    assert_eq!(info.contract, "user-board");
+}
+
+#[test]
+fn legacy_tickets_are_moved_to_map() {
+    let mut suite = suite();
+
+    // Storage layout of the contract deployed before the versioning.
+    {
+        let mut storage = suite.app.contract_storage_mut(&suite.ticket_manager);
+        storage.remove(b"contract_info");
+        let tickets = format!("[{},{}]", legacy_ticket(1), legacy_ticket(2));
+        storage.set(b"tickets", tickets.as_bytes());
+    }
+
+    let code_id = suite.app.store_code(contract_ticket_manager());
+    let res = migrate(&mut suite, code_id).unwrap();
+    let attrs = res
+        .events
+        .iter()
+        .flat_map(|e| e.attributes.iter())
+        .map(|a| (a.key.as_str(), a.value.as_str()))
+        .collect::<Vec<_>>();
+    assert!(attrs.contains(&("from_version", "0.0.0")));
+    assert!(attrs.contains(&("steps", "0.2.0")));
+
+    // The fields added since then get their defaults.
+    assert_eq!(
+        suite.ticket_info(1).unwrap(),
+        TicketInfoResponse {
+            id: 1,
+            bet_finish_timestamp: 100,
+            close_timestamp: 200,
+            result: "42".to_string(),
+            collateral: 100,
+            auction: AuctionParams::default(),
+            metadata: TicketMetadata::default(),
+            creator: String::new(),
+            slots: 1,
+            assessment: AssessmentMode::ExpectedResult,
+            resubmission: false,
+            matcher: ResultMatcher::Exact,
+            lateness: LatenessPenalty::Flat { cutoff: None },
+        }
+    );
+    assert_eq!(suite.ticket_info(2).unwrap().id, 2);
+    assert!(suite
+        .app
+        .contract_storage(&suite.ticket_manager)
+        .get(b"tickets")
+        .is_none());
+
+    // Migrating again to the same version runs no step.
+    let res = migrate(&mut suite, code_id).unwrap();
+    assert!(res
+        .events
+        .iter()
+        .flat_map(|e| e.attributes.iter())
+        .any(|a| a.key == "steps" && a.value == "none"));
+}
+
+#[test]
+fn legacy_stakes_are_backfilled() {
+    let mut suite = suite_with_workers(1);
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, 100, "42")).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+
+    // Only the stakers of the ticket were stored before the versioning.
+    {
+        let mut storage = suite.app.contract_storage_mut(&suite.collateral_manager);
+        storage.remove(b"contract_info");
+        remove_stake_amount(storage.as_mut(), 1, &worker1);
+        store_worker_stakes(storage.as_mut(), &worker1, vec![]).unwrap();
+        TREASURY.remove(storage.as_mut());
+    }
+    assert!(suite.treasury().unwrap().locked.is_zero());
+
+    let new_code_id = suite.app.store_code(contract_collateral_manager());
+    suite
+        .execute_admin(AdminExecuteMsg::MigrateChild {
+            contract: ChildKind::CollateralManager,
+            new_code_id,
+            msg: to_json_binary(&Empty {}).unwrap(),
+        })
+        .unwrap();
+    assert_eq!(suite.treasury().unwrap().locked.u128(), 100);
+    assert_eq!(suite.worker_stakes(&worker1).unwrap(), vec![1]);
+
+    // The backfilled stake is released as usual.
+    suite.submit_result(&worker1, 1, "42").unwrap();
+    assert!(suite.treasury().unwrap().locked.is_zero());
+    assert_eq!(suite.balance(&worker1), INITIAL);
+}
+
+#[test]
+fn foreign_contract_is_refused() {
+    let mut suite = suite();
+    let code_id = suite.app.store_code(contract_user_board());
+    migrate(&mut suite, code_id).unwrap_err();
+}
+
+#[test]
+fn downgrade_is_refused() {
+    let mut suite = suite();
+    {
+        let mut storage = suite.app.contract_storage_mut(&suite.ticket_manager);
+        cw2::set_contract_version(storage.as_mut(), "ticket-manager", "9.0.0").unwrap();
+    }
+    let code_id = suite.app.store_code(contract_ticket_manager());
+    migrate(&mut suite, code_id).unwrap_err();
+}
+
+#[test]
+fn children_are_listed_with_code_id_and_version() {
+    let suite = suite();
+    let children = suite.children().unwrap();
+    assert_eq!(children.len(), 4);
+
+    let ticket_manager = suite.child(ChildKind::TicketManager).unwrap();
+    assert_eq!(ticket_manager.address, suite.ticket_manager.to_string());
+    assert_eq!(ticket_manager.contract.as_deref(), Some("ticket-manager"));
+    assert_eq!(ticket_manager.version.as_deref(), Some("0.2.0"));
+
+    let collateral_manager = suite.child(ChildKind::CollateralManager).unwrap();
+    assert_eq!(collateral_manager.version.as_deref(), Some("0.1.0"));
+}
+
+#[test]
+fn admin_migrates_child() {
+    let mut suite = suite();
+    let old_code_id = suite.child(ChildKind::TicketManager).unwrap().code_id;
+    let new_code_id = suite.app.store_code(contract_ticket_manager());
+    let msg = AdminExecuteMsg::MigrateChild {
+        contract: ChildKind::TicketManager,
+        new_code_id,
+        msg: to_json_binary(&Empty {}).unwrap(),
+    };
+
+    // Only admin
+    let worker = suite.addr("worker1");
+    suite
+        .app
+        .execute_contract(worker, suite.admin_board.clone(), &msg, &[])
+        .unwrap_err();
+
+    suite.execute_admin(msg).unwrap();
+    let ticket_manager = suite.child(ChildKind::TicketManager).unwrap();
+    assert_ne!(ticket_manager.code_id, old_code_id);
+    assert_eq!(ticket_manager.code_id, new_code_id);
+
+    // The code of the other contract is refused by the child.
+    let foreign_code_id = suite.app.store_code(contract_user_board());
+    suite
+        .execute_admin(AdminExecuteMsg::MigrateChild {
+            contract: ChildKind::TicketManager,
+            new_code_id: foreign_code_id,
+            msg: to_json_binary(&Empty {}).unwrap(),
+        })
+        .unwrap_err();
+}
+
+#[test]
+fn admin_migrates_all_children_atomically() {
+    let mut suite = suite();
+    let ticket_manager_code = suite.app.store_code(contract_ticket_manager());
+    let auction_manager_code = suite.app.store_code(contract_auction_manager());
+    let user_board_code = suite.child(ChildKind::UserBoard).unwrap().code_id;
+
+    // The wrong code for user_board fails the whole migration.
+    suite
+        .execute_admin(AdminExecuteMsg::MigrateAll(MigrateAllMsg {
+            ticket_manager: Some(ticket_manager_code),
+            user_board: Some(auction_manager_code),
+            ..MigrateAllMsg::default()
+        }))
+        .unwrap_err();
+    assert_ne!(
+        suite.child(ChildKind::TicketManager).unwrap().code_id,
+        ticket_manager_code
+    );
+
+    suite
+        .execute_admin(AdminExecuteMsg::MigrateAll(MigrateAllMsg {
+            ticket_manager: Some(ticket_manager_code),
+            auction_manager: Some(auction_manager_code),
+            ..MigrateAllMsg::default()
+        }))
+        .unwrap();
+    assert_eq!(
+        suite.child(ChildKind::TicketManager).unwrap().code_id,
+        ticket_manager_code
+    );
+    assert_eq!(
+        suite.child(ChildKind::AuctionManager).unwrap().code_id,
+        auction_manager_code
+    );
+    assert_eq!(
+        suite.child(ChildKind::UserBoard).unwrap().code_id,
+        user_board_code
+    );
+}
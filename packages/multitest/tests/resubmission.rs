@@ -0,0 +1,83 @@
+use ticket_auction::ticket_manager::{AddTicketMsg, AssessmentMode};
+use ticket_auction_multitest::{
+    assert_err, suite_with_workers, Suite, COLLATERAL, INITIAL, WORK_DURATION,
+};
+
+const FIELDS: &str = r#"{"name":"Alice","age":"42","city":"Paris","zip":"75001"}"#;
+
+#[test]
+fn last_submission_is_assessed_at_close() {
+    let mut suite = suite_with_workers(1);
+    let worker1 = suite.addr("worker1");
+    let keeper = suite.addr("keeper");
+    let msg = AddTicketMsg {
+        resubmission: true,
+        ..suite.ticket(1, COLLATERAL, "42")
+    };
+    suite.add_ticket(msg).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+
+    // The submissions are held & replaced until the close.
+    suite.submit_result(&worker1, 1, "41").unwrap();
+    suite.submit_result(&worker1, 1, "42").unwrap();
+    assert_eq!(suite.balance(&worker1), INITIAL - COLLATERAL as u128);
+    assert_eq!(suite.submission(1, &worker1).unwrap().unwrap().result, "42");
+    assert_err(suite.close_submissions(&keeper, 1), "not closed");
+
+    suite.advance_time(WORK_DURATION + 1);
+    assert_err(suite.submit_result(&worker1, 1, "43"), "already submitted");
+    suite.close_submissions(&keeper, 1).unwrap();
+    assert_eq!(suite.balance(&worker1), INITIAL);
+    let assessment = suite
+        .submission(1, &worker1)
+        .unwrap()
+        .unwrap()
+        .assessment
+        .unwrap();
+    assert!(assessment.correct && assessment.on_time);
+    assert_err(suite.close_submissions(&keeper, 1), "Not found");
+}
+
+#[test]
+fn matched_fields_give_partial_credit() {
+    let mut suite = suite_with_workers(1);
+    let worker1 = suite.addr("worker1");
+    let partial = |suite: &Suite, id: u64, result: &str| AddTicketMsg {
+        assessment: AssessmentMode::PartialCredit,
+        ..suite.ticket(id, COLLATERAL, result)
+    };
+
+    // The expected result must have the fields.
+    for result in ["42", "{}", r#"{"age":42}"#] {
+        assert_err(
+            suite.add_ticket(partial(&suite, 1, result)),
+            "JSON object of string fields",
+        );
+    }
+    suite.add_ticket(partial(&suite, 1, FIELDS)).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+
+    // 2 of 4 fields match, the half of the 50% slash is applied.
+    suite
+        .submit_result(
+            &worker1,
+            1,
+            r#"{"name":"Alice","age":"42","city":"Lyon","extra":"x"}"#,
+        )
+        .unwrap();
+    assert_eq!(suite.balance(&worker1), INITIAL - 25);
+    let outcome = suite.worker_submissions(&worker1).unwrap()[0]
+        .assessment
+        .clone()
+        .unwrap();
+    assert!(!outcome.correct);
+    assert_eq!(outcome.slash_perc.u128(), 250);
+
+    // The unstructured result has no credit.
+    suite.add_ticket(partial(&suite, 2, FIELDS)).unwrap();
+    suite.run_auction(2, &[(&worker1, 50)]).unwrap();
+    suite.submit_result(&worker1, 2, "Alice, 42").unwrap();
+    assert_eq!(suite.balance(&worker1), INITIAL - 25 - 50);
+    let stats = suite.worker_reputation(&worker1).unwrap().stats;
+    assert_eq!(stats.wrong, 2);
+}
@@ -0,0 +1,141 @@
+use cosmwasm_std::to_json_string;
+use ticket_auction::matcher::ResultMatcher;
+use ticket_auction::ticket_manager::{AddTicketMsg, AssessmentMode, LatenessPenalty};
+use ticket_auction_multitest::{suite_with_workers, Suite, INITIAL, WORK_DURATION};
+
+const HOUR: u64 = 3_600;
+
+fn close_of(suite: &Suite, tid: u64) -> u64 {
+    suite.ticket_info(tid).unwrap().close_timestamp
+}
+
+#[test]
+fn simulation_matches_real_assessment() {
+    let mut suite = suite_with_workers(2);
+    let worker1 = suite.addr("worker1");
+    let worker2 = suite.addr("worker2");
+    // The odd collateral for the rounding of the slashed amount.
+    let msg = AddTicketMsg {
+        matcher: ResultMatcher::CaseInsensitive,
+        lateness: LatenessPenalty::Linear {
+            per_hour: 100,
+            max: 500,
+            cutoff: None,
+        },
+        ..suite.ticket(1, 333, "Mount Everest")
+    };
+    suite.add_ticket(msg).unwrap();
+    let close = close_of(&suite, 1);
+
+    // The worker can simulate before the bet.
+    let res = suite
+        .simulate_assessment(1, &worker2, "MOUNT EVEREST", None)
+        .unwrap();
+    assert_eq!(res.matched, Some(true));
+    assert!(res.on_time && !res.rejected);
+    assert!(res.slash_perc.is_zero());
+    assert_eq!(res.released.u128(), 333);
+
+    // The wrong result on time: 50%, 333 * 0.5 = 166.5 slashed.
+    let res = suite.simulate_assessment(1, &worker2, "K2", None).unwrap();
+    assert_eq!(res.matched, Some(false));
+    assert!(!res.held);
+    assert!(res.lateness_penalty.is_zero());
+    assert_eq!(res.slash_perc.u128(), 500);
+    assert_eq!(res.released.u128(), 167);
+
+    // The wrong result 2 hours late: 50% + 20%, 333 * 0.7 = 233.1 slashed.
+    let late = close + 2 * HOUR;
+    let res = suite
+        .simulate_assessment(1, &worker1, "K2", Some(late))
+        .unwrap();
+    assert_eq!(res.matched, Some(false));
+    assert!(!res.on_time);
+    assert_eq!(res.lateness_penalty.u128(), 200);
+    assert_eq!(res.slash_perc.u128(), 700);
+    assert_eq!(res.collateral.u128(), 333);
+    assert_eq!(res.released.u128(), 100);
+    // The expected result is not revealed.
+    assert!(!to_json_string(&res).unwrap().contains("Everest"));
+
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+    suite.advance_time(late - suite.block_time());
+    suite.submit_result(&worker1, 1, "K2").unwrap();
+    let assessment = suite
+        .submission(1, &worker1)
+        .unwrap()
+        .unwrap()
+        .assessment
+        .unwrap();
+    assert_eq!(assessment.slash_perc, res.slash_perc);
+    assert_eq!(assessment.released, res.released);
+    assert_eq!(suite.balance(&worker1), INITIAL - 333 + 100);
+
+    let err = suite
+        .simulate_assessment(1, &worker1, "Mount Everest", None)
+        .unwrap_err();
+    assert!(err.to_string().contains("already assessed"), "{}", err);
+}
+
+#[test]
+fn simulation_reports_credit_and_cutoff() {
+    let mut suite = suite_with_workers(2);
+    let worker1 = suite.addr("worker1");
+    let msg = AddTicketMsg {
+        assessment: AssessmentMode::PartialCredit,
+        lateness: LatenessPenalty::Flat { cutoff: Some(600) },
+        ..suite.ticket(1, 100, r#"{"city":"Paris","zip":"75001"}"#)
+    };
+    suite.add_ticket(msg).unwrap();
+    let close = close_of(&suite, 1);
+
+    let partial = r#"{"city":"Paris","zip":"69001"}"#;
+    let res = suite
+        .simulate_assessment(1, &worker1, partial, Some(close + 600))
+        .unwrap();
+    assert_eq!(res.matched, Some(false));
+    assert_eq!(res.credit, Some(500));
+    // 25% for the half credit + 30% for the delay.
+    assert!(!res.rejected);
+    assert_eq!(res.lateness_penalty.u128(), 300);
+    assert_eq!(res.slash_perc.u128(), 550);
+    assert_eq!(res.released.u128(), 45);
+
+    // After the cutoff, the whole stake is slashed as no-show.
+    let res = suite
+        .simulate_assessment(1, &worker1, partial, Some(close + 601))
+        .unwrap();
+    assert!(res.rejected);
+    assert_eq!(res.slash_perc.u128(), 1000);
+    assert!(res.released.is_zero());
+}
+
+#[test]
+fn held_result_is_simulated_as_correct() {
+    let mut suite = suite_with_workers(2);
+    let worker1 = suite.addr("worker1");
+    let consensus = AddTicketMsg {
+        assessment: AssessmentMode::Consensus,
+        slots: 2,
+        ..suite.ticket(1, 100, "42")
+    };
+    let manual = AddTicketMsg {
+        assessment: AssessmentMode::Manual {
+            review_period: WORK_DURATION,
+        },
+        ..suite.ticket(2, 100, "")
+    };
+    suite.add_tickets(vec![consensus, manual]).unwrap();
+
+    for tid in [1, 2] {
+        let late = close_of(&suite, tid) + 1;
+        let res = suite
+            .simulate_assessment(tid, &worker1, "41", Some(late))
+            .unwrap();
+        assert_eq!(res.matched, None);
+        assert_eq!(res.credit, None);
+        assert!(res.held);
+        assert_eq!(res.slash_perc.u128(), 300);
+        assert_eq!(res.released.u128(), 70);
+    }
+}
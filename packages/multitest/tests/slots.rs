@@ -0,0 +1,208 @@
+use cosmwasm_std::Addr;
+use ticket_auction::matcher::ResultMatcher;
+use ticket_auction::ticket_manager::{
+    AddTicketMsg, AssessmentMode, AuctionParams, UpdateTicketMsg, DEFAULT_LATENESS_CUTOFF,
+    MAX_SLOTS,
+};
+use ticket_auction_multitest::{Suite, SuiteBuilder, WORK_DURATION};
+
+const INITIAL: u128 = 10_000;
+const COLLATERAL: u64 = 100;
+
+fn suite() -> (Suite, Vec<Addr>) {
+    let suite = SuiteBuilder::new()
+        .with_funds("worker1", INITIAL)
+        .with_funds("worker2", INITIAL)
+        .with_funds("worker3", INITIAL)
+        .build();
+    let workers = vec![
+        suite.addr("worker1"),
+        suite.addr("worker2"),
+        suite.addr("worker3"),
+    ];
+    (suite, workers)
+}
+
+fn ticket(suite: &Suite, slots: u32, assessment: AssessmentMode) -> AddTicketMsg {
+    AddTicketMsg {
+        slots,
+        assessment,
+        ..suite.ticket(1, COLLATERAL, "42")
+    }
+}
+
+#[test]
+fn lowest_bidders_fill_every_slot() {
+    let (mut suite, w) = suite();
+    suite
+        .add_ticket(ticket(&suite, 2, AssessmentMode::ExpectedResult))
+        .unwrap();
+
+    let first = suite
+        .run_auction(1, &[(&w[0], 50), (&w[1], 30), (&w[2], 40)])
+        .unwrap();
+
+    // The first assignee is the lowest bidder.
+    assert_eq!(first, w[1]);
+    assert_eq!(
+        suite.ticket_workers(1).unwrap(),
+        vec![w[1].clone(), w[2].clone()]
+    );
+    let pairs = suite.ticket_worker_pairs().unwrap();
+    assert_eq!(pairs.iter().filter(|p| p.tid == 1).count(), 2);
+
+    // Only the assignees submit.
+    suite.submit_result(&w[0], 1, "42").unwrap_err();
+
+    // Each result is assessed against the expected result on its own.
+    suite.submit_result(&w[1], 1, "42").unwrap();
+    suite.submit_result(&w[1], 1, "42").unwrap_err();
+    suite.submit_result(&w[2], 1, "41").unwrap();
+    assert_eq!(suite.balance(&w[1]), INITIAL);
+    assert_eq!(suite.balance(&w[2]), INITIAL - 50);
+    assert_eq!(suite.worker_reputation(&w[2]).unwrap().stats.wrong, 1);
+}
+
+#[test]
+fn min_decrement_applies_to_worst_winning_bet() {
+    let (mut suite, w) = suite();
+    let msg = AddTicketMsg {
+        auction: AuctionParams {
+            min_decrement: Some(5),
+            ..AuctionParams::default()
+        },
+        ..ticket(&suite, 2, AssessmentMode::ExpectedResult)
+    };
+    suite.add_ticket(msg).unwrap();
+    for worker in w.iter() {
+        suite.lock_stake(worker, 1, COLLATERAL as u128).unwrap();
+    }
+
+    // No decrement while a slot has no bet.
+    suite.place_bet(&w[0], 1, 50).unwrap();
+    suite.place_bet(&w[1], 1, 60).unwrap();
+    // The bet winning the second slot undercuts 60, not the best bet(50).
+    suite.place_bet(&w[2], 1, 56).unwrap_err();
+    suite.place_bet(&w[2], 1, 55).unwrap();
+}
+
+#[test]
+fn slots_are_bounded_and_frozen() {
+    let (mut suite, w) = suite();
+    for slots in [0, MAX_SLOTS + 1] {
+        let err = suite
+            .add_ticket(ticket(&suite, slots, AssessmentMode::ExpectedResult))
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("slots"));
+    }
+    suite
+        .add_ticket(ticket(&suite, 2, AssessmentMode::ExpectedResult))
+        .unwrap();
+
+    suite.lock_stake(&w[0], 1, COLLATERAL as u128).unwrap();
+    let msg = UpdateTicketMsg {
+        id: 1,
+        bet_finish_timestamp: None,
+        close_timestamp: None,
+        result: None,
+        collateral: None,
+        auction: None,
+        metadata: None,
+        slots: None,
+        assessment: Some(AssessmentMode::Consensus),
+        matcher: None,
+        lateness: None,
+        resubmission: None,
+    };
+    let err = suite.update_ticket(msg).unwrap_err();
+    assert!(err.root_cause().to_string().contains("cannot change"));
+}
+
+#[test]
+fn consensus_slashes_outliers() {
+    let (mut suite, w) = suite();
+    // The majority overrides the expected result.
+    suite
+        .add_ticket(ticket(&suite, 3, AssessmentMode::Consensus))
+        .unwrap();
+    suite
+        .run_auction(1, &[(&w[0], 50), (&w[1], 30), (&w[2], 40)])
+        .unwrap();
+
+    suite.submit_result(&w[0], 1, "cat").unwrap();
+    suite.submit_result(&w[1], 1, "dog").unwrap();
+    // Nothing is assessed until every assignee submits.
+    suite.submit_result(&w[1], 1, "cat").unwrap_err();
+    for worker in w.iter() {
+        assert_eq!(suite.balance(worker), INITIAL - COLLATERAL as u128);
+    }
+
+    suite.submit_result(&w[2], 1, "cat").unwrap();
+    assert_eq!(suite.balance(&w[0]), INITIAL);
+    assert_eq!(suite.balance(&w[1]), INITIAL - 50);
+    assert_eq!(suite.balance(&w[2]), INITIAL);
+    suite.submit_result(&w[0], 1, "cat").unwrap_err();
+}
+
+#[test]
+fn no_show_closes_consensus() {
+    let (mut suite, w) = suite();
+    suite
+        .add_ticket(ticket(&suite, 3, AssessmentMode::Consensus))
+        .unwrap();
+    suite
+        .run_auction(1, &[(&w[0], 50), (&w[1], 30), (&w[2], 40)])
+        .unwrap();
+
+    suite.submit_result(&w[0], 1, "42").unwrap();
+    suite.submit_result(&w[1], 1, "41").unwrap();
+
+    suite.advance_time(WORK_DURATION + DEFAULT_LATENESS_CUTOFF + 1);
+    suite.report_no_show(1).unwrap();
+
+    // No majority between the submissions, the expected result decides.
+    assert_eq!(suite.balance(&w[0]), INITIAL);
+    assert_eq!(suite.balance(&w[1]), INITIAL - 50);
+    assert_eq!(suite.balance(&w[2]), INITIAL - COLLATERAL as u128);
+    assert_eq!(suite.worker_reputation(&w[2]).unwrap().stats.no_shows, 1);
+    suite.report_no_show(1).unwrap_err();
+}
+
+#[test]
+fn consensus_needs_majority_of_assignees() {
+    let (mut suite, w) = suite();
+    let err = suite
+        .add_ticket(ticket(&suite, 1, AssessmentMode::Consensus))
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("slots"));
+
+    // The lone submission is no majority, the expected result decides.
+    suite
+        .add_ticket(ticket(&suite, 3, AssessmentMode::Consensus))
+        .unwrap();
+    suite
+        .run_auction(1, &[(&w[0], 50), (&w[1], 30), (&w[2], 40)])
+        .unwrap();
+    suite.submit_result(&w[0], 1, "41").unwrap();
+    suite.advance_time(WORK_DURATION + DEFAULT_LATENESS_CUTOFF + 1);
+    suite.report_no_show(1).unwrap();
+    assert_eq!(suite.balance(&w[0]), INITIAL - 50);
+
+    // The results are grouped with the matcher.
+    let msg = AddTicketMsg {
+        id: 2,
+        matcher: ResultMatcher::CaseInsensitive,
+        ..ticket(&suite, 3, AssessmentMode::Consensus)
+    };
+    suite.add_ticket(msg).unwrap();
+    suite
+        .run_auction(2, &[(&w[0], 50), (&w[1], 30), (&w[2], 40)])
+        .unwrap();
+    suite.submit_result(&w[0], 2, "Cat").unwrap();
+    suite.submit_result(&w[1], 2, "CAT").unwrap();
+    suite.submit_result(&w[2], 2, "42").unwrap();
+    // (worker2 & worker3 were slashed as the no-shows of the first ticket)
+    assert_eq!(suite.balance(&w[0]), INITIAL - 50);
+    assert_eq!(suite.balance(&w[1]), INITIAL - COLLATERAL as u128);
+    assert_eq!(suite.balance(&w[2]), INITIAL - COLLATERAL as u128 - 50);
+}
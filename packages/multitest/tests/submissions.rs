@@ -0,0 +1,97 @@
+use ticket_auction::ticket_manager::{AddTicketMsg, AssessmentMode, MAX_STORED_RESULT_LEN};
+use ticket_auction_multitest::{Suite, SuiteBuilder, WORK_DURATION};
+
+const INITIAL: u128 = 10_000;
+const COLLATERAL: u64 = 100;
+
+fn suite() -> Suite {
+    SuiteBuilder::new().with_funds("worker1", INITIAL).build()
+}
+
+#[test]
+fn submission_records_result_and_assessment() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+    assert!(suite.submission(1, &worker1).unwrap().is_none());
+
+    let submitted_at = suite.block_time();
+    suite.submit_result(&worker1, 1, "41").unwrap();
+    let submission = suite.submission(1, &worker1).unwrap().unwrap();
+    assert_eq!(submission.result, "41");
+    assert_eq!(submission.submitted_at, submitted_at);
+    let assessment = submission.assessment.unwrap();
+    assert!(!assessment.correct);
+    assert!(assessment.on_time);
+    assert_eq!(assessment.slash_perc.u128(), 500);
+    assert_eq!(assessment.released.u128(), 50);
+    assert_eq!(assessment.assessed_at, submitted_at);
+
+    // The late submission is listed after the first one.
+    suite.add_ticket(suite.ticket(2, COLLATERAL, "42")).unwrap();
+    suite.run_auction(2, &[(&worker1, 50)]).unwrap();
+    suite.advance_time(WORK_DURATION + 1);
+    suite.submit_result(&worker1, 2, "42").unwrap();
+    let submissions = suite.worker_submissions(&worker1).unwrap();
+    assert_eq!(
+        submissions.iter().map(|s| s.tid).collect::<Vec<u64>>(),
+        vec![1, 2]
+    );
+    let assessment = submissions[1].assessment.clone().unwrap();
+    assert!(assessment.correct && !assessment.on_time);
+    assert_eq!(assessment.released.u128(), 70);
+    assert_eq!(
+        suite.balance(&worker1),
+        INITIAL - 2 * COLLATERAL as u128 + 50 + 70
+    );
+}
+
+#[test]
+fn long_result_is_stored_as_hash() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+
+    let result = "x".repeat(MAX_STORED_RESULT_LEN + 1);
+    suite.submit_result(&worker1, 1, &result).unwrap();
+    let submission = suite.submission(1, &worker1).unwrap().unwrap();
+    // sha256 of the result in lowercase hex.
+    assert!(submission.result.starts_with("sha256:"));
+    assert_eq!(submission.result.len(), "sha256:".len() + 64);
+    assert!(submission.result[7..]
+        .chars()
+        .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+}
+
+#[test]
+fn held_submission_waits_for_assessment() {
+    let mut suite = suite();
+    let worker1 = suite.addr("worker1");
+    let grader = suite.addr("grader");
+    suite.add_grader(&grader).unwrap();
+    let msg = AddTicketMsg {
+        assessment: AssessmentMode::Manual {
+            review_period: 2 * WORK_DURATION,
+        },
+        ..suite.ticket(1, COLLATERAL, "")
+    };
+    suite.add_ticket(msg).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+
+    suite.submit_result(&worker1, 1, "ipfs://work").unwrap();
+    let submission = suite.submission(1, &worker1).unwrap().unwrap();
+    assert_eq!(submission.result, "ipfs://work");
+    assert!(submission.assessment.is_none());
+
+    suite.grade_submission(&grader, 1, None, 900).unwrap();
+    let assessment = suite
+        .submission(1, &worker1)
+        .unwrap()
+        .unwrap()
+        .assessment
+        .unwrap();
+    assert_eq!(assessment.slash_perc.u128(), 100);
+    assert_eq!(assessment.released.u128(), 90);
+}
@@ -0,0 +1,203 @@
+use ticket_auction::admin_board::ExecuteMsg as AdminExecuteMsg;
+use ticket_auction::ticket_manager::{
+    AddTicketMsg, AuctionParams, QueryMsg as TicketQueryMsg, TicketLimits, TicketMetadata,
+    UpdateTicketMsg,
+};
+use ticket_auction_multitest::{
+    assert_err, suite_with_workers, Suite, BET_DURATION, COLLATERAL, WORK_DURATION,
+};
+
+fn update(id: u64) -> UpdateTicketMsg {
+    UpdateTicketMsg {
+        id,
+        bet_finish_timestamp: None,
+        close_timestamp: None,
+        result: None,
+        collateral: None,
+        auction: None,
+        metadata: None,
+        slots: None,
+        assessment: None,
+        matcher: None,
+        lateness: None,
+        resubmission: None,
+    }
+}
+
+fn set_limits(suite: &mut Suite, limits: TicketLimits) -> anyhow::Result<()> {
+    suite.execute_admin(AdminExecuteMsg::SetTicketLimits(limits))?;
+    Ok(())
+}
+
+#[test]
+fn timestamps_must_follow_block_time() {
+    let mut suite = suite_with_workers(1);
+    let now = suite.block_time();
+    let ticket = suite.ticket(1, COLLATERAL, "42");
+
+    // Bet finishes in the past or now.
+    for bet_finish_timestamp in [now - 1, now] {
+        let msg = AddTicketMsg {
+            bet_finish_timestamp,
+            ..ticket.clone()
+        };
+        assert_err(suite.add_ticket(msg), "Ticket timestamps");
+    }
+    // Ticket closes before the bet finishes.
+    let msg = AddTicketMsg {
+        close_timestamp: ticket.bet_finish_timestamp,
+        ..ticket.clone()
+    };
+    assert_err(suite.add_ticket(msg), "Ticket timestamps");
+    // Zero collateral.
+    let msg = AddTicketMsg {
+        collateral: 0,
+        ..ticket.clone()
+    };
+    assert_err(suite.add_ticket(msg), "Collateral");
+
+    suite.add_ticket(ticket).unwrap();
+    // The update is validated against the block time as well.
+    let msg = UpdateTicketMsg {
+        bet_finish_timestamp: Some(now),
+        ..update(1)
+    };
+    assert_err(suite.update_ticket(msg), "Ticket timestamps");
+}
+
+#[test]
+fn admin_limits_bound_durations_and_collateral() {
+    let mut suite = suite_with_workers(1);
+    let limits = TicketLimits {
+        min_bet_duration: BET_DURATION,
+        max_bet_duration: Some(2 * BET_DURATION),
+        min_work_duration: WORK_DURATION,
+        max_work_duration: None,
+        min_collateral: 50,
+        max_collateral: Some(500),
+    };
+
+    // Empty ranges & zero minimum collateral are rejected.
+    set_limits(
+        &mut suite,
+        TicketLimits {
+            max_bet_duration: Some(BET_DURATION - 1),
+            ..limits.clone()
+        },
+    )
+    .unwrap_err();
+    set_limits(
+        &mut suite,
+        TicketLimits {
+            min_collateral: 0,
+            ..limits.clone()
+        },
+    )
+    .unwrap_err();
+
+    set_limits(&mut suite, limits.clone()).unwrap();
+    let stored: TicketLimits = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            suite.ticket_manager.clone(),
+            &TicketQueryMsg::QueryTicketLimits {},
+        )
+        .unwrap();
+    assert_eq!(stored, limits);
+
+    let ticket = suite.ticket(1, COLLATERAL, "42");
+    let too_short_bet = AddTicketMsg {
+        bet_finish_timestamp: ticket.bet_finish_timestamp - 1,
+        ..ticket.clone()
+    };
+    assert_err(suite.add_ticket(too_short_bet), "Ticket timestamps");
+    let too_long_bet = AddTicketMsg {
+        bet_finish_timestamp: ticket.bet_finish_timestamp + BET_DURATION + 1,
+        close_timestamp: ticket.close_timestamp + BET_DURATION + 1,
+        ..ticket.clone()
+    };
+    assert_err(suite.add_ticket(too_long_bet), "Ticket timestamps");
+    let too_short_work = AddTicketMsg {
+        close_timestamp: ticket.close_timestamp - 1,
+        ..ticket.clone()
+    };
+    assert_err(suite.add_ticket(too_short_work), "Ticket timestamps");
+    assert_err(
+        suite.add_ticket(suite.ticket(1, 501, "42")),
+        "Collateral is out of the allowed range",
+    );
+    assert_err(
+        suite.add_ticket(suite.ticket(1, 49, "42")),
+        "Collateral is out of the allowed range",
+    );
+
+    suite.add_ticket(ticket).unwrap();
+}
+
+#[test]
+fn schedule_is_frozen_once_staked() {
+    let mut suite = suite_with_workers(1);
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+
+    // Free to change before any stake.
+    let msg = UpdateTicketMsg {
+        collateral: Some(200),
+        ..update(1)
+    };
+    suite.update_ticket(msg).unwrap();
+
+    suite.lock_stake(&worker1, 1, 200).unwrap();
+    let msg = UpdateTicketMsg {
+        collateral: Some(300),
+        ..update(1)
+    };
+    assert_err(suite.update_ticket(msg), "cannot change once a worker");
+    let msg = UpdateTicketMsg {
+        close_timestamp: Some(suite.ticket_info(1).unwrap().close_timestamp + 1),
+        ..update(1)
+    };
+    assert_err(suite.update_ticket(msg), "cannot change once a worker");
+    let msg = UpdateTicketMsg {
+        result: Some("43".to_string()),
+        ..update(1)
+    };
+    assert_err(suite.update_ticket(msg), "cannot change once a worker");
+    let msg = UpdateTicketMsg {
+        auction: Some(AuctionParams {
+            min_bid: Some(10),
+            ..AuctionParams::default()
+        }),
+        ..update(1)
+    };
+    assert_err(suite.update_ticket(msg), "cannot change once a worker");
+
+    // Unchanged values & the metadata can still be updated.
+    let msg = UpdateTicketMsg {
+        collateral: Some(200),
+        result: Some("42".to_string()),
+        metadata: Some(TicketMetadata {
+            title: "Answer".to_string(),
+            ..TicketMetadata::default()
+        }),
+        ..update(1)
+    };
+    suite.update_ticket(msg).unwrap();
+    assert_eq!(suite.ticket_info(1).unwrap().metadata.title, "Answer");
+}
+
+#[test]
+fn schedule_stays_frozen_after_decision() {
+    let mut suite = suite_with_workers(1);
+    let worker1 = suite.addr("worker1");
+    suite.add_ticket(suite.ticket(1, COLLATERAL, "42")).unwrap();
+    suite.run_auction(1, &[(&worker1, 50)]).unwrap();
+
+    // The bets are cleared, the ticket is assigned.
+    let msg = UpdateTicketMsg {
+        collateral: Some(COLLATERAL + 1),
+        ..update(1)
+    };
+    assert_err(suite.update_ticket(msg), "cannot change once a worker");
+}
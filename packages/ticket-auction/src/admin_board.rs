@@ -1,46 +1,176 @@
-use cosmwasm_std::{Addr, Uint128};
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Binary, Uint128};
 
-use crate::ticket_manager::{AddTicketMsg, UpdateTicketMsg};
+use crate::collateral_manager::DisputeParams;
+use crate::ticket_manager::{
+    AddTicketMsg, GradeSubmissionMsg, TicketInfoResponse, TicketLimits, UpdateTicketMsg,
+};
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct InstantiateMsg {}
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub enum ExecuteMsg {
     // Transactions initiated by admin wallet
+    #[serde(alias = "AddTicket")]
     AddTicket(AddTicketMsg),
+    #[serde(alias = "UpdateTicket")]
     UpdateTicket(UpdateTicketMsg),
-    RemoveTicket { tid: u64 },
-    DecideWinningBet { tid: u64 },
+    #[serde(alias = "RemoveTicket")]
+    RemoveTicket {
+        tid: u64,
+    },
+    #[serde(alias = "DecideWinningBet")]
+    DecideWinningBet {
+        tid: u64,
+    },
+    ReportNoShow {
+        tid: u64,
+    },
+    // Batch operations, validated atomically. (up to "batch::MAX_BATCH_SIZE" items)
+    AddTickets(Vec<AddTicketMsg>),
+    UpdateTickets(Vec<UpdateTicketMsg>),
+    CancelTickets {
+        tids: Vec<u64>,
+    },
+    DecideWinningBets {
+        tids: Vec<u64>,
+    },
+    SetKeeperReward {
+        amount: Uint128,
+        #[serde(default)]
+        bet_fee: Uint128,
+    },
+    SetTicketLimits(TicketLimits),
+    AddGrader {
+        address: String,
+    },
+    RemoveGrader {
+        address: String,
+    },
+    AddArbiter {
+        address: String,
+    },
+    RemoveArbiter {
+        address: String,
+    },
+    SetDisputeParams(DisputeParams),
+
+    // Transaction initiated by grader
+    GradeSubmission(GradeSubmissionMsg),
+
+    // Transaction initiated by arbiter
+    // Uphold(the slash & the bond are kept) or reverse(both are returned) the disputed slash.
+    ResolveDispute {
+        tid: u64,
+        worker: String,
+        uphold: bool,
+    },
 
     // Transaction initiated by ticket_manager
+    #[serde(alias = "ReleaseStakeWithSlash")]
     ReleaseStakeWithSlash(SlashMsg),
 
     // Utilities
-    CreateTicketManager { code_id: u64 },
-    CreateUsrBoardManager { code_id: u64 },
-    CreateAuctionManager { code_id: u64 },
-    CreateCollateralManager { code_id: u64 },
+    #[serde(alias = "CreateTicketManager")]
+    CreateTicketManager {
+        code_id: u64,
+    },
+    #[serde(alias = "CreateUsrBoardManager")]
+    CreateUsrBoardManager {
+        code_id: u64,
+    },
+    #[serde(alias = "CreateAuctionManager")]
+    CreateAuctionManager {
+        code_id: u64,
+    },
+    #[serde(alias = "CreateCollateralManager")]
+    CreateCollateralManager {
+        code_id: u64,
+    },
 
+    #[serde(alias = "PostConfig")]
     PostConfig(PostConfigMsg),
+
+    // Migration of the contracts created by admin_board
+    MigrateChild {
+        contract: ChildKind,
+        new_code_id: u64,
+        msg: Binary,
+    },
+    MigrateAll(MigrateAllMsg),
+}
+
+// Contracts created by admin_board.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum ChildKind {
+    TicketManager,
+    UserBoard,
+    AuctionManager,
+    CollateralManager,
+}
+
+impl ChildKind {
+    pub const ALL: [ChildKind; 4] = [
+        ChildKind::TicketManager,
+        ChildKind::UserBoard,
+        ChildKind::AuctionManager,
+        ChildKind::CollateralManager,
+    ];
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+// New code id of each contract to migrate. (None: not migrated)
+// The contracts are migrated with the empty "MigrateMsg".
+#[cw_serde]
+#[derive(Default)]
+pub struct MigrateAllMsg {
+    pub ticket_manager: Option<u64>,
+    pub user_board: Option<u64>,
+    pub auction_manager: Option<u64>,
+    pub collateral_manager: Option<u64>,
+}
+
+#[cw_serde]
 pub struct SlashMsg {
     pub tid: u64,
     pub worker: Addr,
     pub slash_perc: Uint128,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
+#[derive(QueryResponses)]
 pub enum QueryMsg {
+    #[returns(TicketInfoResponse)]
+    #[serde(alias = "QueryTicketInfo")]
     QueryTicketInfo { tid: u64 },
+    #[returns(String)]
+    #[serde(alias = "QueryTicketWorker")]
     QueryTicketWorker { tid: u64 },
+    #[returns(ChildrenResponse)]
+    QueryChildren {},
+    #[returns(Vec<Addr>)]
+    QueryGraders {},
+    #[returns(Vec<Addr>)]
+    QueryArbiters {},
+}
+
+#[cw_serde]
+pub struct ChildInfo {
+    pub kind: ChildKind,
+    pub address: String,
+    pub code_id: u64,
+    // cw2 contract info (None for the contract deployed before the versioning)
+    pub contract: Option<String>,
+    pub version: Option<String>,
+}
+
+#[cw_serde]
+pub struct ChildrenResponse {
+    pub children: Vec<ChildInfo>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct PostConfigMsg {
     pub ticket_manager: Option<String>,
     pub collateral_manager: Option<String>,
@@ -48,5 +178,5 @@ pub struct PostConfigMsg {
     pub user_board: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct MigrateMsg {}
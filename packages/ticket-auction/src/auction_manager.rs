@@ -1,33 +1,113 @@
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Uint128};
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+use crate::ticket_manager::BidScoring;
+
+#[cw_serde]
 pub struct InstantiateMsg {
     pub ticket_manager: String,
+    pub user_board: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub enum ExecuteMsg {
     // Transaction initiated by user_board
+    #[serde(alias = "PlaceBet")]
     PlaceBet(PlaceBetMsg),
 
+    // Transaction initiated by admin_board or keeper(after bet_finish_timestamp)
+    #[serde(alias = "DecideWinningBet")]
+    DecideWinningBet {
+        tid: u64,
+    },
+    SettleExpired {
+        limit: Option<u32>,
+    },
+
     // Transaction initiated by admin_board
-    DecideWinningBet { tid: u64 },
+    // The fields not given are left unchanged.
+    UpdateConfig {
+        #[serde(default)]
+        keeper_reward: Option<Uint128>,
+        #[serde(default)]
+        bet_fee: Option<Uint128>,
+        #[serde(default)]
+        user_board: Option<String>,
+    },
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
+#[derive(QueryResponses)]
 pub enum QueryMsg {
+    #[returns(Vec<u64>)]
+    #[serde(alias = "BetAvailableTickets")]
     BetAvailableTickets,
+    #[returns(Vec<BetDetail>)]
+    #[serde(alias = "CurrActiveBets")]
     CurrActiveBets { tid: u64 },
+    #[returns(Vec<WorkerBet>)]
+    WorkerActiveBets { worker: String },
+    #[returns(BidScoresResponse)]
+    BidScores { tid: u64 },
+    #[returns(FeePoolResponse)]
+    FeePool,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct MigrateMsg {}
 
 // Message for "PlaceBet" execute.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct PlaceBetMsg {
     pub ticket_id: u64,
     pub workder: String,
     pub bet_amount: u64,
 }
+
+// Bet placed on the ticket. (Response item for "CurrActiveBets" query)
+#[cw_serde]
+pub struct BetDetail {
+    pub worker: Addr,
+    pub bet_amt: u64,
+    #[serde(default)]
+    pub placed_at: u64,
+}
+
+// Response item for "WorkerActiveBets" query.
+#[cw_serde]
+pub struct WorkerBet {
+    pub tid: u64,
+    pub bet_amt: u64,
+}
+
+// Score of the single bet. Every score is in per mille.
+#[cw_serde]
+pub struct BidScore {
+    pub worker: String,
+    pub bet_amt: u64,
+    pub price_score: u64,
+    pub completion_score: u64,
+    pub punctuality_score: u64,
+    pub total_score: u64,
+    pub placed_at: u64,
+}
+
+// Response for "BidScores" query.
+// Scores are computed from the current bets until the winning bet is decided,
+// after that the scores recorded at the decision are returned.
+#[cw_serde]
+pub struct BidScoresResponse {
+    pub tid: u64,
+    pub scoring: Option<BidScoring>,
+    pub decided: bool,
+    pub scores: Vec<BidScore>,
+}
+
+// Response for "FeePool" query.
+// The bet fees collected from the bets, out of which the keeper rewards are paid.
+#[cw_serde]
+pub struct FeePoolResponse {
+    pub bet_fee: Uint128,
+    pub keeper_reward: Uint128,
+    pub fee_pool: Uint128,
+}
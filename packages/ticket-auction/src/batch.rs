@@ -0,0 +1,59 @@
+// Validation of the batch ticket operations.
+//
+// Every item of the batch is checked before anything is applied, the batch fails
+// as a whole with the errors of all the failing items.
+use std::collections::HashSet;
+
+use cosmwasm_std::{StdError, StdResult};
+
+use crate::error::{BatchItemError, TAError};
+
+// Maximum number of the items in a batch, keeps the tx within the gas limit.
+pub const MAX_BATCH_SIZE: usize = 30;
+
+// Check the batch size, the duplicate ticket ids & every item with "check".
+// Returns the checked values in the batch order.
+pub fn validate_batch<T, R>(
+    items: &[T],
+    tid: impl Fn(&T) -> u64,
+    mut check: impl FnMut(&T) -> StdResult<R>,
+) -> StdResult<Vec<R>> {
+    if items.is_empty() {
+        return Err(TAError::EmptyBatch.into());
+    }
+    if items.len() > MAX_BATCH_SIZE {
+        return Err(TAError::BatchTooLarge.into());
+    }
+
+    let mut seen: HashSet<u64> = HashSet::new();
+    let mut checked: Vec<R> = vec![];
+    let mut errors: Vec<BatchItemError> = vec![];
+    for (index, item) in items.iter().enumerate() {
+        let tid = tid(item);
+        let result = if seen.insert(tid) {
+            check(item)
+        } else {
+            Err(TAError::DuplicateTicket.into())
+        };
+        match result {
+            Ok(v) => checked.push(v),
+            Err(e) => errors.push(BatchItemError {
+                index,
+                tid,
+                error: error_message(e),
+            }),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(TAError::BatchFailed(errors).into());
+    }
+    Ok(checked)
+}
+
+fn error_message(error: StdError) -> String {
+    match error {
+        StdError::GenericErr { msg, .. } => msg,
+        e => e.to_string(),
+    }
+}
@@ -1,40 +1,123 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Uint128};
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+use crate::ticket_manager::TicketInfoResponse;
+
+#[cw_serde]
 pub struct InstantiateMsg {
     pub user_board: String,
     pub ticket_manager: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub enum ExecuteMsg {
-    // Transaciton initiated by service worker.
-    LockStake { tid: u64 },
+    // Transaciton initiated by service worker. (user_board -> here)
+    #[serde(alias = "LockStake")]
+    LockStake {
+        tid: u64,
+        worker: String,
+    },
+
+    // Transaction initiated by service worker with the dispute bond. (user_board -> here)
+    OpenDispute {
+        tid: u64,
+        worker: String,
+        evidence_uri: String,
+    },
 
     // Transaction initiated by admin
+    #[serde(alias = "ReleaseStake")]
     ReleaseStake(ReleaseStakeMsg),
+    SetDisputeParams(DisputeParams),
+    // Transaction initiated by arbiter. (admin_board -> here)
+    ResolveDispute {
+        tid: u64,
+        worker: String,
+        uphold: bool,
+    },
+
+    // Transaction initiated by anyone once the dispute window has passed without the dispute.
+    FinalizeSlash {
+        tid: u64,
+        worker: String,
+    },
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+// The slashed amount is held for "window" seconds after the release, during which the worker
+// can dispute the assessment with "bond" uluna. The slash is final right away when "window" is 0.
+#[cw_serde]
+#[derive(Default)]
+pub struct DisputeParams {
+    pub window: u64,
+    pub bond: Uint128,
+}
+
+// Slashed amount of the worker's stake held during the dispute window.
+#[cw_serde]
+pub struct HeldSlash {
+    pub tid: u64,
+    pub worker: Addr,
+    pub amount: Uint128,
+    // End of the dispute window.
+    pub release_at: u64,
+    pub dispute: Option<Dispute>,
+}
+
+#[cw_serde]
+pub struct Dispute {
+    pub evidence_uri: String,
+    pub bond: Uint128,
+    pub opened_at: u64,
+}
+
+#[cw_serde]
 pub struct ReleaseStakeMsg {
     pub tid: u64,
     pub worker: Addr,
     pub amt: Uint128,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
+#[derive(QueryResponses)]
 pub enum QueryMsg {
+    #[returns(bool)]
+    #[serde(alias = "QueryStakeStatus")]
     QueryStakeStatus(QueryStakeStatusMsg),
+    #[returns(TicketInfoResponse)]
+    #[serde(alias = "QueryTicket")]
     QueryTicket { tid: u64 },
+    #[returns(Vec<u64>)]
+    QueryWorkerStakes { worker: Addr },
+    // Workers with the locked stake on the ticket.
+    #[returns(Vec<Addr>)]
+    QueryTicketStakers { tid: u64 },
+    #[returns(TreasuryResponse)]
+    QueryTreasury {},
+    #[returns(DisputeParams)]
+    QueryDisputeParams {},
+    #[returns(Option<HeldSlash>)]
+    QueryHeldSlash { tid: u64, worker: Addr },
+    // Held slashes under the dispute, waiting for the arbiter.
+    #[returns(Vec<HeldSlash>)]
+    QueryDisputes {},
+}
+
+#[cw_serde]
+pub struct TreasuryResponse {
+    // Sum of the active stakes
+    pub locked: Uint128,
+    // Sum of the slashed amounts kept by the contract
+    pub slashed: Uint128,
+    // Sum of the slashed amounts held during the dispute window & the dispute bonds
+    #[serde(default)]
+    pub held: Uint128,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct QueryStakeStatusMsg {
     pub tid: u64,
     pub worker: Addr,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct MigrateMsg {}
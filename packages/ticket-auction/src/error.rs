@@ -10,6 +10,49 @@ pub enum TAError {
     NotInitialized,
     InsufficientFunds,
     NotStaked,
+    AlreadyAssessed,
+    InsufficientReputation,
+    NotClosed,
+    BetFinished,
+    InvalidBetAmount,
+    InvalidAuctionParams,
+    AlreadyStaked,
+    InvalidMigration,
+    MigrationDowngrade,
+    EmptyBatch,
+    BatchTooLarge,
+    DuplicateTicket,
+    BatchFailed(Vec<BatchItemError>),
+    InvalidMetadata(String),
+    InvalidTimestamps,
+    InvalidCollateral,
+    InvalidTicketLimits,
+    TicketFrozen,
+    InvalidSlots,
+    SlotsFilled,
+    AlreadySubmitted,
+    NotGradable,
+    InvalidGrade(String),
+    InvalidReviewPeriod,
+    ReviewNotExpired,
+    NotDisputable,
+    AlreadyDisputed,
+    NotDisputed,
+    DisputeWindowOpen,
+    InvalidEvidence,
+    InvalidStructuredResult,
+    InvalidMatcher(String),
+    InvalidLatenessPenalty(String),
+    SubmissionClosed,
+    LockedUnderflow,
+}
+
+// Failed item of the batch operation. (index in the batch, ticket id, reason)
+#[derive(Debug)]
+pub struct BatchItemError {
+    pub index: usize,
+    pub tid: u64,
+    pub error: String,
 }
 
 impl From<TAError> for StdError {
@@ -38,6 +81,115 @@ impl From<TAError> for StdError {
             },
             TAError::NotStaked => {
               StdError::generic_err("Not staked yet")
+            },
+            TAError::AlreadyAssessed => {
+              StdError::generic_err("Submission already assessed")
+            },
+            TAError::InsufficientReputation => {
+              StdError::generic_err("Worker reputation is below the ticket minimum")
+            },
+            TAError::NotClosed => {
+              StdError::generic_err("Ticket not closed yet")
+            },
+            TAError::BetFinished => {
+              StdError::generic_err("Bet already finished")
+            },
+            TAError::InvalidBetAmount => {
+              StdError::generic_err("Bet amount violates the ticket's auction rules")
+            },
+            TAError::InvalidAuctionParams => {
+              StdError::generic_err("Invalid auction params")
+            },
+            TAError::AlreadyStaked => {
+              StdError::generic_err("Already staked")
+            },
+            TAError::InvalidMigration => {
+              StdError::generic_err("Cannot migrate from a different contract")
+            },
+            TAError::MigrationDowngrade => {
+              StdError::generic_err("Cannot migrate to an older version")
+            },
+            TAError::EmptyBatch => {
+              StdError::generic_err("Empty batch")
+            },
+            TAError::BatchTooLarge => {
+              StdError::generic_err("Batch exceeds the maximum size")
+            },
+            TAError::DuplicateTicket => {
+              StdError::generic_err("Duplicate ticket id in the batch")
+            },
+            TAError::BatchFailed(errors) => {
+              let items = errors
+                .iter()
+                .map(|e| format!("#{} (tid {}): {}", e.index, e.tid, e.error))
+                .collect::<Vec<String>>();
+              StdError::generic_err(format!("Batch failed: {}", items.join("; ")))
+            },
+            TAError::InvalidMetadata(reason) => {
+              StdError::generic_err(format!("Invalid ticket metadata: {}", reason))
+            },
+            TAError::InvalidTimestamps => {
+              StdError::generic_err("Ticket timestamps are out of order or out of the allowed durations")
+            },
+            TAError::InvalidCollateral => {
+              StdError::generic_err("Collateral is out of the allowed range")
+            },
+            TAError::InvalidTicketLimits => {
+              StdError::generic_err("Invalid ticket limits")
+            },
+            TAError::TicketFrozen => {
+              StdError::generic_err("Timestamps, collateral, auction params, result, slots & assessment rules(assessment, resubmission, matcher, lateness) cannot change once a worker has staked or bid")
+            },
+            TAError::InvalidSlots => {
+              StdError::generic_err("Invalid number of slots")
+            },
+            TAError::SlotsFilled => {
+              StdError::generic_err("Every slot of the ticket is already assigned")
+            },
+            TAError::AlreadySubmitted => {
+              StdError::generic_err("Result already submitted")
+            },
+            TAError::NotGradable => {
+              StdError::generic_err("No submission of the ticket is waiting for the grade")
+            },
+            TAError::InvalidGrade(reason) => {
+              StdError::generic_err(format!("Invalid grade: {}", reason))
+            },
+            TAError::InvalidReviewPeriod => {
+              StdError::generic_err("Invalid review period: must be more than 0 seconds")
+            },
+            TAError::ReviewNotExpired => {
+              StdError::generic_err("Review deadline not passed yet")
+            },
+            TAError::NotDisputable => {
+              StdError::generic_err("No held slash to dispute within the dispute window")
+            },
+            TAError::AlreadyDisputed => {
+              StdError::generic_err("Slash already disputed")
+            },
+            TAError::NotDisputed => {
+              StdError::generic_err("No dispute on the slash")
+            },
+            TAError::DisputeWindowOpen => {
+              StdError::generic_err("Dispute window not passed yet")
+            },
+            TAError::InvalidEvidence => {
+              StdError::generic_err("Invalid evidence uri")
+            },
+            TAError::InvalidStructuredResult => {
+              StdError::generic_err("Result must be a non-empty JSON object of string fields")
+            },
+            TAError::InvalidMatcher(reason) => {
+              StdError::generic_err(format!("Invalid result matcher: {}", reason))
+            },
+            TAError::InvalidLatenessPenalty(reason) => {
+              StdError::generic_err(format!("Invalid lateness penalty: {}", reason))
+            },
+            TAError::SubmissionClosed => {
+              StdError::generic_err("Submission deadline passed")
+            },
+            TAError::LockedUnderflow => {
+              StdError::generic_err("Released stake exceeds the locked treasury")
             }
         }
     }
@@ -0,0 +1,394 @@
+// Typed clients of the ticket-auction contracts.
+//
+// The contracts talk to each other only through these wrappers, so that the messages
+// sent & the responses expected are checked by the compiler against the message types.
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, CosmosMsg, QuerierWrapper, StdResult, Uint128, WasmMsg,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    admin_board::{ExecuteMsg as AdminExecuteMsg, SlashMsg},
+    auction_manager::{
+        BetDetail, ExecuteMsg as AuctionExecuteMsg, PlaceBetMsg, QueryMsg as AuctionQueryMsg,
+        WorkerBet,
+    },
+    collateral_manager::{
+        DisputeParams, ExecuteMsg as CollateralExecuteMsg, QueryMsg as CollateralQueryMsg,
+        QueryStakeStatusMsg, ReleaseStakeMsg,
+    },
+    ticket_manager::{
+        AddTicketMsg, ExecuteMsg as TicketExecuteMsg, GradeSubmissionMsg,
+        PostConfigMsg as TicketPostConfigMsg, QueryMsg as TicketQueryMsg, TicketInfoResponse,
+        TicketLimits, TicketResultMsg, TicketWorkerPair, TicketsResponse, UpdateTicketMsg,
+        WorkerReputationResponse, WorkerTicketsResponse,
+    },
+    user_board::{ExecuteMsg as UserBoardExecuteMsg, PostConfigMsg as UserBoardPostConfigMsg},
+};
+
+fn execute_msg<T: Serialize>(contract: &Addr, msg: &T, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg: to_json_binary(msg)?,
+        funds,
+    }))
+}
+
+// **=================================================
+// ** admin_board                             ========
+// **=================================================
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AdminBoardContract(pub Addr);
+
+impl AdminBoardContract {
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self(Addr::unchecked(addr))
+    }
+
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call(&self, msg: AdminExecuteMsg) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg, vec![])
+    }
+
+    pub fn release_stake_with_slash(&self, msg: SlashMsg) -> StdResult<CosmosMsg> {
+        self.call(AdminExecuteMsg::ReleaseStakeWithSlash(msg))
+    }
+}
+
+// **=================================================
+// ** ticket_manager                          ========
+// **=================================================
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TicketManagerContract(pub Addr);
+
+impl TicketManagerContract {
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self(Addr::unchecked(addr))
+    }
+
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call(&self, msg: TicketExecuteMsg) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg, vec![])
+    }
+
+    pub fn add_ticket(&self, msg: AddTicketMsg) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::AddTicket(msg))
+    }
+
+    pub fn update_ticket(&self, msg: UpdateTicketMsg) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::UpdateTicket(msg))
+    }
+
+    pub fn remove_ticket(&self, tid: u64) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::RemoveTicket { tid })
+    }
+
+    pub fn add_tickets(&self, msgs: Vec<AddTicketMsg>) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::AddTickets(msgs))
+    }
+
+    pub fn update_tickets(&self, msgs: Vec<UpdateTicketMsg>) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::UpdateTickets(msgs))
+    }
+
+    pub fn cancel_tickets(&self, tids: Vec<u64>) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::CancelTickets { tids })
+    }
+
+    pub fn save_ticket_worker(&self, pair: TicketWorkerPair) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::SaveTicketWorker(pair))
+    }
+
+    pub fn extend_bet_finish(&self, tid: u64, bet_finish_timestamp: u64) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::ExtendBetFinish {
+            tid,
+            bet_finish_timestamp,
+        })
+    }
+
+    pub fn assess_submission(&self, msg: TicketResultMsg) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::AssessSubmission(msg))
+    }
+
+    pub fn report_no_show(&self, tid: u64) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::ReportNoShow { tid })
+    }
+
+    pub fn set_ticket_limits(&self, limits: TicketLimits) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::SetTicketLimits(limits))
+    }
+
+    pub fn grade_submission(&self, msg: GradeSubmissionMsg) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::GradeSubmission(msg))
+    }
+
+    pub fn reverse_slash(&self, tid: u64, worker: impl Into<String>) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::ReverseSlash {
+            tid,
+            worker: worker.into(),
+        })
+    }
+
+    pub fn post_config(&self, msg: TicketPostConfigMsg) -> StdResult<CosmosMsg> {
+        self.call(TicketExecuteMsg::PostConfig(msg))
+    }
+
+    pub fn query_ticket_info(
+        &self,
+        querier: &QuerierWrapper,
+        tid: u64,
+    ) -> StdResult<TicketInfoResponse> {
+        querier.query_wasm_smart(&self.0, &TicketQueryMsg::QueryTicketInfo { tid })
+    }
+
+    pub fn query_tickets(
+        &self,
+        querier: &QuerierWrapper,
+        category: Option<String>,
+        tag: Option<String>,
+    ) -> StdResult<TicketsResponse> {
+        querier.query_wasm_smart(&self.0, &TicketQueryMsg::QueryTickets { category, tag })
+    }
+
+    pub fn query_ticket_workers(
+        &self,
+        querier: &QuerierWrapper,
+        tid: u64,
+    ) -> StdResult<Vec<String>> {
+        querier.query_wasm_smart(&self.0, &TicketQueryMsg::QueryTicketWorkers { tid })
+    }
+
+    pub fn query_ticket_worker(&self, querier: &QuerierWrapper, tid: u64) -> StdResult<String> {
+        querier.query_wasm_smart(&self.0, &TicketQueryMsg::QueryTicketWorker { tid })
+    }
+
+    pub fn query_worker_tickets(
+        &self,
+        querier: &QuerierWrapper,
+        worker: impl Into<String>,
+    ) -> StdResult<WorkerTicketsResponse> {
+        querier.query_wasm_smart(
+            &self.0,
+            &TicketQueryMsg::QueryWorkerTickets {
+                worker: worker.into(),
+            },
+        )
+    }
+
+    pub fn query_worker_reputation(
+        &self,
+        querier: &QuerierWrapper,
+        worker: impl Into<String>,
+    ) -> StdResult<WorkerReputationResponse> {
+        querier.query_wasm_smart(
+            &self.0,
+            &TicketQueryMsg::QueryWorkerReputation {
+                worker: worker.into(),
+            },
+        )
+    }
+
+    pub fn query_ticket_limits(&self, querier: &QuerierWrapper) -> StdResult<TicketLimits> {
+        querier.query_wasm_smart(&self.0, &TicketQueryMsg::QueryTicketLimits {})
+    }
+}
+
+// **=================================================
+// ** user_board                              ========
+// **=================================================
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct UserBoardContract(pub Addr);
+
+impl UserBoardContract {
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self(Addr::unchecked(addr))
+    }
+
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call(&self, msg: UserBoardExecuteMsg) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg, vec![])
+    }
+
+    pub fn post_config(&self, msg: UserBoardPostConfigMsg) -> StdResult<CosmosMsg> {
+        self.call(UserBoardExecuteMsg::PostConfig(msg))
+    }
+}
+
+// **=================================================
+// ** auction_manager                         ========
+// **=================================================
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AuctionManagerContract(pub Addr);
+
+impl AuctionManagerContract {
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self(Addr::unchecked(addr))
+    }
+
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call(&self, msg: AuctionExecuteMsg) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg, vec![])
+    }
+
+    // Place the bet with the bet fee sent as "funds".
+    pub fn place_bet(&self, msg: PlaceBetMsg, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &AuctionExecuteMsg::PlaceBet(msg), funds)
+    }
+
+    pub fn decide_winning_bet(&self, tid: u64) -> StdResult<CosmosMsg> {
+        self.call(AuctionExecuteMsg::DecideWinningBet { tid })
+    }
+
+    pub fn update_config(
+        &self,
+        keeper_reward: Option<Uint128>,
+        bet_fee: Option<Uint128>,
+        user_board: Option<String>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(AuctionExecuteMsg::UpdateConfig {
+            keeper_reward,
+            bet_fee,
+            user_board,
+        })
+    }
+
+    pub fn query_bet_available_tickets(&self, querier: &QuerierWrapper) -> StdResult<Vec<u64>> {
+        querier.query_wasm_smart(&self.0, &AuctionQueryMsg::BetAvailableTickets {})
+    }
+
+    pub fn query_curr_active_bets(
+        &self,
+        querier: &QuerierWrapper,
+        tid: u64,
+    ) -> StdResult<Vec<BetDetail>> {
+        querier.query_wasm_smart(&self.0, &AuctionQueryMsg::CurrActiveBets { tid })
+    }
+
+    pub fn query_worker_active_bets(
+        &self,
+        querier: &QuerierWrapper,
+        worker: impl Into<String>,
+    ) -> StdResult<Vec<WorkerBet>> {
+        querier.query_wasm_smart(
+            &self.0,
+            &AuctionQueryMsg::WorkerActiveBets {
+                worker: worker.into(),
+            },
+        )
+    }
+}
+
+// **=================================================
+// ** collateral_manager                      ========
+// **=================================================
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct CollateralManagerContract(pub Addr);
+
+impl CollateralManagerContract {
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self(Addr::unchecked(addr))
+    }
+
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call(&self, msg: CollateralExecuteMsg, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg, funds)
+    }
+
+    // Lock the stake sent as "funds" for the worker.
+    pub fn lock_stake(
+        &self,
+        tid: u64,
+        worker: impl Into<String>,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            CollateralExecuteMsg::LockStake {
+                tid,
+                worker: worker.into(),
+            },
+            funds,
+        )
+    }
+
+    pub fn release_stake(&self, msg: ReleaseStakeMsg) -> StdResult<CosmosMsg> {
+        self.call(CollateralExecuteMsg::ReleaseStake(msg), vec![])
+    }
+
+    // Dispute the held slash with the bond sent as "funds".
+    pub fn open_dispute(
+        &self,
+        tid: u64,
+        worker: impl Into<String>,
+        evidence_uri: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            CollateralExecuteMsg::OpenDispute {
+                tid,
+                worker: worker.into(),
+                evidence_uri,
+            },
+            funds,
+        )
+    }
+
+    pub fn set_dispute_params(&self, params: DisputeParams) -> StdResult<CosmosMsg> {
+        self.call(CollateralExecuteMsg::SetDisputeParams(params), vec![])
+    }
+
+    pub fn resolve_dispute(
+        &self,
+        tid: u64,
+        worker: impl Into<String>,
+        uphold: bool,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            CollateralExecuteMsg::ResolveDispute {
+                tid,
+                worker: worker.into(),
+                uphold,
+            },
+            vec![],
+        )
+    }
+
+    pub fn query_stake_status(
+        &self,
+        querier: &QuerierWrapper,
+        tid: u64,
+        worker: Addr,
+    ) -> StdResult<bool> {
+        querier.query_wasm_smart(
+            &self.0,
+            &CollateralQueryMsg::QueryStakeStatus(QueryStakeStatusMsg { tid, worker }),
+        )
+    }
+
+    pub fn query_worker_stakes(
+        &self,
+        querier: &QuerierWrapper,
+        worker: Addr,
+    ) -> StdResult<Vec<u64>> {
+        querier.query_wasm_smart(&self.0, &CollateralQueryMsg::QueryWorkerStakes { worker })
+    }
+
+    pub fn query_ticket_stakers(&self, querier: &QuerierWrapper, tid: u64) -> StdResult<Vec<Addr>> {
+        querier.query_wasm_smart(&self.0, &CollateralQueryMsg::QueryTicketStakers { tid })
+    }
+}
@@ -1,6 +1,10 @@
 pub mod admin_board;
 pub mod auction_manager;
+pub mod batch;
 pub mod collateral_manager;
 pub mod error;
+pub mod helpers;
+pub mod matcher;
+pub mod migration;
 pub mod ticket_manager;
 pub mod user_board;
@@ -0,0 +1,150 @@
+// Strategies comparing the submitted result with the ticket's expected result.
+use std::str::FromStr;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, SignedDecimal};
+use sha2::{Digest, Sha256};
+
+// Maximum number of the alternatives of "OneOf".
+pub const MAX_ALTERNATIVES: usize = 32;
+
+#[cw_serde]
+#[derive(Default)]
+pub enum ResultMatcher {
+    // Byte for byte.
+    #[default]
+    Exact,
+    // Letter case ignored.
+    CaseInsensitive,
+    // Leading & trailing whitespace ignored.
+    Trimmed,
+    // Both are decimal numbers(eg. "-3.14") differing at most by "tolerance".
+    Numeric {
+        tolerance: Decimal,
+    },
+    // The expected result or any of "alternatives", byte for byte.
+    OneOf {
+        alternatives: Vec<String>,
+    },
+    // The expected result is the pattern: "*" matches any sequence, "?" any single character.
+    Pattern,
+    // The expected result is the sha256 hash(lowercase hex) of the normalized result,
+    // so that the answer is not revealed on the chain.
+    NormalizedHash,
+}
+
+impl ResultMatcher {
+    // Check if the expected result can be matched with the strategy.
+    pub fn validate(&self, expected: &str) -> Result<(), String> {
+        match self {
+            ResultMatcher::Numeric { .. } => match parse_number(expected) {
+                Some(_) => Ok(()),
+                None => Err("expected result is not a decimal number".to_string()),
+            },
+            ResultMatcher::OneOf { alternatives } if alternatives.is_empty() => {
+                Err("no alternatives".to_string())
+            }
+            ResultMatcher::OneOf { alternatives } if alternatives.len() > MAX_ALTERNATIVES => {
+                Err(format!("more than {} alternatives", MAX_ALTERNATIVES))
+            }
+            ResultMatcher::Pattern if expected.is_empty() => Err("empty pattern".to_string()),
+            ResultMatcher::NormalizedHash if !is_sha256_hex(expected) => {
+                Err("expected result is not sha256 in lowercase hex".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn matches(&self, expected: &str, submitted: &str) -> bool {
+        match self {
+            ResultMatcher::Exact => expected == submitted,
+            ResultMatcher::CaseInsensitive => expected.to_lowercase() == submitted.to_lowercase(),
+            ResultMatcher::Trimmed => expected.trim() == submitted.trim(),
+            ResultMatcher::Numeric { tolerance } => {
+                match (parse_number(expected), parse_number(submitted)) {
+                    (Some(expected), Some(submitted)) => expected.abs_diff(submitted) <= *tolerance,
+                    _ => false,
+                }
+            }
+            ResultMatcher::OneOf { alternatives } => {
+                expected == submitted || alternatives.iter().any(|a| a == submitted)
+            }
+            ResultMatcher::Pattern => matches_pattern(expected, submitted),
+            ResultMatcher::NormalizedHash => {
+                sha256_hex(normalize(submitted).as_bytes()) == expected
+            }
+        }
+    }
+
+    // Check if the two submitted results are the same answer, grouping the consensus votes.
+    // The alternatives & the pattern describe the expected result only, so "OneOf" and
+    // "Pattern" compare the submissions byte for byte.
+    pub fn equivalent(&self, x: &str, y: &str) -> bool {
+        match self {
+            ResultMatcher::CaseInsensitive
+            | ResultMatcher::Trimmed
+            | ResultMatcher::Numeric { .. } => self.matches(x, y),
+            ResultMatcher::NormalizedHash => normalize(x) == normalize(y),
+            ResultMatcher::Exact | ResultMatcher::OneOf { .. } | ResultMatcher::Pattern => x == y,
+        }
+    }
+}
+
+// Trimmed, lowercase & the inner whitespace collapsed into a single space.
+pub fn normalize(result: &str) -> String {
+    result
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+// sha256 hash in lowercase hex.
+pub fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn is_sha256_hex(value: &str) -> bool {
+    value.len() == 64
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+}
+
+fn parse_number(value: &str) -> Option<SignedDecimal> {
+    SignedDecimal::from_str(value.trim()).ok()
+}
+
+// Wildcard matching, backtracking to the last "*" on the mismatch.
+fn matches_pattern(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<char>>();
+    let value = value.chars().collect::<Vec<char>>();
+    let (mut p, mut v) = (0, 0);
+    // Position of the last "*" & the value position it currently covers up to.
+    let mut star: Option<(usize, usize)> = None;
+
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, v));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match star {
+                Some((star_p, star_v)) => {
+                    star = Some((star_p, star_v + 1));
+                    p = star_p + 1;
+                    v = star_v + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
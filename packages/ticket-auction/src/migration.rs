@@ -0,0 +1,70 @@
+use cosmwasm_std::{attr, DepsMut, Response, StdError, StdResult};
+use semver::Version;
+
+use crate::error::TAError;
+
+// Version of the contracts deployed before the contract info(cw2) was recorded.
+pub const LEGACY_VERSION: &str = "0.0.0";
+
+// Storage migration rolled out by the contract "version".
+// The steps are listed in the ascending order of the version.
+// The step can query the other contracts, e.g. to backfill the state kept there.
+pub struct MigrationStep {
+    pub version: &'static str,
+    pub migrate: fn(DepsMut) -> StdResult<()>,
+}
+
+fn parse_version(version: &str) -> StdResult<Version> {
+    Version::parse(version).map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+// Common "migrate" of the contracts.
+//   - Refuse the code of the other contract & the downgrade.
+//   - Run the steps newer than the stored version, up to the new version, in order.
+//   - Record the new contract name & version.
+pub fn migrate_contract(
+    mut deps: DepsMut,
+    name: &str,
+    version: &str,
+    steps: &[MigrationStep],
+) -> StdResult<Response> {
+    let stored = match cw2::CONTRACT.may_load(deps.storage)? {
+        Some(info) => {
+            if info.contract != name {
+                return Err(TAError::InvalidMigration.into());
+            }
+            info.version
+        }
+        None => LEGACY_VERSION.to_string(),
+    };
+
+    let from = parse_version(&stored)?;
+    let to = parse_version(version)?;
+    if from > to {
+        return Err(TAError::MigrationDowngrade.into());
+    }
+
+    let mut applied = vec![];
+    for step in steps {
+        let step_version = parse_version(step.version)?;
+        if step_version > from && step_version <= to {
+            (step.migrate)(deps.branch())?;
+            applied.push(step.version);
+        }
+    }
+
+    cw2::set_contract_version(deps.storage, name, version)?;
+
+    // Empty attribute value is not allowed.
+    let steps = match applied.is_empty() {
+        true => "none".to_string(),
+        false => applied.join(","),
+    };
+
+    Ok(Response::new().add_attributes(vec![
+        attr("method", "migrate"),
+        attr("from_version", stored),
+        attr("to_version", version),
+        attr("steps", steps),
+    ]))
+}
@@ -1,97 +1,680 @@
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+use crate::matcher::ResultMatcher;
+
+#[cw_serde]
 pub struct InstantiateMsg {
     pub auction_manager: Option<String>,
     pub user_board: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub enum ExecuteMsg {
     // Transactions initiated by admin
+    #[serde(alias = "AddTicket")]
     AddTicket(AddTicketMsg),
+    #[serde(alias = "UpdateTicket")]
     UpdateTicket(UpdateTicketMsg),
-    RemoveTicket { tid: u64 },
+    #[serde(alias = "RemoveTicket")]
+    RemoveTicket {
+        tid: u64,
+    },
+    // Batch operations, validated atomically. (up to "batch::MAX_BATCH_SIZE" items)
+    AddTickets(Vec<AddTicketMsg>),
+    UpdateTickets(Vec<UpdateTicketMsg>),
+    CancelTickets {
+        tids: Vec<u64>,
+    },
+    #[serde(alias = "SaveTicketWorker")]
     SaveTicketWorker(TicketWorkerPair),
+    ExtendBetFinish {
+        tid: u64,
+        bet_finish_timestamp: u64,
+    },
+    #[serde(alias = "AssessSubmission")]
     AssessSubmission(TicketResultMsg),
+    ReportNoShow {
+        tid: u64,
+    },
+    SetTicketLimits(TicketLimits),
+    // Transaction initiated by admin_board on behalf of the grader.
+    GradeSubmission(GradeSubmissionMsg),
+    // Transaction initiated by admin_board when the arbiter reverses the disputed slash.
+    ReverseSlash {
+        tid: u64,
+        worker: String,
+    },
+
+    // Transaction initiated by anyone once the review deadline has passed.
+    AutoApprove {
+        tid: u64,
+    },
+    // Transaction initiated by anyone once the ticket is closed.
+    // Assess the held(last) submissions of the ticket. (not "Manual")
+    CloseSubmissions {
+        tid: u64,
+    },
 
     // Utilities
+    #[serde(alias = "PostConfig")]
     PostConfig(PostConfigMsg),
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct AddTicketMsg {
     pub id: u64,
     pub bet_finish_timestamp: u64,
     pub close_timestamp: u64,
     pub result: String,
     pub collateral: u64,
+    #[serde(default)]
+    pub auction: AuctionParams,
+    #[serde(default)]
+    pub metadata: TicketMetadata,
+    // Set by admin_board to the admin wallet adding the ticket.
+    #[serde(default)]
+    pub creator: Option<String>,
+    // Number of the workers assigned on the ticket. (the best "slots" bets win)
+    #[serde(default = "default_slots")]
+    pub slots: u32,
+    #[serde(default)]
+    pub assessment: AssessmentMode,
+    // The submission can be replaced until "close_timestamp", only the last one is assessed
+    // once the ticket is closed.
+    #[serde(default)]
+    pub resubmission: bool,
+    // How the submitted result is compared with "result".
+    #[serde(default)]
+    pub matcher: ResultMatcher,
+    // Slash for the result submitted after "close_timestamp".
+    #[serde(default)]
+    pub lateness: LatenessPenalty,
+}
+
+fn default_slots() -> u32 {
+    1
+}
+
+// Maximum number of the workers assigned on a ticket.
+pub const MAX_SLOTS: u32 = 16;
+
+// How the submitted results are assessed.
+#[cw_serde]
+#[derive(Default)]
+pub enum AssessmentMode {
+    // Every submission is compared with the ticket's "result" as soon as it is submitted.
+    #[default]
+    ExpectedResult,
+    // The submissions are assessed together once every assignee has submitted or
+    // been reported as no-show. The result(grouped with the ticket's matcher) submitted by
+    // at least 2 & the strict majority of all the assignees is the correct one, the outliers
+    // are slashed. Without the majority, the ticket's "result" is used. (at least 2 slots)
+    Consensus,
+    // The submissions(eg. the content hash of the work) are reviewed by a grader, who scores
+    // them in per mille. The submission not graded within "review_period" seconds after
+    // the submission is auto-approved with the full score.
+    Manual {
+        review_period: u64,
+    },
+    // The result & the ticket's "result" are flat JSON objects of string fields,
+    // eg. {"name":"Alice","age":"42"}. The share of the expected fields matched by
+    // the submission is the credit, the slash for the wrong result(50%) is reduced in proportion.
+    PartialCredit,
+}
+
+// Slash(per mille) for the result submitted after the ticket is closed.
+// The result is accepted until "cutoff" seconds after the close("DEFAULT_LATENESS_CUTOFF"
+// when not set), the later submission is rejected & the worker is left to be reported as no-show.
+#[cw_serde]
+pub enum LatenessPenalty {
+    // 30% for any lateness.
+    Flat {
+        #[serde(default)]
+        cutoff: Option<u64>,
+    },
+    // "per_hour" for every started hour after the close, up to "max".
+    Linear {
+        per_hour: u64,
+        max: u64,
+        #[serde(default)]
+        cutoff: Option<u64>,
+    },
+    // The slash of the last tier passed. The tiers are ordered by "after".
+    Stepped {
+        tiers: Vec<LatenessTier>,
+        #[serde(default)]
+        cutoff: Option<u64>,
+    },
+}
+
+impl Default for LatenessPenalty {
+    fn default() -> Self {
+        LatenessPenalty::Flat { cutoff: None }
+    }
+}
+
+#[cw_serde]
+pub struct LatenessTier {
+    // Seconds after the close.
+    pub after: u64,
+    // Per mille.
+    pub slash_perc: u64,
+}
+
+// Slash of the "Flat" lateness penalty. (per mille)
+pub const FLAT_LATENESS_PENALTY: u64 = 300;
+// Maximum number of the tiers of the "Stepped" lateness penalty.
+pub const MAX_LATENESS_TIERS: usize = 8;
+
+const HOUR: u64 = 3_600;
+
+// Seconds after the close until the late result is accepted, unless the "cutoff" is set.
+pub const DEFAULT_LATENESS_CUTOFF: u64 = 24 * HOUR;
+
+impl LatenessPenalty {
+    // Check if the slashes are within 100% & the tiers are ordered.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            LatenessPenalty::Linear { per_hour, max, .. } if *per_hour == 0 || *max == 0 => {
+                Err("zero penalty".to_string())
+            }
+            LatenessPenalty::Linear { max, .. } if *max > MAX_SCORE => {
+                Err("max above 1000".to_string())
+            }
+            LatenessPenalty::Stepped { tiers, .. } if tiers.is_empty() => {
+                Err("no tiers".to_string())
+            }
+            LatenessPenalty::Stepped { tiers, .. } if tiers.len() > MAX_LATENESS_TIERS => {
+                Err(format!("more than {} tiers", MAX_LATENESS_TIERS))
+            }
+            LatenessPenalty::Stepped { tiers, .. } => {
+                if tiers.iter().any(|tier| tier.slash_perc > MAX_SCORE) {
+                    return Err("tier slash above 1000".to_string());
+                }
+                if tiers.windows(2).any(|pair| {
+                    pair[0].after >= pair[1].after || pair[0].slash_perc > pair[1].slash_perc
+                }) {
+                    return Err("tiers not increasing".to_string());
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Slash for the submission "late_by" seconds after the close,
+    // none when the submission is rejected.
+    pub fn penalty(&self, late_by: u64) -> Option<u64> {
+        if late_by == 0 {
+            return Some(0);
+        }
+        if late_by > self.cutoff() {
+            return None;
+        }
+        match self {
+            LatenessPenalty::Flat { .. } => Some(FLAT_LATENESS_PENALTY),
+            LatenessPenalty::Linear { per_hour, max, .. } => {
+                let hours = late_by.div_ceil(HOUR);
+                Some(hours.saturating_mul(*per_hour).min(*max))
+            }
+            LatenessPenalty::Stepped { tiers, .. } => Some(
+                tiers
+                    .iter()
+                    .rev()
+                    .find(|tier| late_by > tier.after)
+                    .map_or(0, |tier| tier.slash_perc),
+            ),
+        }
+    }
+
+    // Seconds after the close until the submission is accepted.
+    pub fn cutoff(&self) -> u64 {
+        match self {
+            LatenessPenalty::Flat { cutoff }
+            | LatenessPenalty::Linear { cutoff, .. }
+            | LatenessPenalty::Stepped { cutoff, .. } => cutoff.unwrap_or(DEFAULT_LATENESS_CUTOFF),
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+// Maximum score of the graded submission. (per mille)
+pub const MAX_SCORE: u64 = 1000;
+// Size limit of the grader's notes. (bytes)
+pub const MAX_NOTES_LEN: usize = 1024;
+// Longer results are recorded in the submission history as their sha256 hash. (bytes)
+pub const MAX_STORED_RESULT_LEN: usize = 256;
+
+// Grade of the submission on the "Manual" ticket.
+// "worker" can be omitted when only one submission of the ticket is waiting for the review.
+#[cw_serde]
+pub struct GradeSubmissionMsg {
+    pub tid: u64,
+    #[serde(default)]
+    pub worker: Option<String>,
+    pub score: u64,
+    #[serde(default)]
+    pub notes: String,
+    // Set by admin_board to the grader.
+    #[serde(default)]
+    pub grader: Option<String>,
+}
+
+#[cw_serde]
 pub struct UpdateTicketMsg {
     pub id: u64,
     pub bet_finish_timestamp: Option<u64>,
     pub close_timestamp: Option<u64>,
     pub result: Option<String>,
     pub collateral: Option<u64>,
+    pub auction: Option<AuctionParams>,
+    // Replaces the whole metadata.
+    #[serde(default)]
+    pub metadata: Option<TicketMetadata>,
+    #[serde(default)]
+    pub slots: Option<u32>,
+    #[serde(default)]
+    pub assessment: Option<AssessmentMode>,
+    #[serde(default)]
+    pub resubmission: Option<bool>,
+    #[serde(default)]
+    pub matcher: Option<ResultMatcher>,
+    #[serde(default)]
+    pub lateness: Option<LatenessPenalty>,
+}
+
+// Bounds of the new & updated tickets, set by admin.
+// Bet duration: block time -> "bet_finish_timestamp" (seconds)
+// Work duration: "bet_finish_timestamp" -> "close_timestamp" (seconds)
+#[cw_serde]
+pub struct TicketLimits {
+    pub min_bet_duration: u64,
+    pub max_bet_duration: Option<u64>,
+    pub min_work_duration: u64,
+    pub max_work_duration: Option<u64>,
+    // At least 1.
+    pub min_collateral: u64,
+    pub max_collateral: Option<u64>,
+}
+
+impl Default for TicketLimits {
+    fn default() -> Self {
+        TicketLimits {
+            min_bet_duration: 0,
+            max_bet_duration: None,
+            min_work_duration: 0,
+            max_work_duration: None,
+            min_collateral: 1,
+            max_collateral: None,
+        }
+    }
+}
+
+// Size limits of the ticket metadata. (bytes)
+pub const MAX_TITLE_LEN: usize = 128;
+pub const MAX_DESCRIPTION_LEN: usize = 2048;
+pub const MAX_CATEGORY_LEN: usize = 32;
+pub const MAX_TAGS: usize = 8;
+pub const MAX_TAG_LEN: usize = 32;
+pub const MAX_CONTENT_URI_LEN: usize = 256;
+
+// Description of the work shown to the workers.
+// "category" & "tags" are lowercase words("a-z", "0-9", "-") used by the ticket filters.
+// "content_uri" points to the off-chain content, "content_hash" is its sha256 hash in hex.
+#[cw_serde]
+#[derive(Default)]
+pub struct TicketMetadata {
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub content_uri: Option<String>,
+    #[serde(default)]
+    pub content_hash: Option<String>,
+}
+
+// Per-ticket rules applied by auction_manager.
+#[cw_serde]
+#[derive(Default)]
+pub struct AuctionParams {
+    // Minimum reputation score(per mille) required to place the bet.
+    pub min_reputation: Option<u64>,
+    // Scoring rule for the winning bet. The lowest bet wins when not set.
+    pub scoring: Option<BidScoring>,
+    // Maximum acceptable bet amount.
+    pub reserve_price: Option<u64>,
+    // Minimum bet amount. (at least 1)
+    pub min_bid: Option<u64>,
+    // New bet must be lower than the current lowest bet by at least this amount.
+    pub min_decrement: Option<u64>,
+    // Anti-sniping rule. The bet finish timestamp is extended on the late best bet.
+    pub soft_close: Option<SoftClose>,
+}
+
+// The new best bet placed within the last "window" seconds of the bet extends
+// "bet_finish_timestamp" by "extension" seconds, up to "max_extension" seconds in total.
+#[cw_serde]
+pub struct SoftClose {
+    pub window: u64,
+    pub extension: u64,
+    pub max_extension: u64,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+// Weights of the bet score components. Every component is scored in per mille.
+#[cw_serde]
+pub struct BidScoring {
+    // Lowest bet amount / bet amount
+    pub price_weight: u64,
+    // Worker's completed tickets / finished tickets
+    pub completion_weight: u64,
+    // Worker's on-time submissions / completed tickets
+    pub punctuality_weight: u64,
+}
+
+// Maximum weight of a bet score component. (at least one weight must be non-zero)
+pub const MAX_SCORING_WEIGHT: u64 = 1000;
+
+#[cw_serde]
 pub struct TicketWorkerPair {
     pub tid: u64,
     pub worker: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct TicketResultMsg {
     pub tid: u64,
     pub worker: String,
     pub result: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct PostConfigMsg {
     pub auction_manager: Option<String>,
     pub user_board: Option<String>,
+    #[serde(default)]
+    pub collateral_manager: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
+#[derive(QueryResponses)]
 pub enum QueryMsg {
-    QueryTickets {},
+    // Tickets of the category and/or with the tag. (all the tickets without the filters)
+    #[returns(TicketsResponse)]
+    #[serde(alias = "QueryTickets")]
+    QueryTickets {
+        category: Option<String>,
+        tag: Option<String>,
+    },
+    #[returns(TicketInfoResponse)]
+    #[serde(alias = "QueryTicketInfo")]
     QueryTicketInfo { tid: u64 },
+    #[returns(TWPairsReponse)]
+    #[serde(alias = "QueryTicketWorkerPairs")]
     QueryTicketWorkerPairs {},
+    // First worker assigned on the ticket.
+    #[returns(String)]
+    #[serde(alias = "QueryTicketWorker")]
     QueryTicketWorker { tid: u64 },
+    // Every worker assigned on the ticket.
+    #[returns(Vec<String>)]
+    QueryTicketWorkers { tid: u64 },
+    #[returns(WorkerTicketsResponse)]
+    QueryWorkerTickets { worker: String },
+    #[returns(WorkerReputationResponse)]
+    QueryWorkerReputation { worker: String },
+    #[returns(TicketLimits)]
+    QueryTicketLimits {},
+    // Submissions of the "Manual" tickets waiting for the grade.
+    #[returns(Vec<PendingReview>)]
+    QueryPendingReviews {},
+    #[returns(Option<Submission>)]
+    QuerySubmission { tid: u64, worker: String },
+    // Submissions of the worker ordered by the ticket id.
+    #[returns(Vec<Submission>)]
+    QuerySubmissionsByWorker { worker: String },
+    // Lateness penalty of the submission at "at_time". (the block time by default)
+    #[returns(LatenessResponse)]
+    QueryLatenessPenalty { tid: u64, at_time: Option<u64> },
+    // Dry run of the worker's submission of "result" at "at_time". (the block time by default)
+    // The expected result is not returned.
+    #[returns(SimulateAssessmentResponse)]
+    SimulateAssessment {
+        tid: u64,
+        worker: String,
+        result: String,
+        at_time: Option<u64>,
+    },
+}
+
+#[cw_serde]
+pub struct LatenessResponse {
+    pub tid: u64,
+    pub at_time: u64,
+    // Seconds after the close. (0 when on time)
+    pub late_by: u64,
+    pub slash_perc: Uint128,
+    // The submission is rejected after the cutoff.
+    pub rejected: bool,
+}
+
+#[cw_serde]
+pub struct SimulateAssessmentResponse {
+    pub tid: u64,
+    pub worker: String,
+    pub at_time: u64,
+    // Match of the result with the expected one. (the expected result is never returned)
+    // None when the result is held for the consensus or the grader.
+    pub matched: Option<bool>,
+    // Share of the matched fields of the "PartialCredit" ticket. (per mille)
+    pub credit: Option<u64>,
+    // The result is held until the consensus or the grade, the slash is then that of
+    // the correct result.
+    pub held: bool,
+    pub on_time: bool,
+    // Slash for the lateness. (per mille)
+    pub lateness_penalty: Uint128,
+    // The submission is rejected after the cutoff, the full stake is slashed as no-show.
+    pub rejected: bool,
+    // Slash of the result & the lateness, at most 1000. (per mille)
+    pub slash_perc: Uint128,
+    pub collateral: Uint128,
+    // collateral - floor(collateral * slash_perc / 1000), as released by admin_board.
+    pub released: Uint128,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct TicketInfoResponse {
     pub id: u64,
     pub bet_finish_timestamp: u64,
     pub close_timestamp: u64,
     pub result: String,
     pub collateral: u64,
+    #[serde(default)]
+    pub auction: AuctionParams,
+    #[serde(default)]
+    pub metadata: TicketMetadata,
+    #[serde(default)]
+    pub creator: String,
+    #[serde(default = "default_slots")]
+    pub slots: u32,
+    #[serde(default)]
+    pub assessment: AssessmentMode,
+    #[serde(default)]
+    pub resubmission: bool,
+    #[serde(default)]
+    pub matcher: ResultMatcher,
+    #[serde(default)]
+    pub lateness: LatenessPenalty,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct Ticket {
     pub id: u64,
     pub bet_finish_timestamp: u64,
     pub close_timestamp: u64,
     pub result: String,
     pub collateral: u64,
+    #[serde(default)]
+    pub auction: AuctionParams,
+    #[serde(default)]
+    pub metadata: TicketMetadata,
+    #[serde(default)]
+    pub creator: String,
+    #[serde(default = "default_slots")]
+    pub slots: u32,
+    #[serde(default)]
+    pub assessment: AssessmentMode,
+    #[serde(default)]
+    pub resubmission: bool,
+    #[serde(default)]
+    pub matcher: ResultMatcher,
+    #[serde(default)]
+    pub lateness: LatenessPenalty,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct TicketsResponse {
     pub tickets: Vec<Ticket>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct TWPairsReponse {
     pub pairs: Vec<TicketWorkerPair>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+// Outcome of the "AssessSubmission" for the ticket.
+#[cw_serde]
+pub struct TicketOutcome {
+    pub tid: u64,
+    pub worker: String,
+    pub correct: bool,
+    pub on_time: bool,
+    #[serde(default)]
+    pub no_show: bool,
+    pub slash_perc: Uint128,
+    pub assessed_at: u64,
+    // Only for the "Manual" tickets.
+    #[serde(default)]
+    pub grade: Option<Grade>,
+    // Share of the matched fields(per mille), only for the "PartialCredit" tickets.
+    #[serde(default)]
+    pub credit: Option<u64>,
+    // The slash is reversed by the arbiter. ("slash_perc" is 0)
+    #[serde(default)]
+    pub reversed: bool,
+}
+
+// "grader" is empty for the auto-approved submission.
+#[cw_serde]
+pub struct Grade {
+    pub score: u64,
+    pub notes: String,
+    pub grader: Option<String>,
+}
+
+// Result submitted by the worker, kept for the audit.
+// "result" is "sha256:<hex>" of the result longer than MAX_STORED_RESULT_LEN.
+#[cw_serde]
+pub struct Submission {
+    pub tid: u64,
+    pub worker: String,
+    pub result: String,
+    pub submitted_at: u64,
+    // Empty until the submission is assessed.
+    pub assessment: Option<SubmissionAssessment>,
+}
+
+#[cw_serde]
+pub struct SubmissionAssessment {
+    pub correct: bool,
+    pub on_time: bool,
+    pub slash_perc: Uint128,
+    // uluna released to the worker. (collateral - slashed amount)
+    pub released: Uint128,
+    pub assessed_at: u64,
+}
+
+#[cw_serde]
+pub struct PendingReview {
+    pub tid: u64,
+    pub worker: String,
+    pub result: String,
+    pub submitted_at: u64,
+    // Auto-approved after this timestamp.
+    pub review_deadline: u64,
+}
+
+// Ticket won by the worker. "outcome" is empty until the submission is assessed.
+#[cw_serde]
+pub struct WorkerAssignment {
+    pub tid: u64,
+    pub close_timestamp: u64,
+    pub outcome: Option<TicketOutcome>,
+}
+
+#[cw_serde]
+pub struct WorkerTicketsResponse {
+    pub worker: String,
+    pub assignments: Vec<WorkerAssignment>,
+}
+
+// Reputation score of the worker without any finished ticket.
+pub const NEUTRAL_REPUTATION: u64 = 500;
+
+// Per-worker statistics accumulated from the assessment outcomes.
+#[cw_serde]
+#[derive(Default)]
+pub struct WorkerStats {
+    pub won: u64,
+    pub completed: u64,
+    pub late: u64,
+    pub wrong: u64,
+    pub no_shows: u64,
+    pub total_slashed: Uint128,
+    // Sum of the applied slash percentages(per mille), no-shows count as 1000.
+    pub slash_perc_sum: Uint128,
+}
+
+impl WorkerStats {
+    // Completed tickets(no-shows excluded) in per mille of the finished tickets.
+    pub fn completion_rate(&self) -> u64 {
+        let finished = self.completed + self.no_shows;
+        if finished == 0 {
+            return NEUTRAL_REPUTATION;
+        }
+        self.completed * 1000 / finished
+    }
+
+    // On-time submissions in per mille of the completed tickets.
+    pub fn punctuality_rate(&self) -> u64 {
+        if self.completed == 0 {
+            return NEUTRAL_REPUTATION;
+        }
+        (self.completed - self.late) * 1000 / self.completed
+    }
+
+    // Reputation score in per mille: 1000 minus the average slash of the finished tickets.
+    pub fn reputation_score(&self) -> u64 {
+        let finished = self.completed + self.no_shows;
+        if finished == 0 {
+            return NEUTRAL_REPUTATION;
+        }
+        let avg_slash = self.slash_perc_sum.u128() / finished as u128;
+        1000u64.saturating_sub(avg_slash as u64)
+    }
+}
+
+#[cw_serde]
+pub struct WorkerReputationResponse {
+    pub worker: String,
+    pub stats: WorkerStats,
+    pub score: u64,
+}
+
+#[cw_serde]
 pub struct MigrateMsg {}
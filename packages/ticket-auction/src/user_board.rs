@@ -1,45 +1,88 @@
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use cosmwasm_schema::{cw_serde, QueryResponses};
 
-use crate::{collateral_manager::QueryStakeStatusMsg, ticket_manager::TicketResultMsg};
+use crate::{
+    auction_manager::WorkerBet,
+    collateral_manager::QueryStakeStatusMsg,
+    ticket_manager::{TicketOutcome, TicketResultMsg, TicketsResponse, WorkerAssignment},
+};
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct InstantiateMsg {
     pub ticket_manager: Option<String>,
     pub collateral_manater: Option<String>,
     pub auction_manager: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub enum ExecuteMsg {
     // Transactions initiated by user(worker)
+    #[serde(alias = "PlaceBet")]
     PlaceBet(PlaceBetMsg),
-    LockStake { tid: u64 },
+    #[serde(alias = "LockStake")]
+    LockStake {
+        tid: u64,
+    },
+    #[serde(alias = "SubmitResult")]
     SubmitResult(TicketResultMsg),
+    // Dispute the slash held in collateral_manager. (the dispute bond is sent as funds)
+    DisputeAssessment {
+        tid: u64,
+        evidence_uri: String,
+    },
 
     // Utilities
+    #[serde(alias = "PostConfig")]
     PostConfig(PostConfigMsg),
 }
 
 // Message for "PlaceBet" execute.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct PlaceBetMsg {
     pub ticket_id: u64,
     pub bet_amount: u64,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
 pub struct PostConfigMsg {
     pub ticket_manager: Option<String>,
     pub collateral_manager: Option<String>,
     pub auction_manager: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[cw_serde]
+#[derive(QueryResponses)]
 pub enum QueryMsg {
+    #[returns(Vec<u64>)]
+    #[serde(alias = "QueryBetAvailTickets")]
     QueryBetAvailTickets {},
+    // Tickets with the metadata, filtered by the category and/or the tag. (ticket_manager)
+    #[returns(TicketsResponse)]
+    QueryTickets {
+        category: Option<String>,
+        tag: Option<String>,
+    },
+    #[returns(bool)]
+    #[serde(alias = "QueryStakeStatus")]
     QueryStakeStatus(QueryStakeStatusMsg),
+    #[returns(WorkerDashboardResponse)]
+    QueryWorkerDashboard { worker: String },
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+// Aggregated view of the worker's activity over all the contracts.
+#[cw_serde]
+pub struct WorkerDashboardResponse {
+    pub worker: String,
+    // Tickets on which the worker currently has locked stake. (collateral_manager)
+    pub staked_tickets: Vec<u64>,
+    // Bets placed on the tickets which are still in auction. (auction_manager)
+    pub active_bets: Vec<WorkerBet>,
+    // Tickets won by the worker, with deadlines. (ticket_manager)
+    pub assignments: Vec<WorkerAssignment>,
+    // Won tickets whose result is not submitted yet.
+    pub pending_submissions: Vec<u64>,
+    // Assessed submissions.
+    pub outcomes: Vec<TicketOutcome>,
+}
+
+#[cw_serde]
 pub struct MigrateMsg {}